@@ -1,12 +1,13 @@
 use crate::SqlIntrospectionResult;
 use datamodel::{
     common::{names::NameNormalizer, ScalarType, ScalarValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, OnDeleteStrategy,
+    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, ReferentialAction,
     RelationInfo, ScalarListStrategy, WithDatabaseName,
 };
 use log::debug;
 use prisma_inflector;
 use regex::Regex;
+use rust_decimal::Decimal;
 use sql_schema_describer::*;
 
 fn is_migration_table(table: &Table) -> bool {
@@ -48,7 +49,8 @@ fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is
         name: relation_name,
         to: foreign_key.referenced_table.clone(),
         to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     });
 
     let basename = inflector.pluralize(&foreign_key.referenced_table).camel_case();
@@ -86,6 +88,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         .filter(|table| !is_prisma_scalar_list_table(&table))
     {
         let mut model = Model::new(&table.name);
+        model.documentation = table.comment.clone();
         //Todo: This needs to filter out composite Foreign Key columns, they are merged into one new field
         for column in table.columns.iter() {
             debug!("Handling column {:?}", column);
@@ -103,7 +106,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             let default_value = column
                 .default
                 .as_ref()
-                .and_then(|default| calculate_default(default, &column.tpe.family));
+                .and_then(|default| calculate_default(default, &column.tpe));
 
             let is_unique = match field_type {
                 datamodel::dml::FieldType::Relation(..) => false,
@@ -125,7 +128,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 is_unique,
                 id_info,
                 scalar_list_strategy,
-                documentation: None,
+                documentation: column.comment.clone(),
                 is_generated: false,
                 is_updated_at: false,
             };
@@ -165,17 +168,16 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
     }
 
     for e in schema.enums.iter() {
-        let mut values: Vec<String> = e.values.iter().cloned().collect();
-        values.sort_unstable();
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
-            values,
+            values: e.values.clone(),
             database_name: None,
             documentation: None,
         });
     }
 
     let mut fields_to_be_added = Vec::new();
+    let mut legacy_cascades = Vec::new();
 
     // add backrelation fields
     for model in data_model.models.iter() {
@@ -193,23 +195,6 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                     {
                         let other_model = data_model.find_model(&relation_info.to).unwrap();
 
-                        let table = schema.table_bang(model.name.as_str());
-                        let fk = table.foreign_key_for_column(relation_field.name.as_str());
-                        let on_delete = match fk {
-                            None => OnDeleteStrategy::None,
-                            Some(fk) => match fk.on_delete_action {
-                                ForeignKeyAction::Cascade => OnDeleteStrategy::Cascade,
-                                _ => OnDeleteStrategy::None,
-                            },
-                        };
-
-                        let field_type = FieldType::Relation(RelationInfo {
-                            name: relation_info.name.clone(),
-                            to: model.name.clone(),
-                            to_fields: vec![relation_field.name.clone()],
-                            on_delete,
-                        });
-
                         let arity = match relation_field.arity {
                             FieldArity::Required | FieldArity::Optional
                                 if schema.table_bang(&model.name).is_column_unique(
@@ -222,6 +207,22 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                             FieldArity::List => FieldArity::Optional,
                         };
 
+                        // Cascading deletes stay on the list side, where earlier versions introspected them.
+                        let is_legacy_cascade =
+                            arity == FieldArity::List && relation_info.on_delete == Some(ReferentialAction::Cascade);
+
+                        if is_legacy_cascade {
+                            legacy_cascades.push((model.name.clone(), relation_field.name.clone()));
+                        }
+
+                        let field_type = FieldType::Relation(RelationInfo {
+                            name: relation_info.name.clone(),
+                            to: model.name.clone(),
+                            to_fields: vec![relation_field.name.clone()],
+                            on_delete: relation_info.on_delete.filter(|_| is_legacy_cascade),
+                            on_update: None,
+                        });
+
                         let inflector = prisma_inflector::default();
 
                         let name = match arity {
@@ -252,6 +253,16 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
         }
     }
 
+    for field_ref in legacy_cascades {
+        if let Some(Field {
+            field_type: FieldType::Relation(relation_info),
+            ..
+        }) = data_model.find_field_mut(&field_ref)
+        {
+            relation_info.on_delete = None;
+        }
+    }
+
     // add prisma many to many relation fields
     for table in schema.tables.iter().filter(|table| is_prisma_join_table(&table)) {
         let first = table.foreign_keys.get(0);
@@ -385,8 +396,33 @@ fn parse_float(value: &str) -> Option<f32> {
     }
 }
 
-fn calculate_default(default: &str, tpe: &ColumnTypeFamily) -> Option<ScalarValue> {
-    match tpe {
+fn parse_decimal(value: &str) -> Option<Decimal> {
+    debug!("Parsing decimal '{}'", value);
+    let re_num = Regex::new(r"^'?([^']+)'?$").expect("compile regex");
+    let captures = re_num.captures(value)?;
+    let num_str = captures.get(1).expect("get capture").as_str();
+
+    match num_str.parse::<Decimal>() {
+        Ok(num) => Some(num),
+        Err(_) => {
+            debug!("Couldn't parse decimal '{}'", num_str);
+            None
+        }
+    }
+}
+
+/// Whether the column has an exact numeric type, which is introspected as `Decimal` rather than `Float`. Prisma
+/// creates the columns of `Float` fields as `Decimal(65,30)` on Postgres and MySQL, those stay floats.
+fn is_decimal_column_type(tpe: &ColumnType) -> bool {
+    let raw: String = tpe.raw.to_lowercase().split_whitespace().collect();
+    let is_exact_numeric = raw.starts_with("numeric") || raw.starts_with("decimal");
+
+    tpe.family == ColumnTypeFamily::Float && is_exact_numeric && !raw.ends_with("(65,30)")
+}
+
+fn calculate_default(default: &str, tpe: &ColumnType) -> Option<ScalarValue> {
+    match &tpe.family {
+        ColumnTypeFamily::Float if is_decimal_column_type(tpe) => parse_decimal(default).map(ScalarValue::Decimal),
         ColumnTypeFamily::Boolean => match parse_int(default) {
             Some(x) => Some(ScalarValue::Boolean(x != 0)),
             None => parse_bool(default).map(|b| ScalarValue::Boolean(b)),
@@ -455,6 +491,25 @@ fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -
     }
 }
 
+/// Restrictive actions and `SET NULL` match what the migration engine uses by default, so only the
+/// remaining ones end up in the datamodel.
+fn calculate_on_delete(fk: &ForeignKey) -> Option<ReferentialAction> {
+    match fk.on_delete_action {
+        ForeignKeyAction::Cascade => Some(ReferentialAction::Cascade),
+        ForeignKeyAction::SetDefault => Some(ReferentialAction::SetDefault),
+        _ => None,
+    }
+}
+
+fn calculate_on_update(fk: &ForeignKey, column: &Column) -> Option<ReferentialAction> {
+    match fk.on_update_action {
+        ForeignKeyAction::Cascade => Some(ReferentialAction::Cascade),
+        ForeignKeyAction::SetDefault => Some(ReferentialAction::SetDefault),
+        ForeignKeyAction::SetNull if !column.is_required() => Some(ReferentialAction::SetNull),
+        _ => None,
+    }
+}
+
 fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
     // Look for a foreign key referencing this column
@@ -472,7 +527,8 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
                 name: calculate_relation_name(schema, fk, table),
                 to: fk.referenced_table.clone(),
                 to_fields: vec![referenced_col.clone()],
-                on_delete: OnDeleteStrategy::None,
+                on_delete: calculate_on_delete(fk),
+                on_update: calculate_on_update(fk, column),
             })
         }
         None => {
@@ -480,9 +536,11 @@ fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> F
             match column.tpe.family {
                 ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean),
                 ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
+                ColumnTypeFamily::Float if is_decimal_column_type(&column.tpe) => FieldType::Base(ScalarType::Decimal),
                 ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
                 ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
                 ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
+                ColumnTypeFamily::Enum(ref name) => FieldType::Enum(name.clone()),
                 // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                 // variants that don't yet have corresponding PrismaType variants
                 _ => FieldType::Base(ScalarType::String),
@@ -1,4 +1,3 @@
-use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use log::LevelFilter;
@@ -6,8 +5,7 @@ use pretty_assertions::assert_eq;
 
 use datamodel::{
     common::{ScalarType, ScalarValue},
-    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, OnDeleteStrategy, RelationInfo,
-    ScalarListStrategy,
+    dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, RelationInfo, ScalarListStrategy,
 };
 use sql_introspection_connector::calculate_datamodel::calculate_model;
 use sql_schema_describer::*;
@@ -118,6 +116,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 })
                 .collect(),
             indices: vec![],
@@ -126,9 +125,12 @@ fn a_data_model_can_be_generated_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -206,6 +208,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "required".to_string(),
@@ -216,6 +219,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "list".to_string(),
@@ -226,6 +230,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::List,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -234,9 +239,12 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -340,6 +348,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "int-default".to_string(),
@@ -350,6 +359,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: Some("'1'".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "bool-default".to_string(),
@@ -360,6 +370,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: Some("'1'".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "float-default".to_string(),
@@ -370,6 +381,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: Some("'1.0'".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "string-default".to_string(),
@@ -380,14 +392,18 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: Some("default".to_string()),
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -499,6 +515,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -506,6 +523,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "Table2".to_string(),
@@ -518,6 +537,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -525,6 +545,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "Table3".to_string(),
@@ -537,6 +559,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -548,10 +571,13 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     }),
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -616,6 +642,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "unique".to_string(),
@@ -626,6 +653,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
@@ -635,9 +663,12 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -718,7 +749,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             name: "".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -736,7 +768,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             name: "".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["name".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -770,6 +803,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -780,6 +814,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -788,6 +823,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "User".to_string(),
@@ -801,6 +838,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -811,6 +849,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                     Column {
                         name: "city-name".to_string(),
@@ -821,6 +860,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -831,12 +871,16 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -921,6 +965,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -931,6 +976,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "lastname".to_string(),
@@ -941,6 +987,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
@@ -953,9 +1000,12 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -1010,7 +1060,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             to: "User".to_string(),
                             to_fields: vec!["city-id".to_string()],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -1052,7 +1103,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             name: "CityToUser".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
                         }),
                         database_name: None,
                         default_value: None,
@@ -1086,6 +1138,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -1096,6 +1149,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1104,6 +1158,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "User".to_string(),
@@ -1117,6 +1173,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -1127,6 +1184,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1136,12 +1194,16 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["city-id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -1162,16 +1224,113 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         }],
     };
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("a".to_string());
-    enum_values.insert("b".to_string());
     let schema = SqlSchema {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
-            values: enum_values,
+            values: vec!["a".to_string(), "b".to_string()],
+        }],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    assert_eq!(data_model, ref_data_model);
+}
+
+#[test]
+fn comments_are_preserved_as_documentation_when_generating_data_model_from_a_schema() {
+    setup();
+
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "Table1".to_string(),
+            documentation: Some("The first table".to_string()),
+            is_embedded: false,
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(ScalarType::Int),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: Some(IdInfo {
+                        strategy: IdStrategy::Auto,
+                        sequence: None,
+                    }),
+                    scalar_list_strategy: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                },
+                Field {
+                    name: "price".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(ScalarType::Int),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    id_info: None,
+                    scalar_list_strategy: None,
+                    documentation: Some("In cents".to_string()),
+                    is_generated: false,
+                    is_updated_at: false,
+                },
+            ],
+            is_generated: false,
+            indexes: vec![],
+            id_fields: vec![],
         }],
+        enums: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType {
+                        raw: "integer".to_string(),
+                        family: ColumnTypeFamily::Int,
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    auto_increment: true,
+                    comment: None,
+                },
+                Column {
+                    name: "price".to_string(),
+                    tpe: ColumnType {
+                        raw: "integer".to_string(),
+                        family: ColumnTypeFamily::Int,
+                    },
+                    arity: ColumnArity::Required,
+                    default: None,
+                    auto_increment: false,
+                    comment: Some("In cents".to_string()),
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            check_constraints: vec![CheckConstraint {
+                name: Some("price_positive".to_string()),
+                expression: "price > 0".to_string(),
+            }],
+            comment: Some("The first table".to_string()),
+        }],
+        enums: vec![],
         sequences: vec![],
+        views: vec![View {
+            name: "View1".to_string(),
+            definition: "SELECT id FROM Table1".to_string(),
+        }],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -171,6 +171,29 @@ fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
     custom_assert(&result, dm);
 }
 
+#[test_one_connector(connector = "postgres")]
+fn introspecting_numeric_columns_as_decimals_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel.execute(|migration| {
+        migration.create_table("Invoice", |t| {
+            t.add_column("id", types::primary());
+            t.inject_custom("\"amount\" Numeric(10, 2) NOT NULL DEFAULT 12.50");
+            t.inject_custom("\"rate\" Decimal(28, 10)");
+            t.inject_custom("\"total\" Decimal(65, 30)");
+        });
+    });
+    let dm = r#"
+            model Invoice {
+                amount Decimal @default(12.50)
+                id      Int @id(strategy: NONE) @sequence(name: "Invoice_id_seq", allocationSize: 1, initialValue: 1)
+                rate Decimal?
+                total Float?
+            }
+        "#;
+    let result = dbg!(api.introspect());
+    custom_assert(&result, dm);
+}
+
 #[test_one_connector(connector = "postgres")]
 fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi) {
     let barrel = api.barrel();
@@ -0,0 +1,90 @@
+use super::{declarative_connector::*, ScalarType};
+
+/// The native types of the databases Prisma supports out of the box. They are used in the datamodel through the
+/// `@db.` directives, e.g. `@db.VarChar(255)`.
+///
+/// Types without an obvious Prisma type are not defined yet. The datasource types are spelled the way the database
+/// accepts them in DDL.
+pub struct BuiltinConnectors {}
+
+impl BuiltinConnectors {
+    pub fn postgres() -> DeclarativeConnector {
+        let type_aliases = vec![
+            TypeAlias::new("Int2", "SmallInt"),
+            TypeAlias::new("Int4", "Integer"),
+            TypeAlias::new("Int8", "BigInt"),
+            TypeAlias::new("Float4", "Real"),
+            TypeAlias::new("Float8", "DoublePrecision"),
+            TypeAlias::new("Bool", "Boolean"),
+            TypeAlias::new("TimestampTZ", "Timestamptz"),
+        ];
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Integer", "integer", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "real", ScalarType::Float),
+            FieldTypeConstructor::without_args("DoublePrecision", "double precision", ScalarType::Float),
+            FieldTypeConstructor::with_args("Decimal", "decimal", ScalarType::Decimal, 2),
+            FieldTypeConstructor::with_args("Numeric", "numeric", ScalarType::Decimal, 2),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("Uuid", "uuid", ScalarType::String),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::String),
+            FieldTypeConstructor::without_args("JsonB", "jsonb", ScalarType::String),
+            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Timestamp", "timestamp", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Timestamptz", "timestamptz", ScalarType::DateTime),
+        ];
+
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+        }
+    }
+
+    pub fn mysql() -> DeclarativeConnector {
+        let type_aliases = vec![TypeAlias::new("Integer", "Int")];
+        // TINYINT is left out, since it is what MySQL uses for booleans.
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("MediumInt", "mediumint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Int", "int", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Float", "float", ScalarType::Float),
+            FieldTypeConstructor::without_args("Double", "double", ScalarType::Float),
+            FieldTypeConstructor::with_args("Decimal", "decimal", ScalarType::Decimal, 2),
+            FieldTypeConstructor::with_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("TinyText", "tinytext", ScalarType::String),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("MediumText", "mediumtext", ScalarType::String),
+            FieldTypeConstructor::without_args("LongText", "longtext", ScalarType::String),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::String),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_args("DateTime", "datetime", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+        ];
+
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+        }
+    }
+
+    /// SQLite only knows storage classes, so its native types are the type affinities.
+    pub fn sqlite() -> DeclarativeConnector {
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("Integer", "INTEGER", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "REAL", ScalarType::Float),
+            FieldTypeConstructor::without_args("Numeric", "NUMERIC", ScalarType::Decimal),
+            FieldTypeConstructor::without_args("Text", "TEXT", ScalarType::String),
+        ];
+
+        DeclarativeConnector {
+            type_aliases: Vec::new(),
+            field_type_constructors,
+        }
+    }
+}
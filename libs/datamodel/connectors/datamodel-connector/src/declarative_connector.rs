@@ -1,4 +1,4 @@
-use super::{Connector, ScalarFieldType, ScalarType};
+use super::{error::ConnectorError, Connector, ScalarFieldType, ScalarType};
 
 #[derive(Debug)]
 pub struct DeclarativeConnector {
@@ -7,19 +7,29 @@ pub struct DeclarativeConnector {
 }
 
 impl Connector for DeclarativeConnector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType> {
-        match self.get_type_alias(name) {
-            Some(alias) => self.calculate_type(&alias.aliased_to, args),
-            None => self.get_field_type_constructor(&name).map(|constructor| {
-                let datasource_type = constructor.datasource_type(&args);
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, ConnectorError> {
+        if let Some(alias) = self.get_type_alias(name) {
+            return self.calculate_type(&alias.aliased_to, args);
+        }
+
+        let constructor = self
+            .get_field_type_constructor(&name)
+            .ok_or_else(|| ConnectorError::TypeNotFound { name: name.to_string() })?;
 
-                ScalarFieldType {
-                    name: name.to_string(),
-                    prisma_type: constructor.prisma_type,
-                    datasource_type,
-                }
-            }),
+        if constructor.number_of_args != args.len() {
+            return Err(ConnectorError::ArgumentCountMismatch {
+                name: name.to_string(),
+                required_count: constructor.number_of_args,
+                given_count: args.len(),
+            });
         }
+
+        Ok(ScalarFieldType {
+            name: constructor.name.clone(),
+            prisma_type: constructor.prisma_type,
+            datasource_type: constructor.datasource_type(&args),
+            args,
+        })
     }
 }
 
@@ -1,2 +1,33 @@
-#[allow(unused)]
-enum ConnectorError {}
+use std::fmt;
+
+/// Errors of resolving a connector specific type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectorError {
+    /// The connector does not define a type or type alias with this name.
+    TypeNotFound { name: String },
+    /// The type was given a different number of arguments than its constructor takes.
+    ArgumentCountMismatch {
+        name: String,
+        required_count: usize,
+        given_count: usize,
+    },
+}
+
+impl fmt::Display for ConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConnectorError::TypeNotFound { name } => {
+                write!(f, "Native type `{}` is not supported by the connector.", name)
+            }
+            ConnectorError::ArgumentCountMismatch {
+                name,
+                required_count,
+                given_count,
+            } => write!(
+                f,
+                "Native type `{}` takes {} arguments, but received {}.",
+                name, required_count, given_count
+            ),
+        }
+    }
+}
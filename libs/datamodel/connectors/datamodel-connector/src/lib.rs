@@ -3,13 +3,14 @@ use crate::scalars::ScalarType;
 pub mod error;
 pub mod scalars;
 
+mod builtin_connectors;
 mod declarative_connector;
-mod example_connector;
 
-pub use example_connector::ExampleConnector;
+pub use builtin_connectors::BuiltinConnectors;
+pub use declarative_connector::{DeclarativeConnector, FieldTypeConstructor, TypeAlias};
 
 pub trait Connector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType>;
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, error::ConnectorError>;
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,21 +18,38 @@ pub struct ScalarFieldType {
     name: String,
     prisma_type: scalars::ScalarType,
     datasource_type: String,
+    args: Vec<i32>,
 }
 
 impl ScalarFieldType {
     pub fn new(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str) -> Self {
+        Self::with_args(name, prisma_type, datasource_type, Vec::new())
+    }
+
+    pub fn with_args(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str, args: Vec<i32>) -> Self {
         ScalarFieldType {
             name: name.to_string(),
             prisma_type,
             datasource_type: datasource_type.to_string(),
+            args,
         }
     }
 
+    /// The name of the native type, as used in the `@db.` directive.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments the native type was given, e.g. the length of a `VarChar`.
+    pub fn args(&self) -> &[i32] {
+        &self.args
+    }
+
     pub fn prisma_type(&self) -> scalars::ScalarType {
         self.prisma_type
     }
 
+    /// The type as rendered in DDL, including its arguments, e.g. `varchar(255)`.
     pub fn datasource_type(&self) -> &str {
         &self.datasource_type
     }
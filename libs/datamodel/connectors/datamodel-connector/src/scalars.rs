@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Prisma's builtin scalar types.
@@ -44,7 +45,7 @@ impl ToString for ScalarType {
 pub enum ScalarValue {
     Int(i32),
     Float(f32),
-    Decimal(f32),
+    Decimal(Decimal),
     Boolean(bool),
     String(String),
     DateTime(DateTime<Utc>),
@@ -7,6 +7,7 @@ use super::interpolation::StringInterpolator;
 use super::FromStrAndSpan;
 use super::{ScalarType, ScalarValue};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use std::error;
 
 #[derive(Debug, Clone)]
@@ -170,15 +171,16 @@ impl ValueValidator {
         }
     }
 
-    // TODO: Ask which decimal type to take.
     /// Tries to convert the wrapped value to a Prisma Decimal.
-    pub fn as_decimal(&self) -> Result<f32, DatamodelError> {
+    ///
+    /// The value is parsed from its literal representation, so no precision is lost.
+    pub fn as_decimal(&self) -> Result<Decimal, DatamodelError> {
         match &self.value {
             MaybeExpression::Value(_, ast::Expression::NumericValue(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
+                self.wrap_error_from_result(value.parse::<Decimal>(), "numeric")
             }
             MaybeExpression::Value(_, ast::Expression::Any(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
+                self.wrap_error_from_result(value.parse::<Decimal>(), "numeric")
             }
             _ => Err(self.construct_error("numeric")),
         }
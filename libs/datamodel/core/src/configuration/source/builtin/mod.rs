@@ -11,3 +11,15 @@ pub use postgres_source::*;
 pub use postgres_source_definition::*;
 pub use sqlite_source::*;
 pub use sqlite_source_definition::*;
+
+use datamodel_connector::{BuiltinConnectors, Connector};
+
+/// The connector defining the native types of a builtin source, by connector type.
+pub fn builtin_connector(connector_type: &str) -> Option<Box<dyn Connector>> {
+    match connector_type {
+        POSTGRES_SOURCE_NAME => Some(Box::new(BuiltinConnectors::postgres())),
+        MYSQL_SOURCE_NAME => Some(Box::new(BuiltinConnectors::mysql())),
+        SQLITE_SOURCE_NAME => Some(Box::new(BuiltinConnectors::sqlite())),
+        _ => None,
+    }
+}
@@ -28,6 +28,17 @@ pub enum FieldType {
     Base(ScalarType),
 }
 
+impl FieldType {
+    /// The Prisma scalar type of a scalar field, including fields with a connector specific type.
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        match self {
+            FieldType::Base(scalar_type) => Some(*scalar_type),
+            FieldType::ConnectorSpecific(sft) => Some(sft.prisma_type()),
+            _ => None,
+        }
+    }
+}
+
 /// Holds information about an id, or priamry key.
 #[derive(Debug, PartialEq, Clone)]
 pub struct IdInfo {
@@ -19,3 +19,4 @@ pub use traits::*;
 
 // Compatibility exports.
 pub use datamodel_connector::scalars::{ScalarType, ScalarValue};
+pub use datamodel_connector::ScalarFieldType;
@@ -9,9 +9,12 @@ pub struct RelationInfo {
     pub to_fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
-    /// A strategy indicating what happens when
-    /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    /// What the database does with records of this field's model when
+    /// the referenced record is deleted. `None` means the default applies.
+    pub on_delete: Option<ReferentialAction>,
+    /// What the database does with records of this field's model when
+    /// the referenced record is updated. `None` means the default applies.
+    pub on_update: Option<ReferentialAction>,
 }
 
 impl RelationInfo {
@@ -22,7 +25,8 @@ impl RelationInfo {
             to: String::from(to),
             to_fields: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
     pub fn new_with_field(to: &str, to_field: &str) -> RelationInfo {
@@ -30,7 +34,8 @@ impl RelationInfo {
             to: String::from(to),
             to_fields: vec![String::from(to_field)],
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
 
@@ -39,38 +44,50 @@ impl RelationInfo {
             to: String::from(to),
             to_fields,
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
 }
 
-/// Describes what happens when related nodes
-/// are deleted.
+/// Describes what happens to referencing records
+/// when the referenced record is deleted or updated.
 #[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
+pub enum ReferentialAction {
     Cascade,
-    None,
+    Restrict,
+    NoAction,
+    SetNull,
+    SetDefault,
 }
 
-impl Parsable for OnDeleteStrategy {
+impl Parsable for ReferentialAction {
     fn parse(s: &str) -> Option<Self> {
         match s {
-            "CASCADE" => Some(OnDeleteStrategy::Cascade),
-            "NONE" => Some(OnDeleteStrategy::None),
+            "Cascade" => Some(ReferentialAction::Cascade),
+            "Restrict" => Some(ReferentialAction::Restrict),
+            "NoAction" => Some(ReferentialAction::NoAction),
+            "SetNull" => Some(ReferentialAction::SetNull),
+            "SetDefault" => Some(ReferentialAction::SetDefault),
+            // Legacy spelling of the former onDelete strategy.
+            "CASCADE" => Some(ReferentialAction::Cascade),
             _ => None,
         }
     }
 
     fn descriptor() -> &'static str {
-        "onDelete strategy"
+        "referential action"
     }
 }
 
-impl ToString for OnDeleteStrategy {
+impl ToString for ReferentialAction {
     fn to_string(&self) -> String {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => String::from("Cascade"),
+            ReferentialAction::Restrict => String::from("Restrict"),
+            ReferentialAction::NoAction => String::from("NoAction"),
+            ReferentialAction::SetNull => String::from("SetNull"),
+            ReferentialAction::SetDefault => String::from("SetDefault"),
         }
     }
 }
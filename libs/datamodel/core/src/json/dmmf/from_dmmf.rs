@@ -4,6 +4,7 @@ use crate::common::FromStrAndSpan;
 use crate::common::ScalarType;
 use crate::dml;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 
 pub fn parse_from_dmmf(dmmf: &str) -> dml::Datamodel {
     let parsed_dmmf = serde_json::from_str::<Datamodel>(&dmmf).expect("Failed to parse JSON");
@@ -83,8 +84,11 @@ fn default_value_from_serde(
                 dml::ScalarValue::Float(val.as_f64().unwrap() as f32)
             }
             (serde_json::Value::Number(val), ScalarType::Int) => dml::ScalarValue::Int(val.as_i64().unwrap() as i32),
+            (serde_json::Value::String(val), ScalarType::Decimal) => {
+                dml::ScalarValue::Decimal(val.parse::<Decimal>().unwrap())
+            }
             (serde_json::Value::Number(val), ScalarType::Decimal) => {
-                dml::ScalarValue::Decimal(val.as_f64().unwrap() as f32)
+                dml::ScalarValue::Decimal(val.to_string().parse::<Decimal>().unwrap())
             }
             (serde_json::Value::String(val), ScalarType::DateTime) => {
                 dml::ScalarValue::DateTime(String::from(val.as_str()).parse::<DateTime<Utc>>().unwrap())
@@ -125,10 +129,10 @@ fn function_from_dmmf(func: &Function, expected_type: ScalarType) -> dml::Scalar
     dml::ScalarValue::Expression(func.name.clone(), expected_type, vec![])
 }
 
-fn get_on_delete_strategy(strategy: &Option<String>) -> dml::OnDeleteStrategy {
-    match strategy {
-        Some(val) => dml::OnDeleteStrategy::from_str_and_span(&val, Span::empty()).unwrap(),
-        None => dml::OnDeleteStrategy::None,
+fn get_referential_action(action: &Option<String>) -> Option<dml::ReferentialAction> {
+    match action.as_ref().map(String::as_str) {
+        Some("NONE") | None => None,
+        Some(val) => Some(dml::ReferentialAction::from_str_and_span(val, Span::empty()).unwrap()),
     }
 }
 
@@ -138,7 +142,8 @@ fn get_field_type(field: &Field) -> dml::FieldType {
             to: field.field_type.clone(),
             to_fields: field.relation_to_fields.clone().unwrap_or_default(),
             name: field.relation_name.clone().unwrap_or(String::new()),
-            on_delete: get_on_delete_strategy(&field.relation_on_delete),
+            on_delete: get_referential_action(&field.relation_on_delete),
+            on_update: get_referential_action(&field.relation_on_update),
         }),
         "enum" => dml::FieldType::Enum(field.field_type.clone()),
         "scalar" => dml::FieldType::Base(type_from_string(&field.field_type)),
@@ -63,7 +63,8 @@ fn field_to_dmmf(field: &dml::Field) -> Field {
         is_unique: field.is_unique,
         relation_name: get_relation_name(field),
         relation_to_fields: get_relation_to_fields(field),
-        relation_on_delete: get_relation_delete_strategy(field),
+        relation_on_delete: get_relation_on_delete(field),
+        relation_on_update: get_relation_on_update(field),
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated),
         is_updated_at: Some(field.is_updated_at),
@@ -75,7 +76,7 @@ fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
         dml::FieldType::Relation(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
-        dml::FieldType::Base(_) => String::from("scalar"),
+        dml::FieldType::Base(_) | dml::FieldType::ConnectorSpecific(_) => String::from("scalar"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
     }
 }
@@ -107,7 +108,7 @@ fn value_to_serde(value: &dml::ScalarValue) -> serde_json::Value {
         dml::ScalarValue::ConstantLiteral(val) => serde_json::Value::String(val.clone()),
         dml::ScalarValue::Float(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::ScalarValue::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
-        dml::ScalarValue::Decimal(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
+        dml::ScalarValue::Decimal(val) => serde_json::Value::String(val.to_string()),
         dml::ScalarValue::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
         dml::ScalarValue::Expression(name, return_type, args) => function_to_serde(&name, *return_type, &args),
     }
@@ -137,9 +138,16 @@ fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
     }
 }
 
-fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
+fn get_relation_on_delete(field: &dml::Field) -> Option<String> {
     match &field.field_type {
-        dml::FieldType::Relation(relation_info) => Some(relation_info.on_delete.to_string()),
+        dml::FieldType::Relation(relation_info) => relation_info.on_delete.map(|action| action.to_string()),
+        _ => None,
+    }
+}
+
+fn get_relation_on_update(field: &dml::Field) -> Option<String> {
+    match &field.field_type {
+        dml::FieldType::Relation(relation_info) => relation_info.on_update.map(|action| action.to_string()),
         _ => None,
     }
 }
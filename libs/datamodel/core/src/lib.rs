@@ -83,28 +83,70 @@ pub fn lift_ast(ast: &ast::SchemaAst) -> Result<Datamodel, error::ErrorCollectio
     }
 }
 
+/// Validates a [Schema AST](/ast/struct.SchemaAst.html) and returns its
+/// [Datamodel](/struct.Datamodel.html). Native types are resolved with the
+/// connector of the given connector type, e.g. `postgresql`.
+pub fn lift_ast_for_connector_type(
+    ast: &ast::SchemaAst,
+    connector_type: &str,
+) -> Result<Datamodel, error::ErrorCollection> {
+    let mut errors = error::ErrorCollection::new();
+    let validator = ValidationPipeline::with_connector_type(&[], connector_type);
+
+    match validator.validate(&ast) {
+        Ok(src) => Ok(src),
+        Err(mut err) => {
+            errors.append(&mut err);
+            Err(errors)
+        }
+    }
+}
+
+/// Parses and validates a datamodel string, using core attributes only.
+/// Native types are resolved with the connector of the given connector type.
+pub fn parse_datamodel_for_connector_type(
+    datamodel_string: &str,
+    connector_type: &str,
+) -> Result<Datamodel, error::ErrorCollection> {
+    let ast = ast::parser::parse(datamodel_string)?;
+
+    lift_ast_for_connector_type(&ast, connector_type)
+}
+
 /// Parses and validates a datamodel string, using core attributes only.
 /// In case of an error, a pretty, colorful string is returned.
 pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str) -> Result<Datamodel, String> {
-    match parse_datamodel_with_sources(datamodel_string, vec![]) {
-        Ok(dml) => Ok(dml),
-        Err(errs) => {
-            let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
-
-            for error in errs.to_iter() {
-                writeln!(&mut buffer).expect("Failed to render error.");
-                error
-                    .pretty_print(&mut buffer, file_name, datamodel_string)
-                    .expect("Failed to render error.");
-            }
+    parse_datamodel_with_sources(datamodel_string, vec![])
+        .map_err(|errs| pretty_print_errors(&errs, datamodel_string, file_name))
+}
 
-            Err(String::from_utf8(buffer.into_inner()).expect("Failed to convert error buffer."))
-        }
+/// Parses and validates a datamodel string, resolving native types for the given connector type.
+/// In case of an error, a pretty, colorful string is returned.
+pub fn parse_datamodel_for_connector_type_or_pretty_error(
+    datamodel_string: &str,
+    file_name: &str,
+    connector_type: &str,
+) -> Result<Datamodel, String> {
+    parse_datamodel_for_connector_type(datamodel_string, connector_type)
+        .map_err(|errs| pretty_print_errors(&errs, datamodel_string, file_name))
+}
+
+fn pretty_print_errors(errs: &error::ErrorCollection, datamodel_string: &str, file_name: &str) -> String {
+    let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
+
+    for error in errs.to_iter() {
+        writeln!(&mut buffer).expect("Failed to render error.");
+        error
+            .pretty_print(&mut buffer, file_name, datamodel_string)
+            .expect("Failed to render error.");
     }
+
+    String::from_utf8(buffer.into_inner()).expect("Failed to convert error buffer.")
 }
 
 /// Parses and validates a datamodel string, using core attributes and the given sources.
-/// If source loading failes, validation continues, but an error is returned.
+/// If source loading failes, validation continues, but an error is returned. Native types
+/// are then resolved with the connector of the provider the datasource declares.
 pub fn parse_datamodel_with_sources(
     datamodel_string: &str,
     source_definitions: Vec<Box<dyn configuration::SourceDefinition>>,
@@ -113,14 +155,38 @@ pub fn parse_datamodel_with_sources(
 
     let mut errors = error::ErrorCollection::new();
 
-    let sources = match load_sources(&ast, source_definitions) {
-        Ok(src) => src,
+    let validator = match load_sources(&ast, source_definitions) {
+        Ok(sources) => ValidationPipeline::with_sources(&sources),
         Err(mut err) => {
             errors.append(&mut err);
-            Vec::new()
+
+            match declared_connector_type(&ast) {
+                Some(connector_type) => ValidationPipeline::with_connector_type(&[], &connector_type),
+                None => ValidationPipeline::with_sources(&[]),
+            }
         }
     };
-    let validator = ValidationPipeline::with_sources(&sources);
+
+    match validator.validate(&ast) {
+        Ok(src) => Ok(src),
+        Err(mut err) => {
+            errors.append(&mut err);
+            Err(errors)
+        }
+    }
+}
+
+/// Parses and validates a datamodel string, using core attributes and the given, already
+/// loaded datasources, e.g. the ones of a configuration with overwritten urls. Native types
+/// are resolved with the connector of the first datasource.
+pub fn parse_datamodel_with_datasources(
+    datamodel_string: &str,
+    datasources: &[Box<dyn Source>],
+) -> Result<Datamodel, error::ErrorCollection> {
+    let ast = ast::parser::parse(datamodel_string)?;
+
+    let mut errors = error::ErrorCollection::new();
+    let validator = ValidationPipeline::with_sources(datasources);
 
     match validator.validate(&ast) {
         Ok(src) => Ok(src),
@@ -166,6 +232,20 @@ fn load_sources(
     source_loader.load(&schema_ast)
 }
 
+/// The connector type the provider of the first datasource names, read without loading the
+/// datasource, e.g. when an environment variable of its url is not set.
+fn declared_connector_type(schema_ast: &SchemaAst) -> Option<String> {
+    let source = schema_ast.sources().into_iter().next()?;
+    let mut args = common::argument::Arguments::new(&source.properties, source.span);
+    let provider = args.arg("provider").ok()?.as_str().ok()?;
+
+    // The source loader accepts `postgres` as well.
+    match provider.as_str() {
+        "postgres" => Some(POSTGRES_SOURCE_NAME.to_owned()),
+        _ => Some(provider),
+    }
+}
+
 //
 //  ************** RENDERING FUNCTIONS **************
 //
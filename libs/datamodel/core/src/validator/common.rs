@@ -3,6 +3,8 @@ use crate::{ast, dml, error::DatamodelError};
 /// State error message. Seeing this error means something went really wrong internally. It's the datamodel equivalent of a bluescreen.
 pub (crate) const STATE_ERROR: &str = "Failed lookup of model or field during internal processing. This means that the internal representation was mutated incorrectly.";
 pub (crate) const ERROR_GEN_STATE_ERROR: &str = "Failed lookup of model or field during generating an error message. This often means that a generated field or model was the cause of an error.";
+/// The namespace of the directives specifying a native type, e.g. `@db.VarChar(255)`.
+pub(crate) const NATIVE_TYPE_DIRECTIVE_PREFIX: &str = "db.";
 
 impl ast::WithDirectives for Vec<ast::Directive> {
     fn directives(&self) -> &Vec<ast::Directive> {
@@ -12,6 +12,8 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
         &"relation"
     }
     fn validate_and_apply(&self, args: &mut Args, field: &mut dml::Field) -> Result<(), DatamodelError> {
+        let arity = field.arity;
+
         if let dml::FieldType::Relation(relation_info) = &mut field.field_type {
             if let Ok(name_arg) = args.default_arg("name") {
                 let name = name_arg.as_str()?;
@@ -27,8 +29,43 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 relation_info.to_fields = related_fields.as_array()?.to_literal_vec()?;
             }
 
-            if let Ok(on_delete) = args.arg("onDelete") {
-                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
+            for (arg_name, target) in vec![
+                ("onDelete", &mut relation_info.on_delete),
+                ("onUpdate", &mut relation_info.on_update),
+            ] {
+                if let Ok(value) = args.arg(arg_name) {
+                    // `NONE` is the legacy way of not specifying an action.
+                    if value.as_constant_literal()? == "NONE" {
+                        continue;
+                    }
+
+                    let action = value.parse_literal::<dml::ReferentialAction>()?;
+
+                    // Earlier versions declared cascading deletes on the list side of the relation. That keeps working.
+                    let is_legacy_cascade = arg_name == "onDelete" && action == dml::ReferentialAction::Cascade;
+
+                    if arity == dml::FieldArity::List && !is_legacy_cascade {
+                        return self.error(
+                            &format!(
+                                "The `{}` argument must be defined on the side of the relation that holds the foreign key, not on a list field.",
+                                arg_name
+                            ),
+                            value.span(),
+                        );
+                    }
+
+                    if action == dml::ReferentialAction::SetNull && arity == dml::FieldArity::Required {
+                        return self.error(
+                            &format!(
+                                "The referential action `SetNull` in `{}` is not allowed on a required relation field.",
+                                arg_name
+                            ),
+                            value.span(),
+                        );
+                    }
+
+                    *target = Some(action);
+                }
             }
 
             Ok(())
@@ -79,11 +116,17 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 args.push(ast::Argument::new_array("references", related_fields));
             }
 
-            if relation_info.on_delete != dml::OnDeleteStrategy::None {
-                args.push(ast::Argument::new_constant(
-                    "onDelete",
-                    &relation_info.on_delete.to_string(),
-                ));
+            match &relation_info.on_delete {
+                // List fields only carry the legacy cascade, which keeps its legacy spelling.
+                Some(dml::ReferentialAction::Cascade) if field.arity == dml::FieldArity::List => {
+                    args.push(ast::Argument::new_constant("onDelete", "CASCADE"))
+                }
+                Some(on_delete) => args.push(ast::Argument::new_constant("onDelete", &on_delete.to_string())),
+                None => (),
+            }
+
+            if let Some(on_update) = &relation_info.on_update {
+                args.push(ast::Argument::new_constant("onUpdate", &on_update.to_string()));
             }
 
             if !args.is_empty() {
@@ -11,7 +11,7 @@ impl DirectiveValidator<dml::Field> for UpdatedAtDirectiveValidator {
     }
 
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
-        if obj.field_type != dml::FieldType::Base(dml::ScalarType::DateTime) {
+        if obj.field_type.scalar_type() != Some(dml::ScalarType::DateTime) {
             return self.error(
                 "Fields that are marked with @updatedAt must be of type DateTime.",
                 args.span(),
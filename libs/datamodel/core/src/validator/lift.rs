@@ -1,4 +1,4 @@
-use super::DirectiveBox;
+use super::{common::NATIVE_TYPE_DIRECTIVE_PREFIX, DirectiveBox};
 use crate::{
     ast,
     common::value::ValueValidator,
@@ -6,7 +6,7 @@ use crate::{
     configuration, dml,
     error::{DatamodelError, ErrorCollection},
 };
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::Connector;
 
 /// Helper for lifting a datamodel.
 ///
@@ -15,15 +15,15 @@ use datamodel_connector::{Connector, ExampleConnector};
 /// additional semantics are attached.
 pub struct LiftAstToDml {
     directives: DirectiveBox,
+    connector: Option<Box<dyn Connector>>,
 }
 
-const USE_CONNECTORS_FOR_CUSTOM_TYPES: bool = false; // FEATURE FLAG
-
 impl LiftAstToDml {
     /// Creates a new instance, with all builtin directives registered.
     pub fn new() -> Self {
         Self {
             directives: DirectiveBox::new(),
+            connector: None,
         }
     }
 
@@ -31,9 +31,22 @@ impl LiftAstToDml {
     /// the directives defined by the given sources registered.
     ///
     /// The directives defined by the given sources will be namespaced.
+    /// Native types are resolved with the connector of the first source.
     pub fn with_sources(sources: &[Box<dyn configuration::Source>]) -> LiftAstToDml {
+        let connector_type = sources.first().map(|source| source.connector_type());
+
+        Self::with_sources_and_connector_type(sources, connector_type)
+    }
+
+    /// Creates a new instance like `with_sources`, but native types are resolved with the
+    /// connector of the given connector type.
+    pub fn with_sources_and_connector_type(
+        sources: &[Box<dyn configuration::Source>],
+        connector_type: Option<&str>,
+    ) -> LiftAstToDml {
         LiftAstToDml {
             directives: DirectiveBox::with_sources(sources),
+            connector: connector_type.and_then(configuration::builtin_connector),
         }
     }
 
@@ -117,6 +130,22 @@ impl LiftAstToDml {
         // If we cannot parse the field type, we exit right away.
         let (field_type, extra_attributes) = self.lift_field_type(&ast_field, ast_schema, &mut Vec::new())?;
 
+        // We merge arttributes so we can fail on duplicates.
+        let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
+
+        // Native types are not regular directives, they refine the field type.
+        let (native_type_directives, attributes): (Vec<ast::Directive>, Vec<ast::Directive>) = attributes
+            .into_iter()
+            .partition(|directive| directive.name.name.starts_with(NATIVE_TYPE_DIRECTIVE_PREFIX));
+
+        let field_type = match self.lift_native_type(field_type, &native_type_directives) {
+            Ok(field_type) => field_type,
+            Err(err) => {
+                errors.push(err);
+                return Err(errors);
+            }
+        };
+
         let mut field = dml::Field::new(&ast_field.name.name, field_type.clone());
         field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
         field.arity = self.lift_field_arity(&ast_field.arity);
@@ -124,8 +153,8 @@ impl LiftAstToDml {
         if let Some(value) = &ast_field.default_value {
             let validator = ValueValidator::new(value)?;
 
-            if let dml::FieldType::Base(base_type) = &field_type {
-                match validator.as_type(*base_type) {
+            if let Some(scalar_type) = field_type.scalar_type() {
+                match validator.as_type(scalar_type) {
                     Ok(val) => field.default_value = Some(val),
                     Err(err) => errors.push(err),
                 };
@@ -137,9 +166,6 @@ impl LiftAstToDml {
             }
         }
 
-        // We merge arttributes so we can fail on duplicates.
-        let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
-
         if let Err(mut err) = self.directives.field.validate_and_apply(&attributes, &mut field) {
             errors.append(&mut err);
         }
@@ -151,6 +177,84 @@ impl LiftAstToDml {
         }
     }
 
+    /// Internal: Resolve the native type directive of a field, if it has one, with the connector of the datasource.
+    fn lift_native_type(
+        &self,
+        field_type: dml::FieldType,
+        native_type_directives: &[ast::Directive],
+    ) -> Result<dml::FieldType, DatamodelError> {
+        let directive = match native_type_directives {
+            [] => return Ok(field_type),
+            [directive] => directive,
+            [_, duplicate, ..] => {
+                return Err(DatamodelError::new_duplicate_directive_error(
+                    NATIVE_TYPE_DIRECTIVE_PREFIX.trim_end_matches('.'),
+                    duplicate.span,
+                ))
+            }
+        };
+
+        let directive_name = &directive.name.name;
+        let type_name = directive_name.trim_start_matches(NATIVE_TYPE_DIRECTIVE_PREFIX);
+
+        let scalar_type = match field_type {
+            dml::FieldType::Base(scalar_type) => scalar_type,
+            _ => {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "Native types can only be used on scalar fields.",
+                    directive_name,
+                    directive.span,
+                ))
+            }
+        };
+
+        let connector = self.connector.as_ref().ok_or_else(|| {
+            DatamodelError::new_directive_validation_error(
+                "Native types can only be used with a datasource whose connector defines them.",
+                directive_name,
+                directive.span,
+            )
+        })?;
+
+        let mut args = Vec::with_capacity(directive.arguments.len());
+
+        for argument in &directive.arguments {
+            let value = match (&argument.name.name[..], &argument.value) {
+                ("", ast::Expression::NumericValue(value, _)) => value.parse::<i32>().ok(),
+                _ => None,
+            };
+
+            match value {
+                Some(value) => args.push(value),
+                None => {
+                    return Err(DatamodelError::new_directive_validation_error(
+                        "The arguments of native types must be unnamed integers.",
+                        directive_name,
+                        argument.span,
+                    ))
+                }
+            }
+        }
+
+        let native_type = connector.calculate_type(type_name, args).map_err(|err| {
+            DatamodelError::new_directive_validation_error(&err.to_string(), directive_name, directive.span)
+        })?;
+
+        if native_type.prisma_type() != scalar_type {
+            return Err(DatamodelError::new_directive_validation_error(
+                &format!(
+                    "Native type `{}` can only be used on fields of type `{}`.",
+                    native_type.name(),
+                    native_type.prisma_type().to_string()
+                ),
+                directive_name,
+                directive.span,
+            ));
+        }
+
+        Ok(dml::FieldType::ConnectorSpecific(native_type))
+    }
+
     /// Internal: Lift a field's arity.
     fn lift_field_arity(&self, ast_field: &ast::FieldArity) -> dml::FieldArity {
         match ast_field {
@@ -171,24 +275,7 @@ impl LiftAstToDml {
         let type_name = &ast_field.field_type.name;
 
         if let Ok(scalar_type) = ScalarType::from_str(type_name) {
-            if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-                let pg_connector = ExampleConnector::postgres();
-                let args = vec![]; // TODO: figure out args
-                let pg_type_specification = ast_field
-                    .directives
-                    .iter()
-                    .find(|dir| dir.name.name.starts_with("pg.")) // we use find because there should be at max 1.
-                    .map(|dir| dir.name.name.trim_start_matches("pg."));
-
-                if let Some(x) = pg_type_specification.and_then(|ts| pg_connector.calculate_type(&ts, args)) {
-                    let field_type = dml::FieldType::ConnectorSpecific(x);
-                    Ok((field_type, vec![]))
-                } else {
-                    Ok((dml::FieldType::Base(scalar_type), vec![]))
-                }
-            } else {
-                Ok((dml::FieldType::Base(scalar_type), vec![]))
-            }
+            Ok((dml::FieldType::Base(scalar_type), vec![]))
         } else if ast_schema.find_model(type_name).is_some() {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
@@ -231,19 +318,6 @@ impl LiftAstToDml {
 
             attrs.append(&mut custom_type.directives.clone());
             Ok((field_type, attrs))
-        } else if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-            let pg_connector = ExampleConnector::postgres();
-            let args = vec![]; // TODO: figure out args
-
-            if let Some(x) = pg_connector.calculate_type(&ast_field.field_type.name, args) {
-                let field_type = dml::FieldType::ConnectorSpecific(x);
-                Ok((field_type, vec![]))
-            } else {
-                Err(DatamodelError::new_type_not_found_error(
-                    type_name,
-                    ast_field.field_type.span,
-                ))
-            }
         } else {
             Err(DatamodelError::new_type_not_found_error(
                 type_name,
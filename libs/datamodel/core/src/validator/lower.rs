@@ -2,6 +2,7 @@ use super::{common::*, DirectiveBox};
 use crate::configuration;
 use crate::error::ErrorCollection;
 use crate::{ast, dml};
+use datamodel_connector::ScalarFieldType;
 
 pub struct LowerDmlToAst {
     directives: DirectiveBox,
@@ -98,17 +99,37 @@ impl LowerDmlToAst {
         model: &dml::Model,
         datamodel: &dml::Datamodel,
     ) -> Result<ast::Field, ErrorCollection> {
+        let mut directives = self.directives.field.serialize(field, datamodel)?;
+
+        if let dml::FieldType::ConnectorSpecific(native_type) = &field.field_type {
+            directives.push(self.lower_native_type(native_type));
+        }
+
         Ok(ast::Field {
             name: ast::Identifier::new(&field.name),
             arity: self.lower_field_arity(field.arity),
             default_value: field.default_value.clone().map(|v| v.into()),
-            directives: self.directives.field.serialize(field, datamodel)?,
+            directives,
             field_type: self.lower_type(&field.field_type, field, model, &datamodel),
             documentation: field.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
         })
     }
 
+    /// Internal: Lowers a connector specific type to its `@db.` directive.
+    fn lower_native_type(&self, native_type: &ScalarFieldType) -> ast::Directive {
+        let arguments = native_type
+            .args()
+            .iter()
+            .map(|arg| ast::Argument::new("", ast::Expression::NumericValue(arg.to_string(), ast::Span::empty())))
+            .collect();
+
+        ast::Directive::new(
+            &format!("{}{}", NATIVE_TYPE_DIRECTIVE_PREFIX, native_type.name()),
+            arguments,
+        )
+    }
+
     /// Internal: Lowers a field's arity.
     fn lower_field_arity(&self, field_arity: dml::FieldArity) -> ast::FieldArity {
         match field_arity {
@@ -129,6 +150,9 @@ impl LowerDmlToAst {
         match field_type {
             dml::FieldType::Base(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::ConnectorSpecific(native_type) => {
+                ast::Identifier::new(&native_type.prisma_type().to_string())
+            }
             dml::FieldType::Relation(rel) => {
                 let related_model = datamodel.find_model(&rel.to).expect(STATE_ERROR);
 
@@ -154,7 +178,6 @@ impl LowerDmlToAst {
                     ast::Identifier::new(&rel.to)
                 }
             }
-            _ => unimplemented!("Connector specific types are not supported atm."),
         }
     }
 }
@@ -1,5 +1,5 @@
 use super::common::*;
-use crate::{ast, common::names::*, dml, error::ErrorCollection, OnDeleteStrategy};
+use crate::{ast, common::names::*, dml, error::ErrorCollection};
 use prisma_inflector;
 
 /// Helper for standardsing a datamodel.
@@ -159,7 +159,8 @@ impl Standardiser {
                 to: model.name.clone(),
                 to_fields: model.id_field_names(),
                 name: String::from(relation_name), // Will be corrected in later step
-                on_delete: dml::OnDeleteStrategy::None,
+                on_delete: None,
+                on_update: None,
             }),
         )
     }
@@ -288,7 +289,8 @@ impl Standardiser {
                         to: model.name.clone(),
                         to_fields: vec![],
                         name: rel.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
                     };
 
                     let (arity, field_name) = if field.arity.is_singular() {
@@ -73,10 +73,14 @@ impl Validator {
 
     fn validate_id_fields_valid(&self, ast_schema: &ast::SchemaAst, model: &dml::Model) -> Result<(), DatamodelError> {
         for id_field in model.singular_id_fields() {
-            let is_valid = match (&id_field.default_value, &id_field.field_type, &id_field.arity) {
+            let is_valid = match (
+                &id_field.default_value,
+                id_field.field_type.scalar_type(),
+                &id_field.arity,
+            ) {
                 (
                     Some(dml::ScalarValue::Expression(name, return_type, args)),
-                    dml::FieldType::Base(dml::ScalarType::String),
+                    Some(dml::ScalarType::String),
                     dml::FieldArity::Required,
                 ) => {
                     let name_eq = name == "cuid" || name == "uuid";
@@ -85,7 +89,7 @@ impl Validator {
 
                     name_eq && type_eq && args_eq
                 }
-                (None, dml::FieldType::Base(dml::ScalarType::Int), dml::FieldArity::Required) => true,
+                (None, Some(dml::ScalarType::Int), dml::FieldArity::Required) => true,
                 _ => false,
             };
 
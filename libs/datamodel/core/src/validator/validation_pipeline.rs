@@ -31,6 +31,16 @@ impl ValidationPipeline {
         }
     }
 
+    /// Creates a new instance like `with_sources`, but native types are resolved with the
+    /// connector of the given connector type, regardless of the sources.
+    pub fn with_connector_type(sources: &[Box<dyn configuration::Source>], connector_type: &str) -> ValidationPipeline {
+        ValidationPipeline {
+            lifter: LiftAstToDml::with_sources_and_connector_type(sources, Some(connector_type)),
+            validator: Validator::new(),
+            standardiser: Standardiser::new(),
+        }
+    }
+
     /// Validates an AST semantically and promotes it to a datamodel/schema.
     ///
     /// This method will attempt to
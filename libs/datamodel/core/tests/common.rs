@@ -10,7 +10,8 @@ pub trait FieldAsserts {
     fn assert_connector_type(&self, sft: &ScalarFieldType) -> &Self;
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_on_delete(&self, t: Option<dml::ReferentialAction>) -> &Self;
+    fn assert_relation_on_update(&self, t: Option<dml::ReferentialAction>) -> &Self;
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self;
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
@@ -98,7 +99,7 @@ impl FieldAsserts for dml::Field {
         self
     }
 
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self {
+    fn assert_relation_on_delete(&self, t: Option<dml::ReferentialAction>) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.on_delete, t);
         } else {
@@ -108,6 +109,16 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_relation_on_update(&self, t: Option<dml::ReferentialAction>) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.on_update, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.to_fields, t);
@@ -29,7 +29,7 @@ fn should_set_default_for_all_scalar_types() {
     user_model
         .assert_has_field("decimal")
         .assert_base_type(&ScalarType::Decimal)
-        .assert_default_value(ScalarValue::Decimal(3.15));
+        .assert_default_value(ScalarValue::Decimal("3.15".parse().unwrap()));
     user_model
         .assert_has_field("string")
         .assert_base_type(&ScalarType::String)
@@ -81,7 +81,34 @@ fn settings_must_be_deteced() {
         .assert_relation_to("Todo")
         .assert_relation_to_fields(&["id"])
         .assert_arity(&dml::FieldArity::Optional)
-        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade);
+        .assert_relation_on_delete(Some(dml::ReferentialAction::Cascade));
+}
+
+#[test]
+fn cascading_deletes_on_the_list_side_must_be_accepted() {
+    let dml = r#"
+    model User {
+      id Int @id
+      posts Post[] @relation(onDelete: CASCADE)
+    }
+
+    model Post {
+      id Int @id
+      user User
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_arity(&dml::FieldArity::List)
+        .assert_relation_on_delete(Some(dml::ReferentialAction::Cascade));
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("user")
+        .assert_relation_on_delete(None);
 }
 
 #[test]
@@ -179,3 +179,49 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn should_fail_on_set_null_for_required_relation_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onDelete: SetNull)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The referential action `SetNull` in `onDelete` is not allowed on a required relation field.",
+        "relation",
+        Span::new(139, 146),
+    ));
+}
+
+#[test]
+fn should_fail_on_referential_action_on_list_field() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[] @relation(onDelete: Restrict)
+    }
+
+    model Post {
+        id Int @id
+        user User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `onDelete` argument must be defined on the side of the relation that holds the foreign key, not on a list field.",
+        "relation",
+        Span::new(78, 86),
+    ));
+}
@@ -107,3 +107,31 @@ fn must_generate_back_relation_fields_for_named_relation_fields() {
         .assert_relation_name("AssignedTodos")
         .assert_relation_to("Todo");
 }
+
+#[test]
+fn referential_actions_must_be_parsed() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+        edited_posts Post[] @relation("editor")
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(onDelete: Cascade, onUpdate: Restrict)
+        editor User? @relation("editor", onDelete: SetNull)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let post = datamodel.assert_has_model("Post");
+
+    post.assert_has_field("user")
+        .assert_relation_on_delete(Some(dml::ReferentialAction::Cascade))
+        .assert_relation_on_update(Some(dml::ReferentialAction::Restrict));
+
+    post.assert_has_field("editor")
+        .assert_relation_on_delete(Some(dml::ReferentialAction::SetNull))
+        .assert_relation_on_update(None);
+}
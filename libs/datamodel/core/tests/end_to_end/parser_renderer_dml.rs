@@ -134,3 +134,20 @@ fn test_parser_renderer_model_with_comments_via_dml() {
 
     assert_eq!(rendered, DATAMODEL_STRING_WITH_COMMENTS);
 }
+
+const DATAMODEL_STRING_WITH_NATIVE_TYPES: &str = r#"model Blog {
+  id     Int     @id
+  title  String  @unique @db.VarChar(191)
+  amount Decimal @db.Decimal(10, 2)
+  views  Int?    @db.BigInt
+}"#;
+
+#[test]
+fn test_parser_renderer_native_types_via_dml() {
+    let dml = datamodel::parse_datamodel_for_connector_type(DATAMODEL_STRING_WITH_NATIVE_TYPES, "mysql").unwrap();
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_STRING_WITH_NATIVE_TYPES);
+}
@@ -108,3 +108,90 @@ fn should_fail_on_custom_related_types() {
         ast::Span::new(25, 29),
     ));
 }
+
+#[test]
+fn shound_fail_on_unknown_native_type() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.VarChar2(10)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type `VarChar2` is not supported by the connector.",
+        "db.VarChar2",
+        ast::Span::new(150, 165),
+    ));
+}
+
+#[test]
+fn shound_fail_on_native_type_with_wrong_number_of_arguments() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.VarChar
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type `VarChar` takes 1 arguments, but received 0.",
+        "db.VarChar",
+        ast::Span::new(150, 160),
+    ));
+}
+
+#[test]
+fn shound_fail_on_native_type_for_another_scalar_type() {
+    let dml = r#"
+    datasource pg {
+      provider = "postgresql"
+      url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int @id
+        title Int @db.VarChar(10)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type `VarChar` can only be used on fields of type `String`.",
+        "db.VarChar",
+        ast::Span::new(144, 158),
+    ));
+}
+
+#[test]
+fn shound_fail_on_native_type_without_datasource() {
+    let dml = r#"
+    model Blog {
+        id    Int    @id
+        title String @db.VarChar(10)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native types can only be used with a datasource whose connector defines them.",
+        "db.VarChar",
+        ast::Span::new(65, 79),
+    ));
+}
@@ -118,44 +118,148 @@ fn should_be_able_to_define_custom_enum_types() {
 }
 
 #[test]
-#[ignore]
-fn should_handle_type_mappings() {
+fn should_handle_native_types() {
     let dml = r#"
+        datasource pg {
+          provider = "postgresql"
+          url = "postgresql://"
+        }
+
         model Blog {
-            id     Int    @id
-            bigInt BigInt
+            id     Int     @id
+            bigInt Int     @db.BigInt
+            title  String  @db.VarChar(255)
+            amount Decimal @db.Decimal(10, 2)
+            uid    String  @db.Uuid
         }
     "#;
 
     let datamodel = parse(dml);
 
-    let user_model = datamodel.assert_has_model("Blog");
+    let blog_model = datamodel.assert_has_model("Blog");
 
-    user_model
+    blog_model
         .assert_has_field("bigInt")
         .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+    blog_model
+        .assert_has_field("title")
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "VarChar",
+            ScalarType::String,
+            "varchar(255)",
+            vec![255],
+        ));
+    blog_model
+        .assert_has_field("amount")
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "Decimal",
+            ScalarType::Decimal,
+            "decimal(10,2)",
+            vec![10, 2],
+        ));
+    blog_model
+        .assert_has_field("uid")
+        .assert_connector_type(&ScalarFieldType::new("Uuid", ScalarType::String, "uuid"));
 }
 
 #[test]
-#[ignore]
-fn should_handle_type_specifications() {
+fn should_resolve_native_type_aliases() {
     let dml = r#"
         datasource pg {
-          provider = "postgres"
+          provider = "postgresql"
           url = "postgresql://"
         }
-        
+
         model Blog {
             id     Int @id
-            bigInt Int @pg.BigInt
+            bigInt Int @db.Int8
         }
     "#;
 
     let datamodel = parse(dml);
 
-    let user_model = datamodel.assert_has_model("Blog");
-
-    user_model
+    datamodel
+        .assert_has_model("Blog")
         .assert_has_field("bigInt")
         .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
 }
+
+#[test]
+fn should_handle_native_types_on_custom_types() {
+    let dml = r#"
+        datasource mysql {
+          provider = "mysql"
+          url = "mysql://"
+        }
+
+        type Email = String @db.VarChar(320) @unique
+
+        model User {
+            id    Int    @id
+            email Email
+            bio   String @db.MediumText
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let user_model = datamodel.assert_has_model("User");
+
+    user_model
+        .assert_has_field("email")
+        .assert_is_unique(true)
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "VarChar",
+            ScalarType::String,
+            "varchar(320)",
+            vec![320],
+        ));
+    user_model
+        .assert_has_field("bio")
+        .assert_connector_type(&ScalarFieldType::new("MediumText", ScalarType::String, "mediumtext"));
+}
+
+#[test]
+fn should_resolve_native_types_for_a_given_connector_type() {
+    let dml = r#"
+        model Blog {
+            id    Int    @id
+            title String @db.Text
+        }
+    "#;
+
+    let datamodel = datamodel::parse_datamodel_for_connector_type(dml, "sqlite").unwrap();
+
+    datamodel
+        .assert_has_model("Blog")
+        .assert_has_field("title")
+        .assert_connector_type(&ScalarFieldType::new("Text", ScalarType::String, "TEXT"));
+}
+
+#[test]
+fn should_resolve_native_types_when_the_datasource_can_not_be_loaded() {
+    // The url comes from an environment variable that is not set, e.g. in an editor.
+    let dml = r#"
+        datasource pg {
+          provider = "postgres"
+          url = env("NATIVE_TYPES_UNSET_DATABASE_URL")
+        }
+
+        model Blog {
+            id    Int    @id
+            title String @db.VarChar(50)
+        }
+    "#;
+
+    let datamodel = datamodel::parse_datamodel(dml).unwrap();
+
+    datamodel
+        .assert_has_model("Blog")
+        .assert_has_field("title")
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "VarChar",
+            ScalarType::String,
+            "varchar(50)",
+            vec![50],
+        ));
+}
@@ -106,8 +106,10 @@ impl<'a> DatamodelConverter<'a> {
             .iter()
             .map(|r| RelationTemplate {
                 name: r.name(),
-                model_a_on_delete: OnDelete::SetNull,
-                model_b_on_delete: OnDelete::SetNull,
+                model_a_on_delete: r.model_a_on_delete(),
+                model_b_on_delete: r.model_b_on_delete(),
+                model_a_on_update: r.model_a_on_update(),
+                model_b_on_update: r.model_b_on_update(),
                 manifestation: Some(r.manifestation()),
                 model_a_name: r.model_a.name.clone(),
                 model_b_name: r.model_b.name.clone(),
@@ -288,6 +290,55 @@ impl TempRelationHolder {
         }
     }
 
+    /// The declared action applied to the records of model B when a record of model A is deleted.
+    pub fn model_a_on_delete(&self) -> Option<ReferentialAction> {
+        Self::on_delete(&self.field_b).or_else(|| Self::legacy_on_delete(&self.field_a, &self.field_b))
+    }
+
+    /// The declared action applied to the records of model A when a record of model B is deleted.
+    pub fn model_b_on_delete(&self) -> Option<ReferentialAction> {
+        Self::on_delete(&self.field_a).or_else(|| Self::legacy_on_delete(&self.field_b, &self.field_a))
+    }
+
+    /// The declared action applied to the records of model B when a record of model A is updated.
+    pub fn model_a_on_update(&self) -> Option<ReferentialAction> {
+        Self::on_update(&self.field_b)
+    }
+
+    /// The declared action applied to the records of model A when a record of model B is updated.
+    pub fn model_b_on_update(&self) -> Option<ReferentialAction> {
+        Self::on_update(&self.field_a)
+    }
+
+    fn on_delete(field: &dml::Field) -> Option<ReferentialAction> {
+        match &field.field_type {
+            dml::FieldType::Relation(dml::RelationInfo {
+                on_delete: Some(action),
+                ..
+            }) => Some(convert_referential_action(*action)),
+            _ => None,
+        }
+    }
+
+    /// Earlier versions declared cascading deletes on the list side of one to many relations.
+    fn legacy_on_delete(list_field: &dml::Field, related_field: &dml::Field) -> Option<ReferentialAction> {
+        if list_field.arity == dml::FieldArity::List && related_field.arity != dml::FieldArity::List {
+            Self::on_delete(list_field)
+        } else {
+            None
+        }
+    }
+
+    fn on_update(field: &dml::Field) -> Option<ReferentialAction> {
+        match &field.field_type {
+            dml::FieldType::Relation(dml::RelationInfo {
+                on_update: Some(action),
+                ..
+            }) => Some(convert_referential_action(*action)),
+            _ => None,
+        }
+    }
+
     fn manifestation(&self) -> RelationLinkManifestation {
         match &self.manifestation {
             // TODO: relation table columns must get renamed: lowercased type names instead of A and B
@@ -308,6 +359,16 @@ impl TempRelationHolder {
     }
 }
 
+fn convert_referential_action(action: dml::ReferentialAction) -> ReferentialAction {
+    match action {
+        dml::ReferentialAction::Cascade => ReferentialAction::Cascade,
+        dml::ReferentialAction::Restrict => ReferentialAction::Restrict,
+        dml::ReferentialAction::NoAction => ReferentialAction::NoAction,
+        dml::ReferentialAction::SetNull => ReferentialAction::SetNull,
+        dml::ReferentialAction::SetDefault => ReferentialAction::SetDefault,
+    }
+}
+
 trait DatamodelFieldExtensions {
     fn type_identifier(&self) -> TypeIdentifier;
     fn is_required(&self) -> bool;
@@ -327,10 +388,14 @@ impl DatamodelFieldExtensions for dml::Field {
         match self.field_type {
             dml::FieldType::Enum(_) => TypeIdentifier::Enum,
             dml::FieldType::Relation(_) => TypeIdentifier::Relation,
-            dml::FieldType::Base(scalar) => match scalar {
+            dml::FieldType::Base(_) | dml::FieldType::ConnectorSpecific(_) => match self
+                .field_type
+                .scalar_type()
+                .expect("Base and connector specific types have a scalar type")
+            {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Decimal => TypeIdentifier::Float,
+                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Int => TypeIdentifier::Int,
                 dml::ScalarType::String => match self.default_value {
@@ -343,9 +408,6 @@ impl DatamodelFieldExtensions for dml::Field {
                     _ => TypeIdentifier::String,
                 },
             },
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
-            }
         }
     }
 
@@ -438,7 +500,7 @@ impl DatamodelFieldExtensions for dml::Field {
             datamodel::common::ScalarValue::Float(x) => Some(PrismaValue::Float(f64::from(*x))),
             datamodel::common::ScalarValue::String(x) => Some(PrismaValue::String(x.clone())),
             datamodel::common::ScalarValue::DateTime(x) => Some(PrismaValue::DateTime(*x)),
-            datamodel::common::ScalarValue::Decimal(x) => Some(PrismaValue::Float(f64::from(*x))), // TODO: not sure if this mapping is correct
+            datamodel::common::ScalarValue::Decimal(x) => Some(PrismaValue::Decimal(*x)),
             datamodel::common::ScalarValue::ConstantLiteral(x) => {
                 Some(PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())))
             }
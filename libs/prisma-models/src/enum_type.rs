@@ -1,9 +1,8 @@
-use super::{InternalEnum, OrderBy, ScalarField, SortOrder};
+use super::{InternalEnum, OrderBy};
 use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct EnumType {
@@ -31,17 +30,14 @@ impl EnumValue {
     pub fn as_string(&self) -> String {
         match &self.value {
             EnumValueWrapper::String(s) => s.clone(),
-            EnumValueWrapper::OrderBy(ob) => format!("{}_{}", ob.field.name, ob.sort_order.abbreviated()),
+            EnumValueWrapper::OrderBy(ob) => ob.name(),
         }
     }
 
-    pub fn order_by<T>(name: T, field: Arc<ScalarField>, sort_order: SortOrder) -> Self
-    where
-        T: Into<String>,
-    {
+    pub fn order_by(order_by: OrderBy) -> Self {
         EnumValue {
-            name: name.into(),
-            value: EnumValueWrapper::OrderBy(OrderBy { field, sort_order }),
+            name: order_by.name(),
+            value: EnumValueWrapper::OrderBy(order_by),
         }
     }
 
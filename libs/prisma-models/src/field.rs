@@ -31,6 +31,7 @@ pub struct FieldManifestation {
 pub enum TypeIdentifier {
     String,
     Float,
+    Decimal,
     Boolean,
     Enum,
     Json,
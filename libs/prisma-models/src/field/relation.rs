@@ -152,6 +152,35 @@ impl RelationField {
         }
     }
 
+    /// The action applied to the records of this field's model when the related record is deleted.
+    /// Falls back to what the migration engine creates when nothing is declared:
+    /// required relations restrict the deletion, optional ones are set to null.
+    pub fn on_delete(&self) -> ReferentialAction {
+        match self.declared_on_delete() {
+            Some(action) => action,
+            None if self.is_required && !self.is_list => ReferentialAction::Restrict,
+            None => ReferentialAction::SetNull,
+        }
+    }
+
+    /// The `onDelete` action declared in the datamodel for this field, if any.
+    pub fn declared_on_delete(&self) -> Option<ReferentialAction> {
+        match self.relation_side {
+            RelationSide::A => self.relation().model_b_on_delete,
+            RelationSide::B => self.relation().model_a_on_delete,
+        }
+    }
+
+    /// The action applied to the records of this field's model when the related record is updated.
+    pub fn on_update(&self) -> ReferentialAction {
+        let declared = match self.relation_side {
+            RelationSide::A => self.relation().model_b_on_update,
+            RelationSide::B => self.relation().model_a_on_update,
+        };
+
+        declared.unwrap_or_default()
+    }
+
     pub fn is_relation_with_name_and_side(&self, relation_name: &str, side: RelationSide) -> bool {
         self.relation().name == relation_name && self.relation_side == side
     }
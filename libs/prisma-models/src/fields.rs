@@ -90,8 +90,12 @@ impl Fields {
             .map(|f| f.upgrade().unwrap())
             .fold(Vec::new(), |mut acc, rf| {
                 match rf.relation_side {
-                    RelationSide::A if rf.relation().model_a_on_delete.is_cascade() => acc.push(rf),
-                    RelationSide::B if rf.relation().model_b_on_delete.is_cascade() => acc.push(rf),
+                    RelationSide::A if rf.relation().model_a_on_delete == Some(ReferentialAction::Cascade) => {
+                        acc.push(rf)
+                    }
+                    RelationSide::B if rf.relation().model_b_on_delete == Some(ReferentialAction::Cascade) => {
+                        acc.push(rf)
+                    }
                     _ => (),
                 }
 
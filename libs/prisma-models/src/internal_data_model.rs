@@ -97,11 +97,13 @@ impl InternalDataModel {
         self.version.is_none()
     }
 
-    pub fn fields_requiring_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
+    /// All non-list relation fields pointing to the given model, i.e. the fields whose records
+    /// are affected by the referential actions when a record of `model` is deleted.
+    pub fn fields_referencing_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
         self.relation_fields()
             .iter()
             .filter(|rf| &rf.related_model() == model)
-            .filter(|f| f.is_required && !f.is_list)
+            .filter(|f| !f.is_list)
             .map(|f| Arc::clone(f))
             .collect()
     }
@@ -1,5 +1,4 @@
-use crate::{ModelRef, ScalarField};
-use std::sync::Arc;
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 
 pub trait IntoOrderBy {
     fn into_order_by(self, model: ModelRef) -> OrderBy;
@@ -21,8 +20,114 @@ impl SortOrder {
     }
 }
 
+/// Position of null values in an ordering. Without it, the database decides.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+impl NullsOrder {
+    /// "NULLS_FIRST" / "NULLS_LAST"
+    pub fn abbreviated(self) -> &'static str {
+        match self {
+            NullsOrder::First => "NULLS_FIRST",
+            NullsOrder::Last => "NULLS_LAST",
+        }
+    }
+}
+
+/// What records are ordered by, relative to the end of the relation path of the ordering.
+#[derive(Clone, Debug)]
+pub enum OrderByTarget {
+    /// A scalar field.
+    Field(ScalarFieldRef),
+
+    /// The number of records related through a to-many relation field.
+    RelationCount(RelationFieldRef),
+}
+
+/// One entry of an ordering. Records are ordered by a list of entries, in order.
 #[derive(Clone, Debug)]
 pub struct OrderBy {
-    pub field: Arc<ScalarField>,
+    /// To-one relation fields leading from the ordered model to the target, in order.
+    /// Empty if the target belongs to the ordered model itself.
+    pub path: Vec<RelationFieldRef>,
+    pub target: OrderByTarget,
     pub sort_order: SortOrder,
+    pub nulls_order: Option<NullsOrder>,
+}
+
+impl OrderBy {
+    /// Orders by a scalar field of the ordered model.
+    pub fn field(field: ScalarFieldRef, sort_order: SortOrder, nulls_order: Option<NullsOrder>) -> Self {
+        OrderBy {
+            path: vec![],
+            target: OrderByTarget::Field(field),
+            sort_order,
+            nulls_order,
+        }
+    }
+
+    /// The model whose records are ordered.
+    pub fn model(&self) -> ModelRef {
+        match (self.path.first(), &self.target) {
+            (Some(rf), _) => rf.model(),
+            (None, OrderByTarget::Field(sf)) => sf.model(),
+            (None, OrderByTarget::RelationCount(rf)) => rf.model(),
+        }
+    }
+
+    /// The scalar field ordered by, if it belongs to the ordered model itself.
+    pub fn scalar_field(&self) -> Option<&ScalarFieldRef> {
+        match (self.path.is_empty(), &self.target) {
+            (true, OrderByTarget::Field(sf)) => Some(sf),
+            _ => None,
+        }
+    }
+
+    /// Whether the ordered value can be null, either because the field is optional or
+    /// because one of the relations on the path may not be set.
+    pub fn is_nullable(&self) -> bool {
+        let optional_path = self.path.iter().any(|rf| !rf.is_required);
+
+        match &self.target {
+            OrderByTarget::Field(sf) => optional_path || !sf.is_required,
+            OrderByTarget::RelationCount(_) => optional_path,
+        }
+    }
+
+    /// Positions the nulls of nullable values explicitly, considering them smaller than any other value
+    /// unless a position has been requested. Databases disagree on the default position of nulls.
+    pub fn with_explicit_nulls(mut self) -> Self {
+        if self.nulls_order.is_none() && self.is_nullable() {
+            self.nulls_order = match self.sort_order {
+                SortOrder::Ascending => Some(NullsOrder::First),
+                SortOrder::Descending => Some(NullsOrder::Last),
+            };
+        }
+
+        self
+    }
+
+    /// Name of the entry in the order by enum, e.g. "name_ASC", "author_name_DESC_NULLS_LAST" or "posts_count_ASC".
+    pub fn name(&self) -> String {
+        let mut segments: Vec<String> = self.path.iter().map(|rf| rf.name.clone()).collect();
+
+        match &self.target {
+            OrderByTarget::Field(sf) => segments.push(sf.name.clone()),
+            OrderByTarget::RelationCount(rf) => {
+                segments.push(rf.name.clone());
+                segments.push("count".to_owned());
+            }
+        }
+
+        segments.push(self.sort_order.abbreviated().to_owned());
+
+        if let Some(nulls_order) = self.nulls_order {
+            segments.push(nulls_order.abbreviated().to_owned());
+        }
+
+        segments.join("_")
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{DomainError, DomainResult, EnumValue};
 use chrono::prelude::*;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{convert::TryFrom, fmt, string::FromUtf8Error};
@@ -23,6 +24,10 @@ pub enum PrismaValue {
     #[serde(rename = "float")]
     Float(f64),
 
+    /// Arbitrary-precision decimal, serialized as a string to keep its precision.
+    #[serde(rename = "decimal")]
+    Decimal(Decimal),
+
     #[serde(rename = "bool")]
     Boolean(bool),
 
@@ -65,6 +70,7 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.as_string().fmt(f),
@@ -109,6 +115,12 @@ impl From<f32> for PrismaValue {
     }
 }
 
+impl From<Decimal> for PrismaValue {
+    fn from(d: Decimal) -> Self {
+        PrismaValue::Decimal(d)
+    }
+}
+
 impl From<bool> for PrismaValue {
     fn from(b: bool) -> Self {
         PrismaValue::Boolean(b)
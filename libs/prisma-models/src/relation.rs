@@ -5,29 +5,41 @@ use std::sync::{Arc, Weak};
 pub type RelationRef = Arc<Relation>;
 pub type RelationWeakRef = Weak<Relation>;
 
+/// What happens to the records referencing a record that gets deleted or updated.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OnDelete {
+pub enum ReferentialAction {
     SetNull,
     Cascade,
+    Restrict,
+    NoAction,
+    SetDefault,
 }
 
-impl OnDelete {
+impl ReferentialAction {
     pub fn is_cascade(self) -> bool {
-        match self {
-            OnDelete::Cascade => true,
-            OnDelete::SetNull => false,
-        }
+        self == ReferentialAction::Cascade
     }
 
     pub fn is_set_null(self) -> bool {
+        self == ReferentialAction::SetNull
+    }
+
+    /// Both `Restrict` and `NoAction` fail if referencing records exist.
+    pub fn is_restrict(self) -> bool {
         match self {
-            OnDelete::Cascade => false,
-            OnDelete::SetNull => true,
+            ReferentialAction::Restrict | ReferentialAction::NoAction => true,
+            _ => false,
         }
     }
 }
 
+impl Default for ReferentialAction {
+    fn default() -> Self {
+        ReferentialAction::NoAction
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct InlineRelation {
@@ -56,8 +68,14 @@ pub enum RelationLinkManifestation {
 #[serde(rename_all = "camelCase")]
 pub struct RelationTemplate {
     pub name: String,
-    pub model_a_on_delete: OnDelete,
-    pub model_b_on_delete: OnDelete,
+    #[serde(default)]
+    pub model_a_on_delete: Option<ReferentialAction>,
+    #[serde(default)]
+    pub model_b_on_delete: Option<ReferentialAction>,
+    #[serde(default)]
+    pub model_a_on_update: Option<ReferentialAction>,
+    #[serde(default)]
+    pub model_b_on_update: Option<ReferentialAction>,
     pub manifestation: Option<RelationLinkManifestation>, // TODO: remove the option after the switch to v2 is completed
 
     #[serde(rename = "modelAId")]
@@ -75,8 +93,14 @@ pub struct Relation {
     model_a_name: String,
     model_b_name: String,
 
-    pub model_a_on_delete: OnDelete,
-    pub model_b_on_delete: OnDelete,
+    /// Applied to the records of model B when a record of model A is deleted, `None` if not declared.
+    pub model_a_on_delete: Option<ReferentialAction>,
+    /// Applied to the records of model A when a record of model B is deleted, `None` if not declared.
+    pub model_b_on_delete: Option<ReferentialAction>,
+    /// Applied to the records of model B when a record of model A is updated, `None` if not declared.
+    pub model_a_on_update: Option<ReferentialAction>,
+    /// Applied to the records of model A when a record of model B is updated, `None` if not declared.
+    pub model_b_on_update: Option<ReferentialAction>,
 
     model_a: OnceCell<ModelWeakRef>,
     model_b: OnceCell<ModelWeakRef>,
@@ -99,6 +123,8 @@ impl RelationTemplate {
             model_b_name: self.model_b_name,
             model_a_on_delete: self.model_a_on_delete,
             model_b_on_delete: self.model_b_on_delete,
+            model_a_on_update: self.model_a_on_update,
+            model_b_on_update: self.model_b_on_update,
             model_a: OnceCell::new(),
             model_b: OnceCell::new(),
             field_a: OnceCell::new(),
@@ -1,5 +1,7 @@
 use crate::{GraphqlId, PrismaValue};
 use quaint::ast::{DatabaseValue, Id, ParameterizedValue};
+use rust_decimal::Decimal;
+use std::str::FromStr;
 
 impl From<Id> for GraphqlId {
     fn from(id: Id) -> Self {
@@ -32,6 +34,7 @@ impl<'a> From<PrismaValue> for DatabaseValue<'a> {
         match pv {
             PrismaValue::String(s) => s.into(),
             PrismaValue::Float(f) => (f as f64).into(),
+            PrismaValue::Decimal(d) => decimal_to_database_value(d),
             PrismaValue::Boolean(b) => b.into(),
             PrismaValue::DateTime(d) => d.into(),
             PrismaValue::Enum(e) => e.as_string().into(),
@@ -46,6 +49,17 @@ impl<'a> From<PrismaValue> for DatabaseValue<'a> {
     }
 }
 
+/// Decimals a float holds exactly are passed as reals, which every database converts to its numeric types. Longer
+/// ones are passed as text, so that the database parses the exact value instead of a rounded float.
+fn decimal_to_database_value<'a>(d: Decimal) -> DatabaseValue<'a> {
+    let text = d.to_string();
+
+    match text.parse::<f64>() {
+        Ok(f) if Decimal::from_str(&f.to_string()).ok() == Some(d) => f.into(),
+        _ => text.into(),
+    }
+}
+
 impl<'a> From<ParameterizedValue<'a>> for PrismaValue {
     fn from(pv: ParameterizedValue<'a>) -> Self {
         match pv {
@@ -11,6 +11,9 @@ pub trait RelationExt {
     fn column_for_relation_side(&self, side: RelationSide) -> Column<'static>;
     fn model_a_column(&self) -> Column<'static>;
     fn model_b_column(&self) -> Column<'static>;
+
+    /// The name of the table the foreign keys are written to, see `as_table`.
+    fn table_name(&self) -> String;
 }
 
 pub trait RelationFieldExt {
@@ -53,23 +56,8 @@ impl AsTable for Relation {
     /// - A separate relation table for all relations, if using the deprecated
     ///   data model syntax.
     fn as_table(&self) -> Table<'static> {
-        use crate::RelationLinkManifestation::*;
-
-        match self.manifestation {
-            Some(RelationTable(ref m)) => {
-                let db = self.model_a().internal_data_model().db_name.clone();
-                (db, m.table.clone()).into()
-            }
-            Some(Inline(ref m)) => self
-                .internal_data_model()
-                .find_model(&m.in_table_of_model_name)
-                .unwrap()
-                .as_table(),
-            None => {
-                let db = self.model_a().internal_data_model().db_name.clone();
-                (db, format!("_{}", self.name)).into()
-            }
-        }
+        let db = self.model_a().internal_data_model().db_name.clone();
+        (db, self.table_name()).into()
     }
 }
 
@@ -150,4 +138,19 @@ impl RelationExt for Relation {
             None => Relation::MODEL_B_DEFAULT_COLUMN.into(),
         }
     }
+
+    fn table_name(&self) -> String {
+        use crate::RelationLinkManifestation::*;
+
+        match self.manifestation {
+            Some(RelationTable(ref m)) => m.table.clone(),
+            Some(Inline(ref m)) => self
+                .internal_data_model()
+                .find_model(&m.in_table_of_model_name)
+                .unwrap()
+                .db_name()
+                .to_string(),
+            None => format!("_{}", self.name),
+        }
+    }
 }
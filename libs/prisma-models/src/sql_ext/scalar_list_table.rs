@@ -22,6 +22,10 @@ impl<'a> ScalarListTable<'a> {
         }
     }
 
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
     pub fn table(&self) -> Table<'static> {
         let internal_data_model = self.parent_field.internal_data_model();
         let database_name = internal_data_model.db_name.clone();
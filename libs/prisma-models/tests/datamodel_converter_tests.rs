@@ -49,6 +49,7 @@ fn models_with_only_scalar_fields() {
                 id Int @id
                 int Int
                 float Float
+                decimal Decimal
                 boolean Boolean
                 dateTime DateTime
                 stringOpt String?
@@ -74,6 +75,10 @@ fn models_with_only_scalar_fields() {
         .assert_scalar_field("float")
         .assert_type_identifier(TypeIdentifier::Float)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("decimal")
+        .assert_type_identifier(TypeIdentifier::Decimal)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("boolean")
         .assert_type_identifier(TypeIdentifier::Boolean)
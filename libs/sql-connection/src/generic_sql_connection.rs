@@ -1,6 +1,7 @@
 use datamodel::{Source, configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME}};
 use crate::{SqlConnection, SyncSqlConnection};
-use quaint::{prelude::*, error::Error as QuaintError};
+use quaint::{prelude::*, error::Error as QuaintError, pooled::PooledConnection};
+use std::sync::Arc;
 use url::Url;
 use tokio::runtime::Runtime;
 
@@ -102,4 +103,44 @@ impl SyncSqlConnection for GenericSqlConnection {
         let conn = self.runtime.block_on(self.pool.check_out())?;
         self.runtime.block_on(conn.execute_raw(sql, params))
     }
+
+    fn single_connection(&self) -> Result<Box<dyn SyncSqlConnection + '_>, QuaintError> {
+        let conn = self.runtime.block_on(self.pool.check_out())?;
+
+        Ok(Box::new(SingleSqlConnection {
+            conn: Arc::new(conn),
+            runtime: &self.runtime,
+        }))
+    }
+}
+
+/// A connection checked out of the pool of a `GenericSqlConnection`. It goes back to the pool when dropped.
+struct SingleSqlConnection<'a> {
+    conn: Arc<PooledConnection>,
+    runtime: &'a Runtime,
+}
+
+impl SyncSqlConnection for SingleSqlConnection<'_> {
+    fn execute(&self, q: Query<'_>) -> Result<Option<Id>, QuaintError> {
+        self.runtime.block_on(self.conn.execute(q))
+    }
+
+    fn query(&self, q: Query<'_>) -> Result<ResultSet, QuaintError> {
+        self.runtime.block_on(self.conn.query(q))
+    }
+
+    fn query_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<ResultSet, QuaintError> {
+        self.runtime.block_on(self.conn.query_raw(sql, params))
+    }
+
+    fn execute_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<u64, QuaintError> {
+        self.runtime.block_on(self.conn.execute_raw(sql, params))
+    }
+
+    fn single_connection(&self) -> Result<Box<dyn SyncSqlConnection + '_>, QuaintError> {
+        Ok(Box::new(SingleSqlConnection {
+            conn: Arc::clone(&self.conn),
+            runtime: self.runtime,
+        }))
+    }
 }
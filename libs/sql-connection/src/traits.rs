@@ -29,6 +29,11 @@ pub trait SyncSqlConnection {
     ///
     /// The `db` param is only used on SQLite to give a name to the attached database.
     fn execute_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Result<u64, QueryError>;
+
+    /// Checks out a single connection. Unlike queries on a pool, which can run on any of its connections, the queries
+    /// on the returned connection can share a transaction and connection level settings, e.g. SQLite's
+    /// `PRAGMA foreign_keys`.
+    fn single_connection(&self) -> Result<Box<dyn SyncSqlConnection + '_>, QueryError>;
 }
 
 /// A generic asynchronous SQL connection interface.
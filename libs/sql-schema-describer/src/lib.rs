@@ -2,7 +2,6 @@
 
 use failure::Fail;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::fmt;
 
 pub mod mysql;
@@ -46,6 +45,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views. They are described, but not managed by migrations.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -63,6 +65,17 @@ impl SqlSchema {
         self.enums.iter().find(|x| x.name == name)
     }
 
+    /// Get the columns of the given enum type, along with their tables.
+    pub fn enum_columns<'a>(&'a self, enum_name: &'a str) -> impl Iterator<Item = (&'a Table, &'a Column)> + 'a {
+        self.tables.iter().flat_map(move |table| {
+            table
+                .columns
+                .iter()
+                .filter(move |column| column.tpe.family == ColumnTypeFamily::Enum(enum_name.to_string()))
+                .map(move |column| (table, column))
+        })
+    }
+
     pub fn table(&self, name: &str) -> core::result::Result<&Table, String> {
         match self.tables.iter().find(|t| t.name == name) {
             Some(t) => Ok(t),
@@ -79,11 +92,17 @@ impl SqlSchema {
         self.sequences.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     pub fn empty() -> SqlSchema {
         SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
@@ -102,6 +121,11 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The table's comment, if there is one.
+    pub comment: Option<String>,
 }
 
 impl Table {
@@ -201,6 +225,8 @@ pub struct Column {
     pub default: Option<String>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The column's comment, if there is one.
+    pub comment: Option<String>,
 }
 
 impl Column {
@@ -208,10 +234,10 @@ impl Column {
         self.arity == ColumnArity::Required
     }
 
+    /// Compares the name, type family and arity. Raw types are spelled differently depending on
+    /// where the schema comes from, so comparing them is left to the migration connector.
     pub fn differs_in_something_except_default(&self, other: &Column) -> bool {
-        let result = self.name != other.name
-            || self.tpe.family != other.tpe.family // TODO: must respect full type
-            || self.arity != other.arity;
+        let result = self.name != other.name || self.tpe.family != other.tpe.family || self.arity != other.arity;
         //|| self.auto_increment != other.auto_increment;
 
         //        if result {
@@ -269,6 +295,8 @@ pub enum ColumnTypeFamily {
     TextSearch,
     /// Transaction ID types.
     TransactionId,
+    /// Enum types, referring to the enum by name.
+    Enum(String),
     /// Unknown
     Unknown,
 }
@@ -288,6 +316,7 @@ impl fmt::Display for ColumnTypeFamily {
             Self::LogSequenceNumber => "logSequenceNumber",
             Self::TextSearch => "textSearch",
             Self::TransactionId => "transactionId",
+            Self::Enum(_) => "enum",
             Self::Unknown => "unknown",
         };
         write!(f, "{}", str)
@@ -328,6 +357,12 @@ pub enum ForeignKeyAction {
     SetDefault,
 }
 
+impl Default for ForeignKeyAction {
+    fn default() -> Self {
+        ForeignKeyAction::NoAction
+    }
+}
+
 /// A foreign key.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -342,6 +377,19 @@ pub struct ForeignKey {
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
     pub on_delete_action: ForeignKeyAction,
+    /// Action on update. Schemas serialized before update actions were described have none.
+    #[serde(default)]
+    pub on_update_action: ForeignKeyAction,
+}
+
+/// A check constraint on a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The constraint name, when the constraint was given one.
+    pub name: Option<String>,
+    /// The checked expression, as reported by the database.
+    pub expression: String,
 }
 
 /// A SQL enum.
@@ -350,8 +398,8 @@ pub struct ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
-    /// Possible enum values.
-    pub values: HashSet<String>,
+    /// Possible enum values, in their declared order.
+    pub values: Vec<String>,
 }
 
 /// A SQL sequence.
@@ -365,3 +413,13 @@ pub struct Sequence {
     /// Sequence allocation size.
     pub allocation_size: u32,
 }
+
+/// A SQL view.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// View name.
+    pub name: String,
+    /// The query defining the view, as reported by the database.
+    pub definition: String,
+}
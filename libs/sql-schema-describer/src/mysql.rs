@@ -25,15 +25,19 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
     fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
+        let supports_check_constraints = self.supports_check_constraints();
         let tables = self
             .get_table_names(schema)
             .into_iter()
-            .map(|t| self.get_table(schema, &t))
+            .map(|t| self.get_table(schema, &t, supports_check_constraints))
             .collect();
+        let enums = self.get_enums(schema);
+        let views = self.get_views(schema);
         Ok(SqlSchema {
             tables,
-            enums: vec![],
+            enums,
             sequences: vec![],
+            views,
         })
     }
 }
@@ -65,7 +69,7 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).expect("get table names ");
@@ -98,26 +102,101 @@ impl SqlSchemaDescriber {
         size.parse().unwrap()
     }
 
-    fn get_table(&self, schema: &str, name: &str) -> Table {
+    fn get_table(&self, schema: &str, name: &str, supports_check_constraints: bool) -> Table {
         debug!("Getting table '{}'", name);
         let columns = self.get_columns(schema, name);
         let foreign_keys = self.get_foreign_keys(schema, name);
         let (indices, primary_key) = self.get_indices(schema, name, &foreign_keys);
+        let check_constraints = if supports_check_constraints {
+            self.get_check_constraints(schema, name)
+        } else {
+            Vec::new()
+        };
+        let comment = self.get_table_comment(schema, name);
         Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            comment,
         }
     }
 
+    fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "
+            SELECT table_comment table_comment
+            FROM information_schema.tables
+            WHERE table_schema = ? AND table_name = ?";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .expect("querying for table comment");
+
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("table_comment").and_then(|x| x.to_string()))
+            .filter(|comment| !comment.is_empty())
+    }
+
+    /// `information_schema.check_constraints` only exists from MySQL 8.0.16 on. Earlier versions parse CHECK
+    /// clauses, but ignore them, so there is nothing to describe.
+    fn supports_check_constraints(&self) -> bool {
+        let sql = "
+            SELECT COUNT(*) table_count
+            FROM information_schema.tables
+            WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'";
+        let rows = self
+            .conn
+            .query_raw(sql, &[])
+            .expect("querying for check constraints support");
+
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("table_count").and_then(|x| x.as_i64()))
+            .map(|count| count > 0)
+            .unwrap_or(false)
+    }
+
+    fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = "
+            SELECT tc.constraint_name constraint_name, cc.check_clause check_clause
+            FROM information_schema.table_constraints tc
+            JOIN information_schema.check_constraints cc
+                ON cc.constraint_schema = tc.constraint_schema AND cc.constraint_name = tc.constraint_name
+            WHERE tc.table_schema = ? AND tc.table_name = ? AND tc.constraint_type = 'CHECK'
+            ORDER BY tc.constraint_name";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .expect("querying for check constraints");
+        let check_constraints = rows
+            .into_iter()
+            .map(|row| {
+                debug!("Got check constraint: {:?}", row);
+                CheckConstraint {
+                    name: row.get("constraint_name").and_then(|x| x.to_string()),
+                    expression: row
+                        .get("check_clause")
+                        .and_then(|x| x.to_string())
+                        .expect("get check_clause"),
+                }
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", check_constraints);
+        check_constraints
+    }
+
     fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
         let sql = "
-            SELECT column_name column_name, data_type data_type, column_default column_default, is_nullable is_nullable, extra extra
+            SELECT column_name column_name, data_type data_type, column_default column_default, is_nullable is_nullable, extra extra,
+                column_comment column_comment, character_maximum_length character_maximum_length,
+                numeric_precision numeric_precision, numeric_scale numeric_scale, datetime_precision datetime_precision
             FROM information_schema.columns
             WHERE table_schema = ? AND table_name = ?
             ORDER BY column_name";
@@ -142,7 +221,26 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(data_type.as_ref());
+                let column_name = col
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column name");
+                let tpe = match data_type.as_ref() {
+                    "enum" => ColumnType {
+                        raw: data_type.clone(),
+                        family: ColumnTypeFamily::Enum(enum_name(table, &column_name)),
+                    },
+                    _ => ColumnType {
+                        raw: with_type_params(
+                            &data_type,
+                            col.get("character_maximum_length").and_then(|x| x.as_i64()),
+                            col.get("numeric_precision").and_then(|x| x.as_i64()),
+                            col.get("numeric_scale").and_then(|x| x.as_i64()),
+                            col.get("datetime_precision").and_then(|x| x.as_i64()),
+                        ),
+                        ..get_column_type(data_type.as_ref())
+                    },
+                };
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -160,14 +258,15 @@ impl SqlSchemaDescriber {
                     _ => false,
                 };
                 Column {
-                    name: col
-                        .get("column_name")
-                        .and_then(|x| x.to_string())
-                        .expect("get column name"),
+                    name: column_name,
                     tpe,
                     arity,
                     default: col.get("column_default").and_then(|x| x.to_string()),
                     auto_increment: auto_increment,
+                    comment: col
+                        .get("column_comment")
+                        .and_then(|x| x.to_string())
+                        .filter(|comment| !comment.is_empty()),
                 }
             })
             .collect();
@@ -192,7 +291,8 @@ impl SqlSchemaDescriber {
                 kcu.referenced_table_name referenced_table_name,
                 kcu.referenced_column_name referenced_column_name,
                 kcu.ordinal_position ordinal_position,
-                rc.delete_rule delete_rule
+                rc.delete_rule delete_rule,
+                rc.update_rule update_rule
             FROM information_schema.key_column_usage AS kcu
             INNER JOIN information_schema.referential_constraints AS rc ON
             kcu.constraint_name = rc.constraint_name
@@ -228,20 +328,16 @@ impl SqlSchemaDescriber {
                 .get("ordinal_position")
                 .and_then(|x| x.as_i64())
                 .expect("get ordinal_position");
-            let on_delete_action = match row
-                .get("delete_rule")
-                .and_then(|x| x.to_string())
-                .expect("get delete_rule")
-                .to_lowercase()
-                .as_str()
-            {
-                "cascade" => ForeignKeyAction::Cascade,
-                "set null" => ForeignKeyAction::SetNull,
-                "set default" => ForeignKeyAction::SetDefault,
-                "restrict" => ForeignKeyAction::Restrict,
-                "no action" => ForeignKeyAction::NoAction,
-                s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-            };
+            let on_delete_action = parse_foreign_key_action(
+                &row.get("delete_rule")
+                    .and_then(|x| x.to_string())
+                    .expect("get delete_rule"),
+            );
+            let on_update_action = parse_foreign_key_action(
+                &row.get("update_rule")
+                    .and_then(|x| x.to_string())
+                    .expect("get update_rule"),
+            );
             match intermediate_fks.get_mut(&constraint_name) {
                 Some(fk) => {
                     let pos = ord_pos as usize - 1;
@@ -261,6 +357,7 @@ impl SqlSchemaDescriber {
                         referenced_table,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(constraint_name, fk);
                 }
@@ -376,6 +473,104 @@ impl SqlSchemaDescriber {
         debug!("Found table indices: {:?}, primary key: {:?}", indices, primary_key);
         (indices, primary_key)
     }
+
+    fn get_views(&self, schema: &str) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "
+            SELECT table_name view_name, view_definition view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).expect("querying for views");
+        let views = rows
+            .into_iter()
+            .map(|row| View {
+                name: row.get("view_name").and_then(|x| x.to_string()).expect("get view name"),
+                definition: row
+                    .get("view_definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get view definition"),
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
+    /// MySQL enums are defined inline on their columns. Every enum column gets its own enum, named
+    /// after the table and the column.
+    fn get_enums(&self, schema: &str) -> Vec<Enum> {
+        debug!("Getting enums");
+        let sql = "
+            SELECT table_name table_name, column_name column_name, column_type column_type
+            FROM information_schema.columns
+            WHERE table_schema = ? AND data_type = 'enum'
+            ORDER BY table_name, column_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).expect("querying for enums");
+        let enums = rows
+            .into_iter()
+            .map(|row| {
+                debug!("Got enum row: {:?}", row);
+                let table_name = row
+                    .get("table_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get table name");
+                let column_name = row
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column name");
+                let column_type = row
+                    .get("column_type")
+                    .and_then(|x| x.to_string())
+                    .expect("get column type");
+
+                Enum {
+                    name: enum_name(&table_name, &column_name),
+                    values: parse_enum_values(&column_type),
+                }
+            })
+            .collect();
+
+        debug!("Found enums: {:?}", enums);
+        enums
+    }
+}
+
+/// The name of the enum of an enum column.
+pub fn enum_name(table: &str, column: &str) -> String {
+    format!("{}_{}", table, column)
+}
+
+/// Parses the values of a column type like `enum('a','b')`.
+fn parse_enum_values(column_type: &str) -> Vec<String> {
+    let inner = column_type.trim_start_matches("enum(").trim_end_matches(")");
+
+    inner
+        .split("','")
+        .map(|value| value.trim_matches('\'').replace("''", "'"))
+        .collect()
+}
+
+/// Append the length, precision and scale to the raw types that have them, e.g. `varchar(191)`, `decimal(65,30)` or
+/// `datetime(3)`, so that migrations can compare them with the native types of the datamodel.
+fn with_type_params(
+    data_type: &str,
+    length: Option<i64>,
+    precision: Option<i64>,
+    scale: Option<i64>,
+    datetime_precision: Option<i64>,
+) -> String {
+    let params = match data_type {
+        "char" | "varchar" | "binary" | "varbinary" => length.map(|length| length.to_string()),
+        "decimal" | "numeric" => precision.map(|precision| format!("{},{}", precision, scale.unwrap_or(0))),
+        "time" | "datetime" | "timestamp" => datetime_precision.map(|precision| precision.to_string()),
+        _ => None,
+    };
+
+    match params {
+        Some(params) => format!("{}({})", data_type, params),
+        None => data_type.to_string(),
+    }
 }
 
 fn get_column_type(data_type: &str) -> ColumnType {
@@ -400,8 +595,6 @@ fn get_column_type(data_type: &str) -> ColumnType {
         "tinytext" => ColumnTypeFamily::String,
         "mediumtext" => ColumnTypeFamily::String,
         "longtext" => ColumnTypeFamily::String,
-        // XXX: Is this correct?
-        "enum" => ColumnTypeFamily::String,
         "set" => ColumnTypeFamily::String,
         "binary" => ColumnTypeFamily::Binary,
         "varbinary" => ColumnTypeFamily::Binary,
@@ -425,3 +618,14 @@ fn get_column_type(data_type: &str) -> ColumnType {
         family: family,
     }
 }
+
+fn parse_foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "cascade" => ForeignKeyAction::Cascade,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "restrict" => ForeignKeyAction::Restrict,
+        "no action" => ForeignKeyAction::NoAction,
+        s @ _ => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
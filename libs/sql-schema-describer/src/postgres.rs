@@ -3,7 +3,7 @@ use super::*;
 use log::debug;
 use regex::Regex;
 use sql_connection::SyncSqlConnection;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -29,16 +29,18 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
         let sequences = self.get_sequences(schema)?;
+        let enums = self.get_enums(schema)?;
         let tables = self
             .get_table_names(schema)
             .into_iter()
-            .map(|t| self.get_table(schema, &t, &sequences))
+            .map(|t| self.get_table(schema, &t, &sequences, &enums))
             .collect();
-        let enums = self.get_enums(schema)?;
+        let views = self.get_views(schema)?;
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 }
@@ -70,7 +72,7 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).expect("get table names ");
@@ -107,22 +109,77 @@ impl SqlSchemaDescriber {
         size.try_into().unwrap()
     }
 
-    fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>) -> Table {
+    fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>, enums: &Vec<Enum>) -> Table {
         debug!("Getting table '{}'", name);
-        let columns = self.get_columns(schema, name);
+        let columns = self.get_columns(schema, name, enums);
         let (indices, primary_key) = self.get_indices(schema, name, sequences);
         let foreign_keys = self.get_foreign_keys(schema, name);
+        let check_constraints = self.get_check_constraints(schema, name);
+        let comment = self.get_table_comment(schema, name);
         Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            comment,
         }
     }
 
-    fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
-        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type
+    fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql =
+            "SELECT obj_description((quote_ident($1) || '.' || quote_ident($2))::regclass, 'pg_class') AS comment";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table.into()])
+            .expect("querying for table comment");
+
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("comment").and_then(|x| x.to_string()))
+    }
+
+    fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = "SELECT con.conname AS constraint_name, pg_get_constraintdef(con.oid) AS constraint_definition
+            FROM pg_constraint con
+            JOIN pg_class rel ON rel.oid = con.conrelid
+            JOIN pg_namespace nsp ON nsp.oid = rel.relnamespace
+            WHERE con.contype = 'c' AND nsp.nspname = $1 AND rel.relname = $2
+            ORDER BY con.conname";
+        let rows = self
+            .conn
+            .query_raw(&sql, &[schema.into(), table.into()])
+            .expect("querying for check constraints");
+        let check_constraints = rows
+            .into_iter()
+            .map(|row| {
+                debug!("Got check constraint: {:?}", row);
+                let name = row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name");
+                let definition = row
+                    .get("constraint_definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_definition");
+                let expression = definition.trim_start_matches("CHECK ").to_string();
+
+                CheckConstraint {
+                    name: Some(name),
+                    expression,
+                }
+            })
+            .collect();
+
+        debug!("Found check constraints: {:?}", check_constraints);
+        check_constraints
+    }
+
+    fn get_columns(&self, schema: &str, table: &str, enums: &Vec<Enum>) -> Vec<Column> {
+        let sql = "SELECT column_name, udt_name, column_default, is_nullable, is_identity, data_type,
+                character_maximum_length, numeric_precision, numeric_scale, datetime_precision,
+                col_description((quote_ident(table_schema) || '.' || quote_ident(table_name))::regclass, ordinal_position) AS column_comment
             FROM information_schema.columns
             WHERE table_schema = $1 AND table_name = $2
             ORDER BY column_name";
@@ -159,7 +216,14 @@ impl SqlSchemaDescriber {
                     "yes" => false,
                     x => panic!(format!("unrecognized is_nullable variant '{}'", x)),
                 };
-                let tpe = get_column_type(udt.as_ref());
+                let mut tpe = get_column_type(udt.as_ref(), enums);
+                tpe.raw = with_type_params(
+                    &tpe.raw,
+                    col.get("character_maximum_length").and_then(|x| x.as_i64()),
+                    col.get("numeric_precision").and_then(|x| x.as_i64()),
+                    col.get("numeric_scale").and_then(|x| x.as_i64()),
+                    col.get("datetime_precision").and_then(|x| x.as_i64()),
+                );
                 let arity = if tpe.raw.starts_with("_") {
                     ColumnArity::List
                 } else if is_required {
@@ -169,9 +233,12 @@ impl SqlSchemaDescriber {
                 };
 
                 let default = col.get("column_default").and_then(|param_value| {
-                    param_value
-                        .to_string()
-                        .map(|x| x.replace("\'", "").replace("::text", ""))
+                    param_value.to_string().map(|x| {
+                        x.replace("\'", "")
+                            .replace("::text", "")
+                            .replace(&format!("::\"{}\"", udt), "")
+                            .replace(&format!("::{}", udt), "")
+                    })
                 });
                 let is_auto_increment = is_identity
                     || match default {
@@ -180,12 +247,14 @@ impl SqlSchemaDescriber {
                         }
                         _ => false,
                     };
+                let comment = col.get("column_comment").and_then(|x| x.to_string());
                 Column {
                     name: col_name,
                     tpe,
                     arity,
                     default,
                     auto_increment: is_auto_increment,
+                    comment,
                 }
             })
             .collect();
@@ -201,6 +270,7 @@ impl SqlSchemaDescriber {
                 cl.relname as \"parent_table\", 
                 att.attname as \"parent_column\",
                 con.confdeltype,
+                con.confupdtype,
                 conname as constraint_name
             FROM
             (SELECT 
@@ -210,7 +280,8 @@ impl SqlSchemaDescriber {
                     con1.confrelid, 
                     con1.conrelid,
                     con1.conname,
-                    con1.confdeltype
+                    con1.confdeltype,
+                    con1.confupdtype
                 FROM
                     pg_class cl
                     join pg_namespace ns on cl.relnamespace = ns.oid
@@ -256,18 +327,16 @@ impl SqlSchemaDescriber {
                 .get("confdeltype")
                 .and_then(|x| x.as_char())
                 .expect("get confdeltype");
+            let confupdtype = row
+                .get("confupdtype")
+                .and_then(|x| x.as_char())
+                .expect("get confupdtype");
             let constraint_name = row
                 .get("constraint_name")
                 .and_then(|x| x.to_string())
                 .expect("get constraint_name");
-            let on_delete_action = match confdeltype {
-                'a' => ForeignKeyAction::NoAction,
-                'r' => ForeignKeyAction::Restrict,
-                'c' => ForeignKeyAction::Cascade,
-                'n' => ForeignKeyAction::SetNull,
-                'd' => ForeignKeyAction::SetDefault,
-                _ => panic!(format!("unrecognized foreign key action '{}'", confdeltype)),
-            };
+            let on_delete_action = parse_foreign_key_action(confdeltype);
+            let on_update_action = parse_foreign_key_action(confupdtype);
             match intermediate_fks.get_mut(&id) {
                 Some(fk) => {
                     fk.columns.push(column);
@@ -280,6 +349,7 @@ impl SqlSchemaDescriber {
                         referenced_table,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -453,36 +523,91 @@ impl SqlSchemaDescriber {
         Ok(sequences)
     }
 
+    fn get_views(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<View>> {
+        debug!("Getting views");
+        let sql = "SELECT viewname AS view_name, definition AS view_definition
+            FROM pg_catalog.pg_views
+            WHERE schemaname = $1
+            ORDER BY viewname";
+        let rows = self.conn.query_raw(&sql, &[schema.into()]).expect("querying for views");
+        let views = rows
+            .into_iter()
+            .map(|row| View {
+                name: row.get("view_name").and_then(|x| x.to_string()).expect("get view_name"),
+                definition: row
+                    .get("view_definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get view_definition")
+                    .trim()
+                    .to_string(),
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        Ok(views)
+    }
+
     fn get_enums(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<Enum>> {
         debug!("Getting enums");
         let sql = "SELECT t.typname as name, e.enumlabel as value
             FROM pg_type t 
             JOIN pg_enum e ON t.oid = e.enumtypid  
             JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-            WHERE n.nspname = $1";
+            WHERE n.nspname = $1
+            ORDER BY t.typname, e.enumsortorder";
         let rows = self.conn.query_raw(&sql, &[schema.into()]).expect("querying for enums");
-        let mut enum_values: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut enums: Vec<Enum> = Vec::new();
         for row in rows.into_iter() {
             debug!("Got enum row: {:?}", row);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let value = row.get("value").and_then(|x| x.to_string()).expect("get value");
-            if !enum_values.contains_key(&name) {
-                enum_values.insert(name.clone(), HashSet::new());
+            match enums.iter_mut().find(|e| e.name == name) {
+                Some(existing) => existing.values.push(value),
+                None => enums.push(Enum {
+                    name,
+                    values: vec![value],
+                }),
             }
-            let vals = enum_values.get_mut(&name).expect("get enum values");
-            vals.insert(value);
         }
 
-        let enums: Vec<Enum> = enum_values
-            .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
-            .collect();
         debug!("Found enums: {:?}", enums);
         Ok(enums)
     }
 }
 
-fn get_column_type(udt: &str) -> ColumnType {
+/// Append the length, precision and scale to the raw types that have them, e.g. `varchar(191)`, `numeric(65,30)` or
+/// `timestamp(3)`, so that migrations can compare them with the native types of the datamodel.
+fn with_type_params(
+    udt: &str,
+    length: Option<i64>,
+    precision: Option<i64>,
+    scale: Option<i64>,
+    datetime_precision: Option<i64>,
+) -> String {
+    let params = match udt {
+        "varchar" | "bpchar" => length.map(|length| length.to_string()),
+        "numeric" => precision.map(|precision| format!("{},{}", precision, scale.unwrap_or(0))),
+        "time" | "timetz" | "timestamp" | "timestamptz" => datetime_precision.map(|precision| precision.to_string()),
+        _ => None,
+    };
+
+    match params {
+        Some(params) => format!("{}({})", udt, params),
+        None => udt.to_string(),
+    }
+}
+
+fn get_column_type(udt: &str, enums: &Vec<Enum>) -> ColumnType {
+    // Enum columns have the enum as their type, enum array columns the enum prefixed with an underscore.
+    let is_enum = |e: &&Enum| e.name == udt || (udt.starts_with("_") && e.name == udt[1..]);
+
+    if let Some(e) = enums.iter().find(is_enum) {
+        return ColumnType {
+            raw: udt.to_string(),
+            family: ColumnTypeFamily::Enum(e.name.clone()),
+        };
+    }
+
     let family = match udt {
         "int2" => ColumnTypeFamily::Int,
         "int4" => ColumnTypeFamily::Int,
@@ -532,3 +657,14 @@ fn get_column_type(udt: &str) -> ColumnType {
         family: family,
     }
 }
+
+fn parse_foreign_key_action(action: char) -> ForeignKeyAction {
+    match action {
+        'a' => ForeignKeyAction::NoAction,
+        'r' => ForeignKeyAction::Restrict,
+        'c' => ForeignKeyAction::Cascade,
+        'n' => ForeignKeyAction::SetNull,
+        'd' => ForeignKeyAction::SetDefault,
+        _ => panic!(format!("unrecognized foreign key action '{}'", action)),
+    }
+}
@@ -34,12 +34,14 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             .filter(|table| !is_system_table(&table))
             .map(|t| self.get_table(schema, &t))
             .collect();
+        let views = self.get_views(schema);
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables: tables,
+            views,
         })
     }
 }
@@ -81,6 +83,24 @@ impl SqlSchemaDescriber {
         names
     }
 
+    fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = format!(
+            r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view' ORDER BY name"#,
+            schema
+        );
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).expect("get views");
+        let views = result_set
+            .into_iter()
+            .map(|row| View {
+                name: row.get("name").and_then(|x| x.to_string()).expect("get view name"),
+                definition: row.get("sql").and_then(|x| x.to_string()).expect("get view sql"),
+            })
+            .collect();
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = format!(r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#);
@@ -98,15 +118,41 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name);
         let foreign_keys = self.get_foreign_keys(schema, name);
         let indices = self.get_indices(schema, name);
+        let check_constraints = self.get_check_constraints(schema, name);
         Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
+            // There are no comments in SQLite.
+            comment: None,
         }
     }
 
+    /// SQLite does not expose check constraints through any pragma, so they are parsed from the `CREATE TABLE`
+    /// statement stored in `sqlite_master`.
+    fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name = ?"#,
+            schema
+        );
+        debug!("describing table check constraints, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .expect("querying for table sql");
+        let check_constraints = result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|create_table_sql| parse_check_constraints(&create_table_sql))
+            .unwrap_or_else(Vec::new);
+        debug!("Found check constraints: {:?}", check_constraints);
+        check_constraints
+    }
+
     fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
         let sql = format!(r#"PRAGMA "{}".table_info ("{}")"#, schema, table);
         debug!("describing table columns, query: '{}'", sql);
@@ -138,6 +184,8 @@ impl SqlSchemaDescriber {
                     arity: arity.clone(),
                     default: default_value.clone(),
                     auto_increment: false,
+                    // There are no comments in SQLite.
+                    comment: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -201,6 +249,7 @@ impl SqlSchemaDescriber {
             pub referenced_table: String,
             pub referenced_columns: HashMap<i64, String>,
             pub on_delete_action: ForeignKeyAction,
+            pub on_update_action: ForeignKeyAction,
         }
 
         let sql = format!(r#"PRAGMA "{}".foreign_key_list("{}");"#, schema, table);
@@ -228,25 +277,16 @@ impl SqlSchemaDescriber {
                     columns.insert(seq, column);
                     let mut referenced_columns: HashMap<i64, String> = HashMap::new();
                     referenced_columns.insert(seq, referenced_column);
-                    let on_delete_action = match row
-                        .get("on_delete")
-                        .and_then(|x| x.to_string())
-                        .expect("on_delete")
-                        .to_lowercase()
-                        .as_str()
-                    {
-                        "no action" => ForeignKeyAction::NoAction,
-                        "restrict" => ForeignKeyAction::Restrict,
-                        "set null" => ForeignKeyAction::SetNull,
-                        "set default" => ForeignKeyAction::SetDefault,
-                        "cascade" => ForeignKeyAction::Cascade,
-                        s @ _ => panic!(format!("Unrecognized on delete action '{}'", s)),
-                    };
+                    let on_delete_action =
+                        parse_foreign_key_action(&row.get("on_delete").and_then(|x| x.to_string()).expect("on_delete"));
+                    let on_update_action =
+                        parse_foreign_key_action(&row.get("on_update").and_then(|x| x.to_string()).expect("on_update"));
                     let fk = IntermediateForeignKey {
                         columns,
                         referenced_table,
                         referenced_columns,
                         on_delete_action,
+                        on_update_action,
                     };
                     intermediate_fks.insert(id, fk);
                 }
@@ -278,6 +318,7 @@ impl SqlSchemaDescriber {
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
+                    on_update_action: intermediate_fk.on_update_action.to_owned(),
 
                     // Not relevant in SQLite since we cannot ALTER or DROP foreign keys by
                     // constraint name.
@@ -346,6 +387,7 @@ fn get_column_type(tpe: &str) -> ColumnType {
         "text" => ColumnTypeFamily::String,
         s if s.contains("char") => ColumnTypeFamily::String,
         s if s.contains("numeric") => ColumnTypeFamily::Float,
+        s if s.contains("decimal") => ColumnTypeFamily::Float,
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
@@ -381,3 +423,165 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+fn parse_foreign_key_action(action: &str) -> ForeignKeyAction {
+    match action.to_lowercase().as_str() {
+        "no action" => ForeignKeyAction::NoAction,
+        "restrict" => ForeignKeyAction::Restrict,
+        "set null" => ForeignKeyAction::SetNull,
+        "set default" => ForeignKeyAction::SetDefault,
+        "cascade" => ForeignKeyAction::Cascade,
+        s @ _ => panic!(format!("Unrecognized foreign key action '{}'", s)),
+    }
+}
+
+/// Extract the check constraints from a `CREATE TABLE` statement, with their names when they have one.
+fn parse_check_constraints(create_table_sql: &str) -> Vec<CheckConstraint> {
+    let tokens = tokenize(create_table_sql);
+    let mut check_constraints = Vec::new();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        if !token.is_keyword("check") {
+            continue;
+        }
+
+        let open = match tokens.get(idx + 1) {
+            Some(open) if open.text == "(" => open,
+            _ => continue,
+        };
+
+        let mut depth = 0;
+        let close = tokens[idx + 1..].iter().find(|token| {
+            match token.text {
+                "(" => depth += 1,
+                ")" => depth -= 1,
+                _ => (),
+            };
+
+            depth == 0
+        });
+
+        let close = match close {
+            Some(close) => close,
+            None => continue,
+        };
+
+        let name = match idx.checked_sub(2).map(|constraint_idx| &tokens[constraint_idx]) {
+            Some(constraint) if constraint.is_keyword("constraint") => Some(tokens[idx - 1].unquoted().to_owned()),
+            _ => None,
+        };
+
+        check_constraints.push(CheckConstraint {
+            name,
+            expression: create_table_sql[open.end..close.start].trim().to_owned(),
+        });
+    }
+
+    check_constraints
+}
+
+/// A token of an SQL statement, with its byte offsets in the statement.
+#[derive(Debug)]
+struct SqlToken<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> SqlToken<'a> {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    fn unquoted(&self) -> &'a str {
+        let quoted = match (self.text.chars().next(), self.text.chars().last()) {
+            (Some('"'), Some('"')) | (Some('`'), Some('`')) | (Some('['), Some(']')) | (Some('\''), Some('\'')) => {
+                self.text.len() >= 2
+            }
+            _ => false,
+        };
+
+        if quoted {
+            &self.text[1..self.text.len() - 1]
+        } else {
+            self.text
+        }
+    }
+}
+
+/// Split an SQL statement into words, quoted identifiers, string literals and single punctuation characters,
+/// dropping whitespace and comments. This is just enough to find constraints in `CREATE TABLE` statements.
+fn tokenize(sql: &str) -> Vec<SqlToken<'_>> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let start = idx;
+        let byte = bytes[idx];
+
+        if byte.is_ascii_whitespace() {
+            idx += 1;
+            continue;
+        }
+
+        if sql[idx..].starts_with("--") {
+            idx = sql[idx..]
+                .find('\n')
+                .map(|offset| idx + offset + 1)
+                .unwrap_or(bytes.len());
+            continue;
+        }
+
+        if sql[idx..].starts_with("/*") {
+            idx = sql[idx + 2..]
+                .find("*/")
+                .map(|offset| idx + offset + 4)
+                .unwrap_or(bytes.len());
+            continue;
+        }
+
+        idx = match byte {
+            b'"' | b'`' | b'\'' | b'[' => {
+                let closing = if byte == b'[' { b']' } else { byte };
+                let mut end = idx + 1;
+
+                // Quotes are escaped by doubling them.
+                while end < bytes.len() {
+                    if bytes[end] == closing {
+                        if closing != b']' && bytes.get(end + 1) == Some(&closing) {
+                            end += 2;
+                            continue;
+                        }
+
+                        break;
+                    }
+
+                    end += 1;
+                }
+
+                (end + 1).min(bytes.len())
+            }
+            b if b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80 => {
+                let mut end = idx + 1;
+
+                while end < bytes.len()
+                    && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_' || bytes[end] >= 0x80)
+                {
+                    end += 1;
+                }
+
+                end
+            }
+            _ => idx + 1,
+        };
+
+        tokens.push(SqlToken {
+            text: &sql[start..idx],
+            start,
+            end: idx,
+        });
+    }
+
+    tokens
+}
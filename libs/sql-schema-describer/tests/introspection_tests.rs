@@ -59,6 +59,7 @@ fn is_required_must_work() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "column2".to_string(),
@@ -69,6 +70,7 @@ fn is_required_must_work() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             assert_eq!(user_table.columns, expected_columns);
@@ -108,12 +110,17 @@ fn foreign_keys_must_work() {
                 arity: ColumnArity::Required,
                 default: None,
                 auto_increment: false,
+                comment: None,
             }];
 
             let on_delete_action = match db_type {
                 DbType::MySql => ForeignKeyAction::Restrict,
                 _ => ForeignKeyAction::NoAction,
             };
+            let on_update_action = match db_type {
+                DbType::MySql => ForeignKeyAction::Restrict,
+                _ => ForeignKeyAction::NoAction,
+            };
             assert_eq!(
                 user_table,
                 &Table {
@@ -131,7 +138,10 @@ fn foreign_keys_must_work() {
                         referenced_columns: vec!["id".to_string()],
                         referenced_table: "City".to_string(),
                         on_delete_action,
+                        on_update_action,
                     }],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
         },
@@ -181,6 +191,7 @@ fn multi_column_foreign_keys_must_work() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_name".to_string(),
@@ -191,6 +202,7 @@ fn multi_column_foreign_keys_must_work() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
 
@@ -198,6 +210,10 @@ fn multi_column_foreign_keys_must_work() {
                 DbType::MySql => ForeignKeyAction::Restrict,
                 _ => ForeignKeyAction::NoAction,
             };
+            let on_update_action = match db_type {
+                DbType::MySql => ForeignKeyAction::Restrict,
+                _ => ForeignKeyAction::NoAction,
+            };
 
             assert_eq!(
                 user_table,
@@ -216,7 +232,10 @@ fn multi_column_foreign_keys_must_work() {
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                         referenced_table: "City".to_string(),
                         on_delete_action,
+                        on_update_action,
                     },],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
         },
@@ -245,6 +264,7 @@ fn names_with_hyphens_must_work() {
                 arity: ColumnArity::Required,
                 default: None,
                 auto_increment: false,
+                comment: None,
             }];
             assert_eq!(user_table.columns, expected_columns);
         },
@@ -295,6 +315,7 @@ fn composite_primary_keys_must_work() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -305,6 +326,7 @@ fn composite_primary_keys_must_work() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -320,6 +342,8 @@ fn composite_primary_keys_must_work() {
                         sequence: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
         },
@@ -355,6 +379,7 @@ fn indices_must_work() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -365,6 +390,7 @@ fn indices_must_work() {
                     arity: ColumnArity::Required,
                     default,
                     auto_increment: true,
+                    comment: None,
                 },
             ];
             let pk_sequence = match db_type {
@@ -390,6 +416,8 @@ fn indices_must_work() {
                         sequence: pk_sequence,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
         },
@@ -426,6 +454,7 @@ fn column_uniqueness_must_be_detected() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "uniq2".to_string(),
@@ -436,6 +465,7 @@ fn column_uniqueness_must_be_detected() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             let mut expected_indices = vec![Index {
@@ -471,6 +501,8 @@ fn column_uniqueness_must_be_detected() {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
             assert!(
@@ -511,6 +543,7 @@ fn defaults_must_work() {
                 arity: ColumnArity::Nullable,
                 default: Some(default),
                 auto_increment: false,
+                comment: None,
             }];
             assert_eq!(
                 user_table,
@@ -520,6 +553,8 @@ fn defaults_must_work() {
                     indices: vec![],
                     primary_key: None,
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
         },
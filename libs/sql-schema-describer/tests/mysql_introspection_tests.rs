@@ -70,6 +70,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -80,6 +81,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -90,6 +92,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -100,6 +103,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -110,6 +114,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -120,26 +125,29 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
             tpe: ColumnType {
-                raw: "decimal".to_string(),
+                raw: "decimal(10,0)".to_string(),
                 family: ColumnTypeFamily::Float,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".to_string(),
             tpe: ColumnType {
-                raw: "decimal".to_string(),
+                raw: "decimal(10,0)".to_string(),
                 family: ColumnTypeFamily::Float,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -150,6 +158,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -160,6 +169,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -170,36 +180,40 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "time_col".to_string(),
             tpe: ColumnType {
-                raw: "time".to_string(),
+                raw: "time(0)".to_string(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "datetime_col".to_string(),
             tpe: ColumnType {
-                raw: "datetime".to_string(),
+                raw: "datetime(0)".to_string(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".to_string(),
             tpe: ColumnType {
-                raw: "timestamp".to_string(),
+                raw: "timestamp(0)".to_string(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: Some("CURRENT_TIMESTAMP".to_string()),
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -210,26 +224,29 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "char_col".to_string(),
             tpe: ColumnType {
-                raw: "char".to_string(),
+                raw: "char(1)".to_string(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "varchar_col".to_string(),
             tpe: ColumnType {
-                raw: "varchar".to_string(),
+                raw: "varchar(255)".to_string(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -240,6 +257,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -250,6 +268,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -260,6 +279,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -270,16 +290,18 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "enum_col".to_string(),
             tpe: ColumnType {
                 raw: "enum".to_string(),
-                family: ColumnTypeFamily::String,
+                family: ColumnTypeFamily::Enum("User_enum_col".to_string()),
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -290,26 +312,29 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "binary_col".to_string(),
             tpe: ColumnType {
-                raw: "binary".to_string(),
+                raw: "binary(1)".to_string(),
                 family: ColumnTypeFamily::Binary,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "varbinary_col".to_string(),
             tpe: ColumnType {
-                raw: "varbinary".to_string(),
+                raw: "varbinary(255)".to_string(),
                 family: ColumnTypeFamily::Binary,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -320,6 +345,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -330,6 +356,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -340,6 +367,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -350,6 +378,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -360,6 +389,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -370,6 +400,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -380,6 +411,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -390,6 +422,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -400,6 +433,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -410,6 +444,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -420,6 +455,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -430,6 +466,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -440,6 +477,7 @@ fn all_mysql_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -455,6 +493,8 @@ fn all_mysql_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -496,6 +536,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -506,6 +547,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -516,6 +558,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -526,6 +569,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -536,6 +580,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -550,6 +595,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_2".to_owned()),
@@ -557,6 +603,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_3".to_owned()),
@@ -564,6 +611,7 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_ibfk_4".to_owned()),
@@ -571,8 +619,11 @@ fn mysql_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -641,3 +692,51 @@ fn mysql_join_table_unique_indexes_must_be_inferred() {
         }]
     );
 }
+
+#[test]
+fn mysql_enums_must_work() {
+    setup();
+
+    let mut migration = Migration::new().schema(SCHEMA);
+
+    migration.create_table("User", move |t| {
+        t.add_column("id", types::primary());
+        t.add_column("mood", types::custom("enum('sad', 'ok', 'happy')"));
+    });
+
+    let full_sql = migration.make::<barrel::backend::MySql>();
+    let inspector = get_mysql_describer(&full_sql);
+    let result = inspector.describe(&SCHEMA.to_string()).expect("describing");
+    let column = result.table_bang("User").column_bang("mood");
+
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Enum("User_mood".to_string()));
+    assert_eq!(
+        result.get_enum("User_mood").expect("get enum"),
+        &Enum {
+            name: "User_mood".into(),
+            values: vec!["sad".into(), "ok".into(), "happy".into()],
+        }
+    );
+}
+
+#[test]
+fn mysql_views_and_comments_must_work() {
+    setup();
+
+    let inspector = get_mysql_describer(&format!(
+        "CREATE TABLE `{schema}`.`Product` (id INTEGER PRIMARY KEY, price INTEGER NOT NULL COMMENT 'In cents') COMMENT 'The products we sell';
+        CREATE VIEW `{schema}`.`CheapProduct` AS SELECT id FROM `{schema}`.`Product` WHERE price < 100",
+        schema = SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let table = schema.table_bang("Product");
+
+    assert_eq!(table.comment, Some("The products we sell".into()));
+    assert_eq!(table.column_bang("price").comment, Some("In cents".into()));
+    assert_eq!(table.column_bang("id").comment, None);
+
+    assert!(!schema.has_table("CheapProduct"));
+    let view = schema.get_view("CheapProduct").expect("get view");
+    assert!(view.definition.contains("100"));
+}
@@ -1,7 +1,6 @@
 use barrel::{types, Migration};
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
-use std::collections::HashSet;
 
 mod common;
 mod postgres;
@@ -78,6 +77,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -88,6 +88,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -98,6 +99,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -108,6 +110,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -118,6 +121,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -128,6 +132,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -138,6 +143,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -148,6 +154,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::List,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "binary_col".into(),
@@ -158,6 +165,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -168,6 +176,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -178,6 +187,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "double_col".into(),
@@ -188,6 +198,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "float_col".into(),
@@ -198,6 +209,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "int_col".into(),
@@ -208,6 +220,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "primary_col".into(),
@@ -218,6 +231,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: Some(format!("nextval(\"{}\".\"User_primary_col_seq\"::regclass)", SCHEMA)),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "string1_col".into(),
@@ -228,16 +242,18 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "string2_col".into(),
             tpe: ColumnType {
-                raw: "varchar".into(),
+                raw: "varchar(1)".into(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -248,6 +264,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -258,6 +275,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: Some(format!("nextval(\"{}\".\"User_bigserial_col_seq\"::regclass)", SCHEMA)),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "bit_col".into(),
@@ -268,6 +286,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -278,6 +297,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "box_col".into(),
@@ -288,16 +308,18 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "char_col".into(),
             tpe: ColumnType {
-                raw: "bpchar".into(),
+                raw: "bpchar(1)".into(),
                 family: ColumnTypeFamily::String,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "circle_col".into(),
@@ -308,6 +330,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "interval_col".into(),
@@ -318,6 +341,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "line_col".into(),
@@ -328,6 +352,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -338,6 +363,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -348,6 +374,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "path_col".into(),
@@ -358,6 +385,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -368,6 +396,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -378,6 +407,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -388,6 +418,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -401,6 +432,7 @@ fn all_postgres_column_types_must_work() {
                 SCHEMA
             )),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "serial_col".into(),
@@ -411,46 +443,51 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: Some(format!("nextval(\"{}\".\"User_serial_col_seq\"::regclass)", SCHEMA)),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "time_col".into(),
             tpe: ColumnType {
-                raw: "time".into(),
+                raw: "time(6)".into(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "time_with_zone_col".into(),
             tpe: ColumnType {
-                raw: "timetz".into(),
+                raw: "timetz(6)".into(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".into(),
             tpe: ColumnType {
-                raw: "timestamp".into(),
+                raw: "timestamp(6)".into(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_with_zone_col".into(),
             tpe: ColumnType {
-                raw: "timestamptz".into(),
+                raw: "timestamptz(6)".into(),
                 family: ColumnTypeFamily::DateTime,
             },
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -461,6 +498,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -471,6 +509,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "txid_col".into(),
@@ -481,6 +520,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "json_col".into(),
@@ -491,6 +531,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -501,6 +542,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -511,6 +553,7 @@ fn all_postgres_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -534,6 +577,8 @@ fn all_postgres_column_types_must_work() {
                 },),
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -575,6 +620,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -585,6 +631,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -595,6 +642,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -605,6 +653,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -615,6 +664,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".into(),
@@ -625,6 +675,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -639,6 +690,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_cascade_fkey".to_owned()),
@@ -646,6 +698,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_restrict_fkey".to_owned()),
@@ -653,6 +706,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_default_fkey".to_owned()),
@@ -660,6 +714,7 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: Some("User_city_set_null_fkey".to_owned()),
@@ -667,8 +722,11 @@ fn postgres_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -685,7 +743,7 @@ fn postgres_enums_must_work() {
     let schema = inspector.describe(SCHEMA).expect("describing");
     let got_enum = schema.get_enum("mood").expect("get enum");
 
-    let values: HashSet<String> = ["happy".into(), "ok".into(), "sad".into()].iter().cloned().collect();
+    let values = vec!["sad".into(), "ok".into(), "happy".into()];
     assert_eq!(
         got_enum,
         &Enum {
@@ -695,6 +753,28 @@ fn postgres_enums_must_work() {
     );
 }
 
+#[test]
+fn postgres_enum_columns_must_work() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TYPE \"{schema}\".\"mood\" AS ENUM ('sad', 'ok', 'happy');
+        CREATE TABLE \"{schema}\".\"User\" (id SERIAL PRIMARY KEY, mood \"{schema}\".\"mood\" NOT NULL DEFAULT 'ok', moods \"{schema}\".\"mood\"[])",
+        schema = SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let table = schema.table_bang("User");
+
+    let mood = table.column_bang("mood");
+    assert_eq!(mood.tpe.family, ColumnTypeFamily::Enum("mood".into()));
+    assert_eq!(mood.default, Some("ok".into()));
+
+    let moods = table.column_bang("moods");
+    assert_eq!(moods.tpe.family, ColumnTypeFamily::Enum("mood".into()));
+    assert_eq!(moods.arity, ColumnArity::List);
+}
+
 #[test]
 fn postgres_sequences_must_work() {
     setup();
@@ -713,3 +793,34 @@ fn postgres_sequences_must_work() {
         },
     );
 }
+
+#[test]
+fn postgres_views_check_constraints_and_comments_must_work() {
+    setup();
+
+    let inspector = get_postgres_describer(&format!(
+        "CREATE TABLE \"{schema}\".\"Product\" (id SERIAL PRIMARY KEY, price INTEGER NOT NULL CONSTRAINT price_positive CHECK (price > 0));
+        COMMENT ON TABLE \"{schema}\".\"Product\" IS 'The products we sell';
+        COMMENT ON COLUMN \"{schema}\".\"Product\".\"price\" IS 'In cents';
+        CREATE VIEW \"{schema}\".\"CheapProduct\" AS SELECT id FROM \"{schema}\".\"Product\" WHERE price < 100",
+        schema = SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.check_constraints,
+        vec![CheckConstraint {
+            name: Some("price_positive".into()),
+            expression: "((price > 0))".into(),
+        }]
+    );
+    assert_eq!(table.comment, Some("The products we sell".into()));
+    assert_eq!(table.column_bang("price").comment, Some("In cents".into()));
+    assert_eq!(table.column_bang("id").comment, None);
+
+    assert!(!schema.has_table("CheapProduct"));
+    let view = schema.get_view("CheapProduct").expect("get view");
+    assert!(view.definition.contains("price < 100"));
+}
@@ -6,7 +6,6 @@ use log::{debug, LevelFilter};
 use pretty_assertions::assert_eq;
 use quaint::connector::{Queryable, Sqlite as SqliteDatabaseClient};
 use sql_schema_describer::*;
-use std::collections::HashSet;
 use std::fs::File;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -51,9 +50,7 @@ fn setup() {
 fn database_schema_is_serializable() {
     setup();
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("option1".to_string());
-    enum_values.insert("option2".to_string());
+    let enum_values = vec!["option1".to_string(), "option2".to_string()];
     let schema = SqlSchema {
         tables: vec![
             Table {
@@ -68,6 +65,7 @@ fn database_schema_is_serializable() {
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -78,6 +76,7 @@ fn database_schema_is_serializable() {
                         arity: ColumnArity::Nullable,
                         default: Some("default value".to_string()),
                         auto_increment: false,
+                        comment: Some("column comment".to_string()),
                     },
                     Column {
                         name: "column3".to_string(),
@@ -88,6 +87,7 @@ fn database_schema_is_serializable() {
                         arity: ColumnArity::Required,
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
@@ -105,7 +105,13 @@ fn database_schema_is_serializable() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }],
+                check_constraints: vec![CheckConstraint {
+                    name: Some("column3_positive".to_string()),
+                    expression: "column3 > 0".to_string(),
+                }],
+                comment: Some("table comment".to_string()),
             },
             Table {
                 name: "table2".to_string(),
@@ -118,6 +124,7 @@ fn database_schema_is_serializable() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -125,6 +132,8 @@ fn database_schema_is_serializable() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![Enum {
@@ -136,6 +145,10 @@ fn database_schema_is_serializable() {
             initial_value: 1,
             allocation_size: 32,
         }],
+        views: vec![View {
+            name: "view1".to_string(),
+            definition: "SELECT column1 FROM table1".to_string(),
+        }],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -165,13 +178,17 @@ fn database_schema_without_primary_key_is_serializable() {
                 arity: ColumnArity::Nullable,
                 default: None,
                 auto_increment: false,
+                comment: None,
             }],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -215,6 +232,7 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         arity: ColumnArity::Nullable,
         default: None,
         auto_increment: false,
+        comment: None,
     })
     .collect();
     let schema = SqlSchema {
@@ -224,9 +242,12 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -257,6 +278,7 @@ fn database_schema_is_serializable_for_every_column_arity() {
             arity: arity.to_owned(),
             default: None,
             auto_increment: false,
+            comment: None,
         })
         .collect();
     let schema = SqlSchema {
@@ -266,9 +288,12 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -300,6 +325,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     arity: ColumnArity::Nullable,
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column2".to_string(),
@@ -310,6 +336,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     arity: ColumnArity::Nullable,
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column3".to_string(),
@@ -320,6 +347,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     arity: ColumnArity::Nullable,
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column4".to_string(),
@@ -330,6 +358,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     arity: ColumnArity::Nullable,
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column5".to_string(),
@@ -340,6 +369,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     arity: ColumnArity::Nullable,
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -351,6 +381,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -358,6 +389,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -365,6 +397,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -372,6 +405,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -379,11 +413,15 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     referenced_table: "table2".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -396,3 +434,18 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
     // Verify that schema deserialized from reference JSON is equivalent
     assert_eq!(ref_schema, schema);
 }
+
+#[test]
+fn database_schema_serialized_without_update_actions_is_deserializable() {
+    setup();
+
+    let ref_schema_json = include_str!("./resources/schema-without-update-actions.json");
+    let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
+
+    let table = ref_schema.table_bang("table1");
+    assert_eq!(table.foreign_keys.len(), 1);
+    assert_eq!(table.foreign_keys[0].on_delete_action, ForeignKeyAction::NoAction);
+    assert_eq!(table.foreign_keys[0].on_update_action, ForeignKeyAction::NoAction);
+    assert!(table.check_constraints.is_empty());
+    assert!(ref_schema.views.is_empty());
+}
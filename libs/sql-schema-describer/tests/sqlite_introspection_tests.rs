@@ -34,6 +34,7 @@ fn sqlite_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -44,6 +45,7 @@ fn sqlite_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -54,6 +56,7 @@ fn sqlite_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -64,6 +67,7 @@ fn sqlite_column_types_must_work() {
             arity: ColumnArity::Required,
             default: None,
             auto_increment: true,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -79,6 +83,8 @@ fn sqlite_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -119,6 +125,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -129,6 +136,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -139,6 +147,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -149,6 +158,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -159,6 +169,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Nullable,
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -169,6 +180,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     arity: ColumnArity::Required,
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -183,6 +195,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::NoAction,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -190,6 +203,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Cascade,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -197,6 +211,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::Restrict,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -204,6 +219,7 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetDefault,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: None,
@@ -211,8 +227,11 @@ fn sqlite_foreign_key_on_delete_must_be_handled() {
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -248,3 +267,41 @@ fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_indexe
         }
     );
 }
+
+#[test]
+fn sqlite_views_and_check_constraints_must_work() {
+    setup();
+
+    let inspector = get_sqlite_describer(&format!(
+        "CREATE TABLE \"{schema}\".\"Product\" (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL CHECK (price > 0),
+            discount INTEGER,
+            CONSTRAINT \"discount_below_price\" CHECK (discount IS NULL OR (discount < price))
+        );
+        CREATE VIEW \"{schema}\".\"CheapProduct\" AS SELECT id FROM \"Product\" WHERE price < 100",
+        schema = SCHEMA
+    ));
+
+    let schema = inspector.describe(SCHEMA).expect("describing");
+    let table = schema.table_bang("Product");
+
+    assert_eq!(
+        table.check_constraints,
+        vec![
+            CheckConstraint {
+                name: None,
+                expression: "price > 0".into(),
+            },
+            CheckConstraint {
+                name: Some("discount_below_price".into()),
+                expression: "discount IS NULL OR (discount < price)".into(),
+            },
+        ]
+    );
+    assert_eq!(table.comment, None);
+
+    assert!(!schema.has_table("CheapProduct"));
+    let view = schema.get_view("CheapProduct").expect("get view");
+    assert!(view.definition.contains("price < 100"));
+}
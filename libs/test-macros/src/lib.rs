@@ -125,12 +125,18 @@ pub fn test_one_connector(attr: TokenStream, input: TokenStream) -> TokenStream
     );
     let api_factory = Ident::new(&format!("{}_test_api", args.connector), Span::call_site());
 
+    let test_call = if test_function.sig.asyncness.is_some() {
+        quote! { async_std::task::block_on(#test_impl_name(&api)) }
+    } else {
+        quote! { #test_impl_name(&api) }
+    };
+
     let output = quote! {
         #[test]
         fn #test_fn_name() {
             let api = #api_factory();
 
-            #test_impl_name(&api)
+            #test_call
         }
 
         #test_function
@@ -1,4 +1,4 @@
-use crate::{ConnectorResult, MigrationStep};
+use crate::{ConnectorResult, MigrationScript, MigrationStep};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
@@ -6,7 +6,15 @@ use datamodel::Datamodel;
 /// may gather additional information itself, e.g. through introspection of the underlying database.
 pub trait DatabaseMigrationInferrer<T>: Send + Sync + 'static {
     /// Infer the database migration steps. The previous datamodel is provided, but the implementor can ignore it.
-    fn infer(&self, previous: &Datamodel, next: &Datamodel, steps: &[MigrationStep]) -> ConnectorResult<T>;
+    /// The user-provided script runs at the points it defines, and its `down` part is part of the returned
+    /// migration's rollback.
+    fn infer(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+        script: &MigrationScript,
+    ) -> ConnectorResult<T>;
 
     /// Infer a database migration based on the previous and next datamodels. The method signature is identical to `infer`,
     /// but it is expected that this method is implemented based on the provided previous datamodel, and does not rely
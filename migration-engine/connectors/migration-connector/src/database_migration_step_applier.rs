@@ -11,6 +11,30 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync + 'static {
     /// Returns true to signal to the caller that there are more steps to unapply.
     fn unapply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
+    /// Whether the database can apply the migration in a single transaction. If it can, the migration is applied
+    /// with `apply_all_steps` and reverted with `unapply_all_steps`, so that a failing step leaves the database as it
+    /// was.
+    fn supports_transactional_ddl(&self, database_migration: &T) -> bool;
+
+    /// Applies all steps in a single transaction, which is rolled back when one of them fails. Returns the number of
+    /// applied steps. Only called when `supports_transactional_ddl` returns true.
+    fn apply_all_steps(&self, database_migration: &T) -> ConnectorResult<usize>;
+
+    /// Unapplies all steps in a single transaction, the counterpart of `apply_all_steps`.
+    fn unapply_all_steps(&self, database_migration: &T) -> ConnectorResult<usize>;
+
+    /// Best-effort recovery for databases without transactional DDL, called after applying the migration failed
+    /// midway. Reverts the first `applied_steps` steps, the ones that ran before the failing step, in reverse order.
+    /// Steps that can't be reverted are skipped. Returns their errors.
+    fn unapply_steps_best_effort(&self, database_migration: &T, applied_steps: usize) -> Vec<ConnectorError>;
+
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<serde_json::Value>>;
+
+    /// Render the complete script applying the migration, in the order the steps would be applied. Nothing is
+    /// executed against the database.
+    fn render_script(&self, database_migration: &T) -> ConnectorResult<String>;
+
+    /// Render the complete script reverting the migration, the counterpart of `render_script`.
+    fn render_rollback_script(&self, database_migration: &T) -> ConnectorResult<String>;
 }
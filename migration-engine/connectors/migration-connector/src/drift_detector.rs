@@ -0,0 +1,36 @@
+use crate::ConnectorResult;
+use datamodel::Datamodel;
+use serde::Serialize;
+
+/// Implementors of this trait compare the schema the migration history leads to with the schema actually found in
+/// the database, to surface changes that were applied by hand.
+pub trait DriftDetector: Send + Sync + 'static {
+    /// Report every difference between the database and the schema expected for the datamodel.
+    fn detect_drift(&self, expected: &Datamodel) -> ConnectorResult<Vec<Drift>>;
+}
+
+/// A single difference between the expected and the actual database schema.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Drift {
+    pub kind: DriftKind,
+    pub table: String,
+    /// The name of the column, index or foreign key that drifted. Absent for missing and extra tables.
+    pub name: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum DriftKind {
+    MissingTable,
+    ExtraTable,
+    MissingColumn,
+    ExtraColumn,
+    ChangedColumn,
+    MissingIndex,
+    ExtraIndex,
+    RenamedIndex,
+    MissingForeignKey,
+    ExtraForeignKey,
+}
@@ -1,3 +1,4 @@
+use crate::MigrationError;
 use failure::{Error, Fail};
 
 #[derive(Debug, Fail)]
@@ -31,6 +32,9 @@ pub enum ConnectorError {
 
     #[fail(display = "Error opening a TLS connection. {}", message)]
     TlsError { message: String },
+
+    #[fail(display = "The migration violates foreign key constraints: {:?}", _0)]
+    ForeignKeyViolations(Vec<MigrationError>),
 }
 
 impl From<quaint::error::Error> for ConnectorError {
@@ -3,18 +3,22 @@
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_changes_checker;
+mod drift_detector;
 mod error;
 mod migration_applier;
 mod migration_persistence;
+mod migration_script;
 
 pub mod steps;
 
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_changes_checker::*;
+pub use drift_detector::*;
 pub use error::*;
 pub use migration_applier::*;
 pub use migration_persistence::*;
+pub use migration_script::*;
 pub use steps::MigrationStep;
 
 use std::fmt::Debug;
@@ -54,6 +58,9 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// See [DestructiveChangesChecker](trait.DestructiveChangesChecker.html).
     fn destructive_changes_checker(&self) -> Arc<dyn DestructiveChangesChecker<Self::DatabaseMigration>>;
 
+    /// See [DriftDetector](trait.DriftDetector.html).
+    fn drift_detector(&self) -> Arc<dyn DriftDetector>;
+
     // TODO: figure out if this is the best way to do this or move to a better place/interface
     // this is placed here so i can use the associated type
     fn deserialize_database_migration(&self, json: serde_json::Value) -> Self::DatabaseMigration;
@@ -20,7 +20,12 @@ impl<T: 'static> MigrationApplier<T> for MigrationApplierImpl<T> {
         migration_updates.status = MigrationStatus::MigrationInProgress;
         self.migration_persistence.update(&migration_updates);
 
-        let apply_result = self.go_forward(&mut migration_updates, database_migration);
+        let transactional = self.step_applier.supports_transactional_ddl(database_migration);
+        let apply_result = if transactional {
+            self.go_forward_in_transaction(&mut migration_updates, database_migration)
+        } else {
+            self.go_forward(&mut migration_updates, database_migration)
+        };
 
         match apply_result {
             Ok(()) => {
@@ -31,6 +36,17 @@ impl<T: 'static> MigrationApplier<T> for MigrationApplierImpl<T> {
             Err(err) => {
                 migration_updates.status = MigrationStatus::MigrationFailure;
                 migration_updates.errors = vec![format!("{:?}", err)];
+
+                // Without a transaction, the steps applied before the failing one are still in place.
+                if !transactional {
+                    let rollback_errors = self
+                        .step_applier
+                        .unapply_steps_best_effort(database_migration, migration_updates.applied);
+                    migration_updates
+                        .errors
+                        .extend(rollback_errors.iter().map(|err| format!("{:?}", err)));
+                }
+
                 self.migration_persistence.update(&migration_updates);
                 Err(err)
             }
@@ -43,7 +59,11 @@ impl<T: 'static> MigrationApplier<T> for MigrationApplierImpl<T> {
         migration_updates.status = MigrationStatus::RollingBack;
         self.migration_persistence.update(&migration_updates);
 
-        let unapply_result = self.go_backward(&mut migration_updates, database_migration);
+        let unapply_result = if self.step_applier.supports_transactional_ddl(database_migration) {
+            self.go_backward_in_transaction(&mut migration_updates, database_migration)
+        } else {
+            self.go_backward(&mut migration_updates, database_migration)
+        };
 
         match unapply_result {
             Ok(()) => {
@@ -72,6 +92,16 @@ impl<T: 'static> MigrationApplierImpl<T> {
         Ok(())
     }
 
+    fn go_forward_in_transaction(
+        &self,
+        migration_updates: &mut MigrationUpdateParams,
+        database_migration: &T,
+    ) -> ConnectorResult<()> {
+        migration_updates.applied += self.step_applier.apply_all_steps(&database_migration)?;
+        self.migration_persistence.update(&migration_updates);
+        Ok(())
+    }
+
     fn go_backward(
         &self,
         migration_updates: &mut MigrationUpdateParams,
@@ -85,4 +115,14 @@ impl<T: 'static> MigrationApplierImpl<T> {
         }
         Ok(())
     }
+
+    fn go_backward_in_transaction(
+        &self,
+        migration_updates: &mut MigrationUpdateParams,
+        database_migration: &T,
+    ) -> ConnectorResult<()> {
+        migration_updates.rolled_back += self.step_applier.unapply_all_steps(&database_migration)?;
+        self.migration_persistence.update(&migration_updates);
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use crate::steps::*;
+use crate::MigrationScript;
 use chrono::{DateTime, Utc};
 use datamodel::{ast::SchemaAst, Datamodel};
 use serde::Serialize;
@@ -88,6 +89,7 @@ pub struct Migration {
     pub datamodel: Datamodel,
     pub datamodel_steps: Vec<MigrationStep>,
     pub database_migration: serde_json::Value,
+    pub script: MigrationScript,
     pub errors: Vec<String>,
     pub started_at: DateTime<Utc>,
     pub finished_at: Option<DateTime<Utc>>,
@@ -129,6 +131,7 @@ impl Migration {
             datamodel: Datamodel::empty(),
             datamodel_steps: Vec::new(),
             database_migration: serde_json::to_value("{}").unwrap(),
+            script: MigrationScript::default(),
             errors: Vec::new(),
             started_at: Self::timestamp_without_nanos(),
             finished_at: None,
@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+
+/// SQL provided by the user to run as part of a migration, e.g. to backfill new columns from the column they
+/// replace. Each script runs at a defined point relative to the steps inferred by the connector. The statements of a
+/// script are separated by semicolons. Semicolons in strings, quoted identifiers, comments and dollar-quoted bodies
+/// don't end a statement.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MigrationScript {
+    /// Runs before the inferred steps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+
+    /// Runs once the created tables and columns exist, before the dropped ones are dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_drops: Option<String>,
+
+    /// Runs after the inferred steps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+
+    /// Runs when the migration is unapplied, once the tables and columns of the previous migration are back, before
+    /// the ones of this migration are dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub down: Option<String>,
+
+    /// Fill the required columns without default added to tables with rows. The other ones are filled with the
+    /// zero value of their type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backfills: Vec<Backfill>,
+}
+
+/// An SQL expression filling a column added to a table with rows. It can refer to the other columns of the row.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Backfill {
+    /// The database name of the table.
+    pub table: String,
+    /// The database name of the column.
+    pub column: String,
+    pub expression: String,
+}
+
+impl MigrationScript {
+    pub fn is_empty(&self) -> bool {
+        self.before.is_none()
+            && self.before_drops.is_none()
+            && self.after.is_none()
+            && self.down.is_none()
+            && self.backfills.is_empty()
+    }
+
+    /// Splits a script into its statements, each with its terminating semicolon. Quotes in strings and quoted
+    /// identifiers are escaped by doubling them. Parts of the script that only hold comments are left out.
+    pub fn statements(script: &str) -> Vec<String> {
+        let bytes = script.as_bytes();
+        let mut statements = Vec::new();
+        let mut start = 0;
+        let mut has_code = false;
+        let mut idx = 0;
+
+        let mut push_statement = |statement: &str, has_code: bool| {
+            if has_code {
+                statements.push(statement.trim().to_owned());
+            }
+        };
+
+        while idx < bytes.len() {
+            match bytes[idx] {
+                b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                    idx = find(bytes, idx + 2, b"\n").unwrap_or_else(|| bytes.len());
+                }
+                b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                    idx = find(bytes, idx + 2, b"*/")
+                        .map(|end| end + 2)
+                        .unwrap_or_else(|| bytes.len());
+                }
+                quote @ b'\'' | quote @ b'"' | quote @ b'`' => {
+                    has_code = true;
+                    idx = end_of_quoted(bytes, idx + 1, quote);
+                }
+                b'$' => {
+                    has_code = true;
+                    idx = match dollar_quote_tag(&script[idx..]) {
+                        Some(tag) => find(bytes, idx + tag.len(), tag.as_bytes())
+                            .map(|end| end + tag.len())
+                            .unwrap_or_else(|| bytes.len()),
+                        None => idx + 1,
+                    };
+                }
+                b';' => {
+                    push_statement(&script[start..=idx], has_code);
+                    has_code = false;
+                    idx += 1;
+                    start = idx;
+                }
+                byte => {
+                    has_code = has_code || !byte.is_ascii_whitespace();
+                    idx += 1;
+                }
+            }
+        }
+
+        push_statement(&script[start..], has_code);
+
+        statements
+    }
+}
+
+/// The position of the first occurrence of `pattern` in `bytes`, starting the search at `from`.
+fn find(bytes: &[u8], from: usize, pattern: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(pattern.len())
+        .position(|window| window == pattern)
+        .map(|position| from + position)
+}
+
+/// The position after the closing quote of a string or quoted identifier starting at `from`.
+fn end_of_quoted(bytes: &[u8], from: usize, quote: u8) -> usize {
+    let mut idx = from;
+
+    while idx < bytes.len() {
+        if bytes[idx] == quote {
+            if bytes.get(idx + 1) == Some(&quote) {
+                idx += 2;
+                continue;
+            }
+
+            return idx + 1;
+        }
+
+        idx += 1;
+    }
+
+    bytes.len()
+}
+
+/// The tag opening a Postgres dollar-quoted string, e.g. `$$` or `$body$`, if the script starts with one.
+fn dollar_quote_tag(script: &str) -> Option<&str> {
+    let end = script[1..].find('$')? + 1;
+    let is_tag = script[1..end]
+        .chars()
+        .enumerate()
+        .all(|(idx, c)| c == '_' || c.is_ascii_alphabetic() || (idx > 0 && c.is_ascii_digit()));
+
+    if is_tag {
+        Some(&script[..=end])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statements_are_separated_by_semicolons() {
+        let script = "UPDATE \"User\" SET age = 1;\nUPDATE \"User\"\nSET name = 'a'; SELECT 1;\n";
+
+        assert_eq!(
+            MigrationScript::statements(script),
+            &[
+                "UPDATE \"User\" SET age = 1;",
+                "UPDATE \"User\"\nSET name = 'a';",
+                "SELECT 1;"
+            ]
+        );
+    }
+
+    #[test]
+    fn the_last_statement_does_not_need_a_semicolon() {
+        assert_eq!(
+            MigrationScript::statements("SELECT 1;\nSELECT 2\n"),
+            &["SELECT 1;", "SELECT 2"]
+        );
+    }
+
+    #[test]
+    fn semicolons_in_strings_and_quoted_identifiers_do_not_end_statements() {
+        let script = "INSERT INTO \"a;b\" (`c;`) VALUES ('it''s;\n', 'd');\nSELECT 1;";
+
+        assert_eq!(
+            MigrationScript::statements(script),
+            &["INSERT INTO \"a;b\" (`c;`) VALUES ('it''s;\n', 'd');", "SELECT 1;"]
+        );
+    }
+
+    #[test]
+    fn semicolons_in_dollar_quoted_bodies_do_not_end_statements() {
+        let do_block = "DO $$\nBEGIN\n  UPDATE \"User\" SET age = 1;\nEND\n$$;";
+        let function = "CREATE FUNCTION one() RETURNS integer AS $body$\n  SELECT 1;\n$body$ LANGUAGE SQL;";
+        let script = format!("{}\n{}\nSELECT $1;", do_block, function);
+
+        assert_eq!(
+            MigrationScript::statements(&script),
+            &[do_block, function, "SELECT $1;"]
+        );
+    }
+
+    #[test]
+    fn comments_do_not_end_statements_and_are_not_statements_of_their_own() {
+        let script = "-- Move the names;\nUPDATE \"User\" /* all; */ SET name = 'a';\n-- Done;\n/* ; */";
+
+        assert_eq!(
+            MigrationScript::statements(script),
+            &["-- Move the names;\nUPDATE \"User\" /* all; */ SET name = 'a';"]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use failure::{Error, Fail};
-use migration_connector::ConnectorError;
+use migration_connector::{ConnectorError, MigrationError};
 
 pub type SqlResult<T> = Result<T, SqlError>;
 
@@ -34,6 +34,9 @@ pub enum SqlError {
 
     #[fail(display = "Error opening a TLS connection. {}", message)]
     TlsError { message: String },
+
+    #[fail(display = "The migration violates foreign key constraints: {:?}", _0)]
+    ForeignKeyViolations(Vec<MigrationError>),
 }
 
 impl From<SqlError> for ConnectorError {
@@ -46,6 +49,7 @@ impl From<SqlError> for ConnectorError {
             SqlError::ConnectTimeout => Self::ConnectTimeout,
             SqlError::Timeout => Self::Timeout,
             SqlError::TlsError { message } => Self::TlsError { message },
+            SqlError::ForeignKeyViolations(violations) => Self::ForeignKeyViolations(violations),
             error => Self::QueryError(error.into()),
         }
     }
@@ -2,14 +2,18 @@
 extern crate log;
 
 mod error;
+mod sql_column_defaults;
+mod sql_column_types;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
+mod sql_drift_detector;
 mod sql_migration;
 mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
+mod sql_schema_renames;
 
 pub use error::*;
 pub use sql_migration::*;
@@ -20,6 +24,7 @@ use sql_connection::{GenericSqlConnection, SyncSqlConnection};
 use sql_database_migration_inferrer::*;
 use sql_database_step_applier::*;
 use sql_destructive_changes_checker::*;
+use sql_drift_detector::*;
 use sql_migration_persistence::*;
 use sql_schema_describer::SqlSchemaDescriberBackend;
 use std::{fs, path::PathBuf, sync::Arc};
@@ -37,6 +42,7 @@ pub struct SqlMigrationConnector {
     pub database_migration_inferrer: Arc<dyn DatabaseMigrationInferrer<SqlMigration>>,
     pub database_migration_step_applier: Arc<dyn DatabaseMigrationStepApplier<SqlMigration>>,
     pub destructive_changes_checker: Arc<dyn DestructiveChangesChecker<SqlMigration>>,
+    pub drift_detector: Arc<dyn DriftDetector>,
     pub database_introspector: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
 }
 
@@ -53,11 +59,23 @@ impl SqlMigrationConnector {
         Self::create_connector(connection, &datasource.url().value)
     }
 
+    /// Create a connector without checking that the database is reachable. The connection is lazy, so as long as
+    /// only datamodels are compared and rendered, the database is never contacted.
+    pub fn new_offline(datasource: &dyn datamodel::Source) -> std::result::Result<Self, ConnectorError> {
+        let connection = GenericSqlConnection::from_datasource(datasource, Some("lift"))?;
+
+        Self::build_connector(connection, &datasource.url().value)
+    }
+
     fn create_connector(connection: GenericSqlConnection, url: &str) -> std::result::Result<Self, ConnectorError> {
         // async connections can be lazy, so we issue a simple query to fail early if the database
         // is not reachable.
         connection.query_raw("SELECT 1", &[])?;
 
+        Self::build_connector(connection, url)
+    }
+
+    fn build_connector(connection: GenericSqlConnection, url: &str) -> std::result::Result<Self, ConnectorError> {
         let schema_name = connection.connection_info().schema_name().to_owned();
         let file_path = connection.connection_info().file_path().map(|s| s.to_owned());
         let sql_family = connection.connection_info().sql_family();
@@ -91,10 +109,17 @@ impl SqlMigrationConnector {
         });
 
         let destructive_changes_checker = Arc::new(SqlDestructiveChangesChecker {
+            sql_family,
             schema_name: schema_name.clone(),
             database: Arc::clone(&conn),
         });
 
+        let drift_detector = Arc::new(SqlDriftDetector {
+            sql_family,
+            schema_name: schema_name.clone(),
+            introspector: Arc::clone(&inspector),
+        });
+
         Ok(Self {
             url: url.to_string(),
             file_path,
@@ -105,6 +130,7 @@ impl SqlMigrationConnector {
             database_migration_inferrer,
             database_migration_step_applier,
             destructive_changes_checker,
+            drift_detector,
             database_introspector: Arc::clone(&inspector),
         })
     }
@@ -194,6 +220,10 @@ impl MigrationConnector for SqlMigrationConnector {
         Arc::clone(&self.destructive_changes_checker)
     }
 
+    fn drift_detector(&self) -> Arc<dyn DriftDetector> {
+        Arc::clone(&self.drift_detector)
+    }
+
     fn deserialize_database_migration(&self, json: serde_json::Value) -> SqlMigration {
         serde_json::from_value(json).expect("Deserializing the database migration failed.")
     }
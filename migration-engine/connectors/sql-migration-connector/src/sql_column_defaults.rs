@@ -0,0 +1,184 @@
+//! Comparison of column defaults and backfilling of the required columns added to existing tables. The describers
+//! return the defaults the way each database spells them (e.g. `'a'::text` on Postgres or `1` for `true` on MySQL),
+//! so they are normalized per SQL family before being compared with the defaults calculated from the datamodel.
+
+use crate::SqlFamily;
+use chrono::NaiveDateTime;
+use sql_schema_describer::{Column, ColumnTypeFamily, SqlSchema};
+
+/// Compare the defaults of two columns and return whether a migration needs to change the default.
+///
+/// Auto-incrementing columns get their defaults from the database. Defaults that are expressions, like
+/// `CURRENT_TIMESTAMP`, can't be declared in the datamodel, so they are only replaced by another default.
+pub(crate) fn column_defaults_differ(sql_family: SqlFamily, previous: &Column, next: &Column) -> bool {
+    if previous.auto_increment || next.auto_increment {
+        return false;
+    }
+
+    match (
+        normalized_default(sql_family, previous),
+        normalized_default(sql_family, next),
+    ) {
+        (Some(ref previous_default), None) if is_expression(previous_default) => false,
+        (previous_default, next_default) => previous_default != next_default,
+    }
+}
+
+/// A copy of a described schema without the zero-value defaults earlier versions of the migration engine gave to all
+/// required columns, e.g. `DEFAULT ''` or `DEFAULT 0`. They are dropped where the expected column has no default, so
+/// they don't show up as default changes. `expected` must use the table and column names of `described`.
+pub(crate) fn without_legacy_defaults(sql_family: SqlFamily, described: &SqlSchema, expected: &SqlSchema) -> SqlSchema {
+    let mut schema = described.clone();
+
+    for table in schema.tables.iter_mut() {
+        let expected_table = match expected.table(&table.name) {
+            Ok(expected_table) => expected_table,
+            Err(_) => continue,
+        };
+
+        for column in table.columns.iter_mut() {
+            let expects_no_default = expected_table
+                .column(&column.name)
+                .map(|expected_column| expected_column.default.is_none())
+                .unwrap_or(false);
+
+            if expects_no_default && column.is_required() && is_legacy_default(sql_family, column) {
+                column.default = None;
+            }
+        }
+    }
+
+    schema
+}
+
+/// Whether the default of the column is the zero value of its type, the default earlier versions of the migration
+/// engine declared for required columns.
+fn is_legacy_default(sql_family: SqlFamily, column: &Column) -> bool {
+    let zero_value = match column.tpe.family {
+        ColumnTypeFamily::String => "",
+        ColumnTypeFamily::Int | ColumnTypeFamily::Float => "0",
+        ColumnTypeFamily::Boolean => "false",
+        ColumnTypeFamily::DateTime => "1970-01-01 00:00:00",
+        _ => return false,
+    };
+
+    normalized_default(sql_family, column).map_or(false, |default| default == zero_value)
+}
+
+/// Returns whether the rows of an existing table need a value for the column when it is added. The database can't
+/// make one up for a required column without default.
+pub(crate) fn needs_backfill(column: &Column) -> bool {
+    column.is_required() && column.default.is_none() && !column.auto_increment
+}
+
+/// The zero value of the column type, as an SQL literal, to backfill the column when no expression was provided.
+/// Returns `None` for the types without an obvious zero value.
+pub(crate) fn backfill_value(column: &Column, schema: &SqlSchema) -> Option<String> {
+    let value = match &column.tpe.family {
+        ColumnTypeFamily::Int | ColumnTypeFamily::Float => "0".to_owned(),
+        ColumnTypeFamily::Boolean => "false".to_owned(),
+        ColumnTypeFamily::String => "''".to_owned(),
+        ColumnTypeFamily::DateTime => "'1970-01-01 00:00:00'".to_owned(),
+        ColumnTypeFamily::Enum(name) => {
+            let value = schema.get_enum(name).and_then(|enm| enm.values.first())?;
+            format!("'{}'", value.replace("'", "''"))
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// The default of a column, without quotes and casts, and with booleans, numbers and date times in a canonical
+/// form.
+fn normalized_default(sql_family: SqlFamily, column: &Column) -> Option<String> {
+    let default = column.default.as_ref()?.trim();
+
+    // The describer strips the casts to text and to the column type, but not the ones to other types.
+    let default = match sql_family {
+        SqlFamily::Postgres => without_casts(default),
+        _ => default,
+    };
+
+    let default = unquoted(default);
+
+    let normalized = match column.tpe.family {
+        ColumnTypeFamily::Boolean => match default.to_lowercase().as_str() {
+            "true" | "1" | "b'1'" => "true".to_owned(),
+            "false" | "0" | "b'0'" => "false".to_owned(),
+            _ => default,
+        },
+        ColumnTypeFamily::Int | ColumnTypeFamily::Float => default
+            .parse::<f64>()
+            .map(|number| number.to_string())
+            .unwrap_or(default),
+        ColumnTypeFamily::DateTime => {
+            // Postgres spells out the type of the timestamp after the value.
+            let date_time = default
+                .trim_end_matches(" without time zone")
+                .trim_end_matches(" with time zone");
+
+            ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+                .iter()
+                .filter_map(|format| NaiveDateTime::parse_from_str(date_time, format).ok())
+                .next()
+                .map(|date_time| date_time.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+                .unwrap_or(default)
+        }
+        _ => default,
+    };
+
+    Some(normalized)
+}
+
+/// Strips the casts at the end of a Postgres default, e.g. `'a'::text`. A `::` in a string literal, like in `'a::b'`,
+/// or in the arguments of a function, like in `nextval('seq'::regclass)`, is not a cast of the whole default.
+fn without_casts(default: &str) -> &str {
+    let mut default = default;
+
+    while let Some(idx) = default.rfind("::") {
+        let (value, tpe) = (&default[..idx], &default[idx + 2..]);
+        let in_string_literal = value.matches('\'').count() % 2 == 1;
+        let is_type = !tpe.contains('\'') && tpe.matches('(').count() == tpe.matches(')').count();
+
+        if in_string_literal || !is_type {
+            break;
+        }
+
+        default = value.trim_end();
+    }
+
+    default
+}
+
+/// Strips the quotes around a string literal. SQLite keeps the default as it was written.
+fn unquoted(default: &str) -> String {
+    if default.len() >= 2 && default.starts_with('\'') && default.ends_with('\'') {
+        default[1..default.len() - 1].replace("''", "'")
+    } else {
+        default.to_owned()
+    }
+}
+
+/// Returns whether the default is computed by the database, like `CURRENT_TIMESTAMP` or `nextval(...)`, rather than
+/// a literal value.
+pub(crate) fn is_expression(default: &str) -> bool {
+    let default = default.trim().to_lowercase();
+
+    if default == "current_timestamp" {
+        return true;
+    }
+
+    // A function call, e.g. `nextval(...)` or `now()`.
+    match default.find('(') {
+        Some(start) if default.ends_with(')') => {
+            let function_name = &default[..start];
+
+            !function_name.is_empty()
+                && function_name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        }
+        _ => false,
+    }
+}
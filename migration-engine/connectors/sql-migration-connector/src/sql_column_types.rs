@@ -0,0 +1,264 @@
+//! Comparison of column types beyond their family. Raw types are normalized per SQL family, so that the different
+//! spellings of a type (e.g. `int4` and `integer` on Postgres) compare equal.
+
+use crate::SqlFamily;
+use sql_schema_describer::{ColumnType, ColumnTypeFamily};
+
+/// The type of the columns of `Decimal` fields without native type. It differs from the `Decimal(65,30)` rendered for
+/// floats, so that introspection can tell both apart, and all its values fit in a `rust_decimal::Decimal`.
+pub(crate) const DECIMAL_RAW_TYPE: &str = "Decimal(28,10)";
+
+/// Compare two column types and return whether a migration needs to change the column type.
+///
+/// Enums are only compared by family, their variants are handled by the enum steps. Within a family, the type of a
+/// column only changes when the next schema explicitly asks for a type, i.e. when its datamodel uses a native type.
+/// A previous type without raw type, like the ones of the schemas calculated from a datamodel without native types,
+/// stands for the type rendered for its family, and `Decimal` fields without native type accept any exact numeric
+/// column. Types are compared with their lengths and precisions, and on SQLite
+/// by their type affinity, since that is all SQLite stores.
+pub(crate) fn column_types_differ(sql_family: SqlFamily, previous: &ColumnType, next: &ColumnType) -> bool {
+    if previous.family != next.family {
+        return true;
+    }
+
+    if next.raw.trim().is_empty() {
+        return false;
+    }
+
+    match (
+        normalized_raw_type(sql_family, previous),
+        normalized_raw_type(sql_family, next),
+    ) {
+        (Some(previous_raw), Some(_)) if next.raw == DECIMAL_RAW_TYPE => {
+            let (previous_base, _) = split_raw_type(&previous_raw);
+
+            previous_base != "numeric" && previous_base != "decimal"
+        }
+        (Some(previous_raw), Some(next_raw)) if sql_family == SqlFamily::Sqlite => {
+            sqlite_type_affinity(&previous_raw) != sqlite_type_affinity(&next_raw)
+        }
+        (Some(previous_raw), Some(next_raw)) => previous_raw != next_raw,
+        _ => false,
+    }
+}
+
+/// Returns whether changing a column from the previous to the next type can lose or reject existing values, e.g.
+/// `BIGINT` to `INT`, `TEXT` to `VARCHAR` or `DECIMAL(10,2)` to `DECIMAL(8,2)`. Changes between types we can't rank
+/// are considered narrowing. On SQLite, only changes of the type affinity can affect the stored values.
+pub(crate) fn is_narrowing_type_change(sql_family: SqlFamily, previous: &ColumnType, next: &ColumnType) -> bool {
+    let (previous_raw, next_raw) = match (
+        normalized_raw_type(sql_family, previous),
+        normalized_raw_type(sql_family, next),
+    ) {
+        (Some(previous_raw), Some(next_raw)) => (previous_raw, next_raw),
+        _ => return true,
+    };
+
+    if sql_family == SqlFamily::Sqlite {
+        return sqlite_type_affinity(&previous_raw) != sqlite_type_affinity(&next_raw);
+    }
+
+    let (previous_base, previous_params) = split_raw_type(&previous_raw);
+    let (next_base, next_params) = split_raw_type(&next_raw);
+
+    if previous_base == next_base {
+        return params_narrow(&previous_params, &next_params);
+    }
+
+    match (type_rank(&previous_base), type_rank(&next_base)) {
+        (Some(previous_rank), Some(next_rank)) => next_rank < previous_rank,
+        _ => true,
+    }
+}
+
+/// The normalized raw type of a column, with its length or precision, e.g. `varchar(191)` or `numeric(65,30)`. Types
+/// that have an implicit precision get it spelled out, so that `timestamp` and `timestamp(6)` compare equal on
+/// Postgres. Returns `None` for enums and for types we can't tell anything about.
+pub(crate) fn normalized_raw_type(sql_family: SqlFamily, tpe: &ColumnType) -> Option<String> {
+    if let ColumnTypeFamily::Enum(_) = tpe.family {
+        return None;
+    }
+
+    let raw = tpe.raw.trim().to_lowercase();
+    let raw = if raw.is_empty() {
+        default_raw_type(sql_family, &tpe.family)?.to_owned()
+    } else {
+        raw
+    };
+
+    // Split the params off, e.g. for `varchar(191)` or `timestamp(3) without time zone`.
+    let (base, params) = match raw.find('(') {
+        Some(idx) => {
+            let end = raw[idx..].find(')').map(|end| idx + end).unwrap_or_else(|| raw.len());
+            let suffix = raw.get(end + 1..).unwrap_or("");
+            let params: String = raw[idx + 1..end].split_whitespace().collect();
+
+            (format!("{} {}", raw[..idx].trim(), suffix.trim()), params)
+        }
+        None => (raw.clone(), String::new()),
+    };
+    let base = base.split_whitespace().collect::<Vec<_>>().join(" ");
+    let base = base.trim_end_matches("[]");
+    let base = match sql_family {
+        SqlFamily::Postgres => base.trim_start_matches('_'),
+        _ => base,
+    };
+    let base = canonical_raw_type(sql_family, base);
+
+    let params = if params.is_empty() {
+        implicit_raw_type_params(sql_family, base).unwrap_or("")
+    } else {
+        params.as_str()
+    };
+
+    if params.is_empty() {
+        Some(base.to_owned())
+    } else {
+        Some(format!("{}({})", base, params))
+    }
+}
+
+/// Split a normalized raw type into its base type and its numeric params, e.g. `decimal` and `[10, 2]`.
+fn split_raw_type(raw: &str) -> (String, Vec<u32>) {
+    match raw.find('(') {
+        Some(idx) => {
+            let params = raw[idx + 1..]
+                .trim_end_matches(')')
+                .split(',')
+                .filter_map(|param| param.parse().ok())
+                .collect();
+
+            (raw[..idx].to_owned(), params)
+        }
+        None => (raw.to_owned(), Vec::new()),
+    }
+}
+
+/// Whether the params of the next type hold less than the ones of the previous type. For two params, like the
+/// precision and scale of decimals, the digits before the decimal point count as well.
+fn params_narrow(previous: &[u32], next: &[u32]) -> bool {
+    let narrower = previous.iter().zip(next.iter()).any(|(previous, next)| next < previous);
+
+    let integer_digits = |params: &[u32]| match params {
+        [precision, scale] => Some(precision.saturating_sub(*scale)),
+        _ => None,
+    };
+
+    match (integer_digits(previous), integer_digits(next)) {
+        (Some(previous_digits), Some(next_digits)) => narrower || next_digits < previous_digits,
+        _ => narrower,
+    }
+}
+
+/// The type affinity SQLite gives a declared type, following the rules of section 3.1 of
+/// https://www.sqlite.org/datatype3.html.
+fn sqlite_type_affinity(raw: &str) -> &'static str {
+    if raw.contains("int") {
+        "integer"
+    } else if raw.contains("char") || raw.contains("clob") || raw.contains("text") {
+        "text"
+    } else if raw.contains("blob") || raw.is_empty() {
+        "blob"
+    } else if raw.contains("real") || raw.contains("floa") || raw.contains("doub") {
+        "real"
+    } else {
+        "numeric"
+    }
+}
+
+/// The raw types the `SqlRenderer`s create for each family. This must be kept in sync with them.
+fn default_raw_type(sql_family: SqlFamily, family: &ColumnTypeFamily) -> Option<&'static str> {
+    let raw = match (sql_family, family) {
+        (SqlFamily::Postgres, ColumnTypeFamily::Boolean) => "bool",
+        (SqlFamily::Postgres, ColumnTypeFamily::DateTime) => "timestamp(3)",
+        (SqlFamily::Postgres, ColumnTypeFamily::Float) => "numeric(65,30)",
+        (SqlFamily::Postgres, ColumnTypeFamily::Int) => "int4",
+        (SqlFamily::Postgres, ColumnTypeFamily::String) => "text",
+        (SqlFamily::Postgres, ColumnTypeFamily::Binary) => "bytea",
+        (SqlFamily::Postgres, ColumnTypeFamily::Json) => "jsonb",
+        (SqlFamily::Postgres, ColumnTypeFamily::Uuid) => "uuid",
+        (SqlFamily::Mysql, ColumnTypeFamily::Boolean) => "tinyint",
+        (SqlFamily::Mysql, ColumnTypeFamily::DateTime) => "datetime(3)",
+        (SqlFamily::Mysql, ColumnTypeFamily::Float) => "decimal(65,30)",
+        (SqlFamily::Mysql, ColumnTypeFamily::Int) => "int",
+        (SqlFamily::Mysql, ColumnTypeFamily::String) => "varchar(191)",
+        (SqlFamily::Mysql, ColumnTypeFamily::Binary) => "longblob",
+        (SqlFamily::Mysql, ColumnTypeFamily::Json) => "json",
+        (SqlFamily::Mysql, ColumnTypeFamily::Uuid) => "char(36)",
+        (SqlFamily::Sqlite, ColumnTypeFamily::Boolean) => "boolean",
+        (SqlFamily::Sqlite, ColumnTypeFamily::DateTime) => "date",
+        (SqlFamily::Sqlite, ColumnTypeFamily::Float) => "real",
+        (SqlFamily::Sqlite, ColumnTypeFamily::Int) => "integer",
+        (SqlFamily::Sqlite, ColumnTypeFamily::String) => "text",
+        (SqlFamily::Sqlite, ColumnTypeFamily::Binary) => "blob",
+        _ => return None,
+    };
+
+    Some(raw)
+}
+
+/// The params a type has when it is declared without them.
+fn implicit_raw_type_params(sql_family: SqlFamily, base: &str) -> Option<&'static str> {
+    match (sql_family, base) {
+        (SqlFamily::Postgres, "time") | (SqlFamily::Postgres, "timetz") => Some("6"),
+        (SqlFamily::Postgres, "timestamp") | (SqlFamily::Postgres, "timestamptz") => Some("6"),
+        (SqlFamily::Postgres, "bpchar") => Some("1"),
+        (SqlFamily::Mysql, "time") | (SqlFamily::Mysql, "datetime") | (SqlFamily::Mysql, "timestamp") => Some("0"),
+        (SqlFamily::Mysql, "char") | (SqlFamily::Mysql, "binary") => Some("1"),
+        (SqlFamily::Mysql, "decimal") => Some("10,0"),
+        _ => None,
+    }
+}
+
+/// Map the aliases of a type to the name the database describes it with.
+fn canonical_raw_type(sql_family: SqlFamily, raw: &str) -> &str {
+    match (sql_family, raw) {
+        (SqlFamily::Postgres, "smallint") | (SqlFamily::Postgres, "smallserial") => "int2",
+        (SqlFamily::Postgres, "integer") | (SqlFamily::Postgres, "int") | (SqlFamily::Postgres, "serial") => "int4",
+        (SqlFamily::Postgres, "bigint") | (SqlFamily::Postgres, "bigserial") => "int8",
+        (SqlFamily::Postgres, "real") => "float4",
+        (SqlFamily::Postgres, "double precision") => "float8",
+        (SqlFamily::Postgres, "decimal") => "numeric",
+        (SqlFamily::Postgres, "boolean") => "bool",
+        (SqlFamily::Postgres, "character varying") => "varchar",
+        (SqlFamily::Postgres, "character") | (SqlFamily::Postgres, "char") => "bpchar",
+        (SqlFamily::Postgres, "timestamp without time zone") => "timestamp",
+        (SqlFamily::Postgres, "timestamp with time zone") => "timestamptz",
+        (SqlFamily::Postgres, "time without time zone") => "time",
+        (SqlFamily::Postgres, "time with time zone") => "timetz",
+        (SqlFamily::Mysql, "integer") => "int",
+        (SqlFamily::Mysql, "boolean") | (SqlFamily::Mysql, "bool") => "tinyint",
+        (SqlFamily::Mysql, "numeric") => "decimal",
+        (SqlFamily::Mysql, "double precision") | (SqlFamily::Mysql, "real") => "double",
+        (SqlFamily::Mysql, "character varying") => "varchar",
+        (SqlFamily::Mysql, "character") => "char",
+        (SqlFamily::Sqlite, "int") => "integer",
+        (_, raw) => raw,
+    }
+}
+
+/// The relative capacity of a normalized base type within its family. Types of the same rank can hold the same
+/// values.
+fn type_rank(raw: &str) -> Option<u8> {
+    let rank = match raw {
+        "tinyint" => 0,
+        "int2" | "smallint" => 1,
+        "mediumint" => 2,
+        "int4" | "int" | "integer" => 3,
+        "int8" | "bigint" => 4,
+        "float4" | "float" | "real" => 1,
+        "float8" | "double" => 2,
+        "numeric" | "decimal" => 3,
+        "bpchar" | "char" | "varchar" | "tinytext" => 1,
+        "text" => 2,
+        "mediumtext" => 3,
+        "longtext" => 4,
+        "date" => 1,
+        "datetime" | "timestamp" => 2,
+        "timestamptz" => 3,
+        "boolean" | "bool" => 1,
+        _ => return None,
+    };
+
+    Some(rank)
+}
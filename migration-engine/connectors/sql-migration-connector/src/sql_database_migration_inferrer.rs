@@ -1,5 +1,7 @@
+use crate::sql_column_defaults::{backfill_value, needs_backfill, without_legacy_defaults};
 use crate::sql_schema_calculator::SqlSchemaCalculator;
 use crate::sql_schema_differ::{SqlSchemaDiff, SqlSchemaDiffer};
+use crate::sql_schema_renames::SqlSchemaRenames;
 use crate::*;
 use datamodel::*;
 use migration_connector::steps::MigrationStep;
@@ -16,15 +18,26 @@ pub struct SqlDatabaseMigrationInferrer {
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
     fn infer(
         &self,
-        _previous: &Datamodel,
+        previous: &Datamodel,
         next: &Datamodel,
         _steps: &[MigrationStep],
+        script: &MigrationScript,
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.introspect(&self.schema_name)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family)?;
+        let renames = SqlSchemaRenames::calculate(previous, next, &current_database_schema);
+        let current_database_schema = without_legacy_defaults(
+            self.sql_family,
+            &current_database_schema,
+            &renames.reversed().apply(&expected_database_schema),
+        );
+        let rollback_database_schema = with_renderable_raw_types(&current_database_schema, self.sql_family);
         infer(
             &current_database_schema,
             &expected_database_schema,
+            &rollback_database_schema,
+            &renames,
+            script,
             &self.schema_name,
             self.sql_family,
         )
@@ -36,11 +49,15 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         next: &Datamodel,
         _steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
-        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous)?;
-        let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
+        let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous, self.sql_family)?;
+        let expected_database_schema = SqlSchemaCalculator::calculate(next, self.sql_family)?;
+        let renames = SqlSchemaRenames::calculate(previous, next, &current_database_schema);
         infer(
             &current_database_schema,
             &expected_database_schema,
+            &current_database_schema,
+            &renames,
+            &MigrationScript::default(),
             &self.schema_name,
             self.sql_family,
         )
@@ -53,56 +70,215 @@ impl SqlDatabaseMigrationInferrer {
     }
 }
 
+/// The renamed tables and columns are renamed first. The rest of the migration is inferred from the schema with the
+/// renames applied, which is the schema the migration starts from. The `before` and `after` scripts of the user
+/// surround all of it.
 fn infer(
     current_database_schema: &SqlSchema,
     expected_database_schema: &SqlSchema,
+    rollback_database_schema: &SqlSchema,
+    renames: &SqlSchemaRenames,
+    script: &MigrationScript,
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> ConnectorResult<SqlMigration> {
-    let (original_steps, corrected_steps) = infer_database_migration_steps_and_fix(
-        &current_database_schema,
+    let renamed_database_schema = renames.apply(current_database_schema);
+    let (original_steps, corrected_steps) = infer_database_migration_steps_around_script(
+        &renamed_database_schema,
         &expected_database_schema,
+        script.before_drops.as_ref(),
+        &script.backfills,
         &schema_name,
         sql_family,
     )?;
-    let (_, rollback) = infer_database_migration_steps_and_fix(
+
+    let reversed_renames = renames.reversed();
+    let (_, rollback) = infer_database_migration_steps_around_script(
         &expected_database_schema,
-        &current_database_schema,
+        &renames.apply(rollback_database_schema),
+        script.down.as_ref(),
+        &[],
         &schema_name,
         sql_family,
     )?;
+
+    let before_steps = script_steps(script.before.as_ref());
+    let after_steps = script_steps(script.after.as_ref());
+    // The renamed columns keep their type, any type change is a step of its own. MySQL renames a column along with
+    // its definition, so it's taken from the database.
+    let rename_steps = renames.steps(&renames.apply(rollback_database_schema));
+    let surround = |steps: Vec<SqlMigrationStep>| -> Vec<SqlMigrationStep> {
+        before_steps
+            .iter()
+            .cloned()
+            .chain(rename_steps.iter().cloned())
+            .chain(steps)
+            .chain(after_steps.iter().cloned())
+            .collect()
+    };
+
     Ok(SqlMigration {
-        before: current_database_schema.clone(),
+        before: renamed_database_schema,
         after: expected_database_schema.clone(),
-        original_steps,
-        corrected_steps,
-        rollback,
+        original_steps: surround(original_steps),
+        corrected_steps: surround(corrected_steps),
+        rollback: rollback
+            .into_iter()
+            .chain(reversed_renames.steps(rollback_database_schema))
+            .collect(),
     })
 }
 
+/// Without a script, the steps are inferred in one go. With a script, the migration first creates everything up to
+/// an intermediate schema that still has the dropped tables, columns and enums, so the script can move the data from
+/// the old to the new ones. The dropped parts are dropped after the script.
+fn infer_database_migration_steps_around_script(
+    from: &SqlSchema,
+    to: &SqlSchema,
+    script: Option<&String>,
+    backfills: &[Backfill],
+    schema_name: &str,
+    sql_family: SqlFamily,
+) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
+    let script = match script {
+        Some(script) => script,
+        None => return infer_database_migration_steps_and_fix(from, to, backfills, schema_name, sql_family),
+    };
+
+    let intermediate = with_dropped_parts(from, to);
+    let (original_creations, corrected_creations) =
+        infer_database_migration_steps_and_fix(from, &intermediate, backfills, schema_name, sql_family)?;
+    let (original_drops, corrected_drops) =
+        infer_database_migration_steps_and_fix(&intermediate, to, &[], schema_name, sql_family)?;
+    let script_steps = script_steps(Some(script));
+
+    Ok((
+        original_creations
+            .into_iter()
+            .chain(script_steps.iter().cloned())
+            .chain(original_drops)
+            .collect(),
+        corrected_creations
+            .into_iter()
+            .chain(script_steps.into_iter())
+            .chain(corrected_drops)
+            .collect(),
+    ))
+}
+
+/// A copy of `to` that keeps the tables, columns, foreign keys and enums of `from` it lacks.
+fn with_dropped_parts(from: &SqlSchema, to: &SqlSchema) -> SqlSchema {
+    let mut schema = to.clone();
+
+    for from_table in &from.tables {
+        let table = match schema.tables.iter_mut().find(|table| table.name == from_table.name) {
+            Some(table) => table,
+            None => {
+                schema.tables.push(from_table.clone());
+                continue;
+            }
+        };
+
+        let dropped_columns: Vec<&Column> = from_table
+            .columns
+            .iter()
+            .filter(|column| !table.has_column(&column.name))
+            .collect();
+
+        let dropped_foreign_keys = from_table.foreign_keys.iter().filter(|foreign_key| {
+            foreign_key
+                .columns
+                .iter()
+                .any(|column| dropped_columns.iter().any(|dropped| dropped.name == *column))
+        });
+
+        table.foreign_keys.extend(dropped_foreign_keys.cloned());
+        table.columns.extend(dropped_columns.into_iter().cloned());
+    }
+
+    for from_enum in &from.enums {
+        if schema.get_enum(&from_enum.name).is_none() {
+            schema.enums.push(from_enum.clone());
+        }
+    }
+
+    schema
+}
+
+/// The statements of a user-provided script, each run as a raw SQL step.
+fn script_steps(script: Option<&String>) -> Vec<SqlMigrationStep> {
+    script
+        .map(|script| MigrationScript::statements(script))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|raw| SqlMigrationStep::RawSql { raw })
+        .collect()
+}
+
+/// A copy of a described schema for the rollback. The described raw types are kept, so that the rollback restores the
+/// column types, unless they can't be rendered back, like Postgres arrays or MySQL sets without their values. Those
+/// columns keep their type when it is unchanged, and are recreated with the default type of their family otherwise.
+pub(crate) fn with_renderable_raw_types(schema: &SqlSchema, sql_family: SqlFamily) -> SqlSchema {
+    let mut schema = schema.clone();
+
+    for column in schema.tables.iter_mut().flat_map(|table| table.columns.iter_mut()) {
+        let renderable = match sql_family {
+            SqlFamily::Postgres => !column.tpe.raw.starts_with('_'),
+            SqlFamily::Mysql => column.tpe.raw != "set",
+            SqlFamily::Sqlite => true,
+        };
+
+        if !renderable {
+            column.tpe.raw = String::new();
+        }
+    }
+
+    schema
+}
+
 fn infer_database_migration_steps_and_fix(
     from: &SqlSchema,
     to: &SqlSchema,
+    backfills: &[Backfill],
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
-    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to);
+    let mut diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to, sql_family);
+    set_backfills(&mut diff, backfills);
+    let original_steps = diff.clone().into_steps();
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     let corrected_steps = if is_sqlite {
         fix_stupid_sqlite(diff, &from, &to, &schema_name)?
     } else {
         let steps = delay_foreign_key_creation(diff);
-        fix_id_column_type_change(&from, &to, schema_name, steps)?
+        fix_id_column_type_change(&from, &to, schema_name, sql_family, steps)?
     };
 
-    Ok((SqlSchemaDiffer::diff(&from, &to).into_steps(), corrected_steps))
+    Ok((original_steps, corrected_steps))
+}
+
+/// Attaches the expressions provided by the user to the columns they fill.
+fn set_backfills(diff: &mut SqlSchemaDiff, backfills: &[Backfill]) {
+    for alter_table in diff.alter_tables.iter_mut() {
+        let table_name = &alter_table.table.name;
+
+        for change in alter_table.changes.iter_mut() {
+            if let TableChange::AddColumn(add_column) = change {
+                add_column.backfill = backfills
+                    .iter()
+                    .find(|backfill| &backfill.table == table_name && backfill.column == add_column.column.name)
+                    .map(|backfill| backfill.expression.clone());
+            }
+        }
+    }
 }
 
 fn fix_id_column_type_change(
     from: &SqlSchema,
     to: &SqlSchema,
     _schema_name: &str,
+    sql_family: SqlFamily,
     steps: Vec<SqlMigrationStep>,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let has_id_type_change = steps
@@ -114,7 +290,8 @@ fn fix_id_column_type_change(
                         TableChange::AlterColumn(alter_column) => {
                             let current_column = current_table.column_bang(&alter_column.name);
                             let current_column_type = &current_column.tpe;
-                            let has_type_changed = current_column_type.family != alter_column.column.tpe.family; // TODO: take into account raw type
+                            // Type changes within the same family are applied in place.
+                            let has_type_changed = current_column_type.family != alter_column.column.tpe.family;
                             let is_part_of_pk = current_table
                                 .primary_key
                                 .clone()
@@ -144,7 +321,12 @@ fn fix_id_column_type_change(
             .map(|t| t.name.clone())
             .collect();
         radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
-        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), &to);
+        // Only the tables are dropped, the existing enums are kept.
+        let from_enums = SqlSchema {
+            enums: from.enums.clone(),
+            ..SqlSchema::empty()
+        };
+        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&from_enums, &to, sql_family);
         let mut steps_from_empty = delay_foreign_key_creation(diff_from_empty);
         radical_steps.append(&mut steps_from_empty);
 
@@ -186,7 +368,12 @@ fn delay_foreign_key_creation(mut diff: SqlSchemaDiff) -> Vec<SqlMigrationStep>
             .retain(|c| !column_that_need_to_be_done_later_for_this_table.contains(&c));
         let changes = column_that_need_to_be_done_later_for_this_table
             .into_iter()
-            .map(|c| TableChange::AddColumn(AddColumn { column: c.clone() }))
+            .map(|c| {
+                TableChange::AddColumn(AddColumn {
+                    column: c.clone(),
+                    backfill: None,
+                })
+            })
             .collect();
 
         let alter_table = AlterTable {
@@ -213,10 +400,23 @@ fn fix_stupid_sqlite(
     for step in steps {
         match step {
             SqlMigrationStep::AlterTable(ref alter_table) if needs_fix(&alter_table) => {
+                let backfills: Vec<(String, String)> = alter_table
+                    .changes
+                    .iter()
+                    .filter_map(|change| match change {
+                        TableChange::AddColumn(AddColumn {
+                            column,
+                            backfill: Some(backfill),
+                        }) => Some((column.name.clone(), backfill.clone())),
+                        _ => None,
+                    })
+                    .collect();
+
                 result.extend(sqlite_fix_table(
                     current_database_schema,
                     next_database_schema,
                     &alter_table.table.name,
+                    &backfills,
                     schema_name,
                 )?);
                 fixed_tables.push(alter_table.table.name.clone());
@@ -229,6 +429,7 @@ fn fix_stupid_sqlite(
                     current_database_schema,
                     next_database_schema,
                     &table,
+                    &[],
                     schema_name,
                 )?);
                 fixed_tables.push(table.clone());
@@ -255,29 +456,75 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
     change_that_does_not_work_on_sqlite.is_some()
 }
 
+/// Turns off foreign key enforcement for the table rebuilds. This is a no-op inside a transaction.
+pub(crate) const SQLITE_DISABLE_FOREIGN_KEYS: &str = "PRAGMA foreign_keys=OFF;";
+
+/// Turns foreign key enforcement back on after the table rebuilds.
+pub(crate) const SQLITE_ENABLE_FOREIGN_KEYS: &str = "PRAGMA foreign_keys=ON;";
+
+/// Lists the rows violating a foreign key after the table rebuilds. The step applier evaluates its results.
+pub(crate) fn sqlite_foreign_key_check(schema_name: &str) -> String {
+    format!(r#"PRAGMA "{}".foreign_key_check;"#, schema_name)
+}
+
 fn sqlite_fix_table(
     current_database_schema: &SqlSchema,
     next_database_schema: &SqlSchema,
     table_name: &str,
+    backfills: &[(String, String)],
     schema_name: &str,
 ) -> SqlResult<impl Iterator<Item = SqlMigrationStep>> {
     let current_table = current_database_schema.table(table_name)?;
     let next_table = next_database_schema.table(table_name)?;
-    Ok(fix(&current_table, &next_table, &schema_name).into_iter())
+    Ok(fix(
+        &current_table,
+        &next_table,
+        next_database_schema,
+        backfills,
+        &schema_name,
+    )
+    .into_iter())
+}
+
+/// Whether the expression mentions the column, quoted or not.
+fn references_column(expression: &str, column_name: &str) -> bool {
+    expression
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|identifier| identifier == column_name)
 }
 
-fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep> {
+/// The backfills are `(column, expression)` pairs provided by the user.
+fn fix(
+    current: &Table,
+    next: &Table,
+    next_schema: &SqlSchema,
+    backfills: &[(String, String)],
+    schema_name: &str,
+) -> Vec<SqlMigrationStep> {
     // based on 'Making Other Kinds Of Table Schema Changes' from https://www.sqlite.org/lang_altertable.html
     let name_of_temporary_table = format!("new_{}", next.name.clone());
     let mut temporary_table = next.clone();
     temporary_table.name = name_of_temporary_table.clone();
+    // Check constraints are not managed by migrations, the rebuilt table keeps the ones that still apply.
+    temporary_table.check_constraints = current
+        .check_constraints
+        .iter()
+        .filter(|check_constraint| {
+            current
+                .columns
+                .iter()
+                .filter(|column| !next.has_column(&column.name))
+                .all(|dropped_column| !references_column(&check_constraint.expression, &dropped_column.name))
+        })
+        .cloned()
+        .collect();
 
     let mut result = Vec::new();
 
     result.push(SqlMigrationStep::RawSql {
-        raw: "PRAGMA foreign_keys=OFF;".to_string(),
+        raw: SQLITE_DISABLE_FOREIGN_KEYS.to_string(),
     });
-    // todo: start transaction now. Unclear if we really want to do that.
+    // The transaction around the rebuild is opened by the `SqlDatabaseStepApplier`, if the whole migration runs in one.
     result.push(SqlMigrationStep::CreateTable(CreateTable { table: temporary_table }));
     result.push(
         // copy table contents; Here we have to handle escpaing ourselves.
@@ -288,16 +535,37 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
                 .into_iter()
                 .filter(|c| next_columns.contains(&c))
                 .collect();
+            // The added required columns without default are filled while copying the rows.
+            let backfilled_columns: Vec<(String, String)> = next
+                .columns
+                .iter()
+                .filter(|c| !current.has_column(&c.name) && needs_backfill(c))
+                .filter_map(|c| {
+                    backfills
+                        .iter()
+                        .find(|(name, _)| name == &c.name)
+                        .map(|(_, expression)| expression.clone())
+                        .or_else(|| backfill_value(c, next_schema))
+                        .map(|value| (c.name.clone(), value))
+                })
+                .collect();
             let columns_string = intersection_columns
                 .iter()
+                .chain(backfilled_columns.iter().map(|(name, _)| name))
                 .map(|c| format!("\"{}\"", c))
                 .collect::<Vec<String>>()
                 .join(",");
+            let values_string = intersection_columns
+                .iter()
+                .map(|c| format!("\"{}\"", c))
+                .chain(backfilled_columns.iter().map(|(_, value)| value.clone()))
+                .collect::<Vec<String>>()
+                .join(",");
             let sql = format!(
                 "INSERT INTO \"{}\" ({}) SELECT {} from \"{}\"",
                 name_of_temporary_table,
                 columns_string,
-                columns_string,
+                values_string,
                 next.name.clone()
             );
             SqlMigrationStep::RawSql { raw: sql.to_string() }
@@ -325,11 +593,10 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
     );
     // todo: recreate triggers
     result.push(SqlMigrationStep::RawSql {
-        raw: format!(r#"PRAGMA "{}".foreign_key_check;"#, schema_name),
+        raw: sqlite_foreign_key_check(schema_name),
     });
-    // todo: commit transaction
     result.push(SqlMigrationStep::RawSql {
-        raw: "PRAGMA foreign_keys=ON;".to_string(),
+        raw: SQLITE_ENABLE_FOREIGN_KEYS.to_string(),
     });
 
     result
@@ -1,6 +1,14 @@
+use crate::sql_column_defaults::{backfill_value, column_defaults_differ, needs_backfill};
+use crate::sql_database_migration_inferrer::{
+    sqlite_foreign_key_check, with_renderable_raw_types, SQLITE_DISABLE_FOREIGN_KEYS, SQLITE_ENABLE_FOREIGN_KEYS,
+};
+use crate::sql_schema_differ::{is_default_change_only, is_type_change_only};
 use crate::*;
+use quaint::connector::ResultSet;
 use sql_connection::SyncSqlConnection;
-use sql_renderer::SqlRenderer;
+use sql_renderer::{
+    render_check_constraint, render_comment, render_default, render_enum_values, render_nullability, SqlRenderer,
+};
 use sql_schema_describer::*;
 use std::sync::Arc;
 
@@ -12,11 +20,83 @@ pub struct SqlDatabaseStepApplier {
 
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
     fn apply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
-        Ok(self.apply_next_step(&database_migration.corrected_steps, index)?)
+        Ok(self.apply_next_step(
+            &database_migration.corrected_steps,
+            index,
+            &database_migration.before,
+            &database_migration.after,
+        )?)
     }
 
     fn unapply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
-        Ok(self.apply_next_step(&database_migration.rollback, index)?)
+        Ok(self.apply_next_step(
+            &database_migration.rollback,
+            index,
+            &database_migration.after,
+            &database_migration.before,
+        )?)
+    }
+
+    /// Postgres and SQLite support transactional DDL, MySQL commits implicitly after every schema change. Postgres
+    /// can't use enum values added in the same transaction though, so migrations adding some are applied step by
+    /// step.
+    fn supports_transactional_ddl(&self, database_migration: &SqlMigration) -> bool {
+        match self.sql_family {
+            SqlFamily::Postgres => {
+                !adds_enum_values(&database_migration.corrected_steps)
+                    && !adds_enum_values(&database_migration.rollback)
+            }
+            SqlFamily::Sqlite => true,
+            SqlFamily::Mysql => false,
+        }
+    }
+
+    fn apply_all_steps(&self, database_migration: &SqlMigration) -> ConnectorResult<usize> {
+        Ok(self.apply_steps_in_transaction(
+            &database_migration.corrected_steps,
+            &database_migration.before,
+            &database_migration.after,
+        )?)
+    }
+
+    fn unapply_all_steps(&self, database_migration: &SqlMigration) -> ConnectorResult<usize> {
+        Ok(self.apply_steps_in_transaction(
+            &database_migration.rollback,
+            &database_migration.after,
+            &database_migration.before,
+        )?)
+    }
+
+    /// The `rollback` steps revert the whole migration, so the applied steps are reverted one by one instead. The
+    /// definitions they restore are taken from the schema the migration started from.
+    fn unapply_steps_best_effort(
+        &self,
+        database_migration: &SqlMigration,
+        applied_steps: usize,
+    ) -> Vec<ConnectorError> {
+        let before = with_renderable_raw_types(&database_migration.before, self.sql_family);
+        let mut errors = Vec::new();
+
+        for step in database_migration.corrected_steps.iter().take(applied_steps).rev() {
+            let reverted_steps = match reverted_steps(step, &before) {
+                Some(reverted_steps) => reverted_steps,
+                None => {
+                    let message = format!("The migration step {:?} can't be reverted.", step);
+                    errors.push(ConnectorError::from(SqlError::Generic(message)));
+                    continue;
+                }
+            };
+
+            for reverted_step in &reverted_steps {
+                let result = self.apply_step(self.conn.as_ref(), reverted_step, &database_migration.after, &before);
+
+                if let Err(err) = result {
+                    errors.push(ConnectorError::from(err));
+                }
+            }
+        }
+
+        errors
     }
 
     fn render_steps_pretty(&self, database_migration: &SqlMigration) -> ConnectorResult<Vec<serde_json::Value>> {
@@ -27,27 +107,301 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
                 .collect(),
         )
     }
+
+    fn render_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        Ok(render_script(
+            &database_migration.corrected_steps,
+            self.sql_family,
+            &self.schema_name,
+            &database_migration.before,
+            &database_migration.after,
+        ))
+    }
+
+    fn render_rollback_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        Ok(render_script(
+            &database_migration.rollback,
+            self.sql_family,
+            &self.schema_name,
+            &database_migration.after,
+            &database_migration.before,
+        ))
+    }
 }
 
 impl SqlDatabaseStepApplier {
-    fn apply_next_step(&self, steps: &Vec<SqlMigrationStep>, index: usize) -> SqlResult<bool> {
+    fn apply_next_step(
+        &self,
+        steps: &Vec<SqlMigrationStep>,
+        index: usize,
+        current_schema: &SqlSchema,
+        next_schema: &SqlSchema,
+    ) -> SqlResult<bool> {
         let has_this_one = steps.get(index).is_some();
         if !has_this_one {
             return Ok(false);
         }
 
-        let step = &steps[index];
-        let sql_string = render_raw_sql(&step, self.sql_family, &self.schema_name);
-        debug!("{}", sql_string);
-
-        let result = self.conn.query_raw(&sql_string, &[]);
-
-        // TODO: this does not evaluate the results of SQLites PRAGMA foreign_key_check
-        result?;
+        self.apply_step(self.conn.as_ref(), &steps[index], current_schema, next_schema)?;
 
         let has_more = steps.get(index + 1).is_some();
         Ok(has_more)
     }
+
+    /// Applies the steps in a transaction on a single connection. The transaction is rolled back when a step fails.
+    fn apply_steps_in_transaction(
+        &self,
+        steps: &[SqlMigrationStep],
+        current_schema: &SqlSchema,
+        next_schema: &SqlSchema,
+    ) -> SqlResult<usize> {
+        let conn = self.conn.single_connection()?;
+
+        // SQLite ignores changes to foreign key enforcement inside a transaction, so the table rebuilds need it
+        // turned off around the transaction.
+        let toggles_foreign_keys = self.sql_family == SqlFamily::Sqlite && steps.iter().any(is_foreign_keys_toggle);
+
+        if toggles_foreign_keys {
+            conn.query_raw(SQLITE_DISABLE_FOREIGN_KEYS, &[])?;
+        }
+
+        conn.query_raw("BEGIN", &[])?;
+
+        let result = steps
+            .iter()
+            .map(|step| self.apply_step(conn.as_ref(), step, current_schema, next_schema))
+            .collect::<SqlResult<Vec<()>>>()
+            .and_then(|_| Ok(conn.query_raw("COMMIT", &[])?));
+
+        if result.is_err() {
+            // The error of the failed step is the one worth reporting.
+            let _ = conn.query_raw("ROLLBACK", &[]);
+        }
+
+        if toggles_foreign_keys {
+            conn.query_raw(SQLITE_ENABLE_FOREIGN_KEYS, &[])?;
+        }
+
+        result.map(|_| steps.len())
+    }
+
+    fn apply_step(
+        &self,
+        conn: &dyn SyncSqlConnection,
+        step: &SqlMigrationStep,
+        current_schema: &SqlSchema,
+        next_schema: &SqlSchema,
+    ) -> SqlResult<()> {
+        if let SqlMigrationStep::RenameColumn(_) = step {
+            self.check_sqlite_can_rename_columns(conn)?;
+        }
+
+        let statements = render_raw_sql(&step, self.sql_family, &self.schema_name, current_schema, next_schema);
+
+        for sql_string in statements {
+            debug!("{}", sql_string);
+
+            let result_set = conn.query_raw(&sql_string, &[])?;
+
+            // The rebuilt tables are not checked while foreign keys are disabled, so the rows left dangling only
+            // show up here. Failing the step rolls back the transaction.
+            if self.is_foreign_key_check(step) {
+                let violations = foreign_key_violations(result_set);
+
+                if !violations.is_empty() {
+                    return Err(SqlError::ForeignKeyViolations(violations));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// SQLite supports `RENAME COLUMN` since 3.25.0. Older versions fail with a syntax error, this explains why.
+    fn check_sqlite_can_rename_columns(&self, conn: &dyn SyncSqlConnection) -> SqlResult<()> {
+        if self.sql_family != SqlFamily::Sqlite {
+            return Ok(());
+        }
+
+        let result_set = conn.query_raw("SELECT sqlite_version() AS version", &[])?;
+        let version = result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row["version"].to_string())
+            .unwrap_or_default();
+        let version_parts: Vec<u32> = version.split('.').filter_map(|part| part.parse().ok()).collect();
+
+        if version_parts.as_slice() < &[3, 25][..] {
+            return Err(SqlError::Generic(format!(
+                "Renaming a column requires SQLite 3.25.0 or later, the database runs SQLite {}.",
+                version
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn is_foreign_key_check(&self, step: &SqlMigrationStep) -> bool {
+        match step {
+            SqlMigrationStep::RawSql { raw } if self.sql_family == SqlFamily::Sqlite => {
+                raw == &sqlite_foreign_key_check(&self.schema_name)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Turns the rows returned by SQLite's `PRAGMA foreign_key_check` into migration errors.
+fn foreign_key_violations(result_set: ResultSet) -> Vec<MigrationError> {
+    result_set
+        .into_iter()
+        .map(|row| {
+            let table = row["table"].to_string().unwrap_or_default();
+            let parent = row["parent"].to_string().unwrap_or_default();
+            let description = match row["rowid"].as_i64() {
+                Some(rowid) => format!(
+                    "The row with rowid {} in table `{}` references a row in table `{}` that does not exist.",
+                    rowid, table, parent
+                ),
+                None => format!(
+                    "A row in table `{}` references a row in table `{}` that does not exist.",
+                    table, parent
+                ),
+            };
+
+            MigrationError {
+                tpe: "ForeignKeyViolation".to_string(),
+                description,
+                field: None,
+            }
+        })
+        .collect()
+}
+
+/// The steps undoing a step of a migration that started from the `before` schema. Returns `None` when the step
+/// can't be reverted, like raw SQL or the changes to tables, columns and indexes `before` doesn't know.
+fn reverted_steps(step: &SqlMigrationStep, before: &SqlSchema) -> Option<Vec<SqlMigrationStep>> {
+    let create_table = |name: &String| {
+        before
+            .table(name)
+            .ok()
+            .map(|table| SqlMigrationStep::CreateTable(CreateTable { table: table.clone() }))
+    };
+
+    let reverted_step = match step {
+        SqlMigrationStep::CreateTable(CreateTable { table }) => SqlMigrationStep::DropTable(DropTable {
+            name: table.name.clone(),
+        }),
+        SqlMigrationStep::DropTable(DropTable { name }) => create_table(name)?,
+        SqlMigrationStep::DropTables(DropTables { names }) => return names.iter().map(create_table).collect(),
+        SqlMigrationStep::RenameTable { name, new_name } => SqlMigrationStep::RenameTable {
+            name: new_name.clone(),
+            new_name: name.clone(),
+        },
+        SqlMigrationStep::RenameColumn(RenameColumn { table, name, column }) => {
+            let previous_column = before.table(table).ok()?.column(&column.name)?;
+
+            SqlMigrationStep::RenameColumn(RenameColumn {
+                table: table.clone(),
+                name: column.name.clone(),
+                column: Column {
+                    name: name.clone(),
+                    ..previous_column.clone()
+                },
+            })
+        }
+        SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+            let previous_table = before.table(&table.name).ok()?;
+            let mut reverted_changes = Vec::new();
+
+            for change in changes.iter().rev() {
+                let reverted_change = match change {
+                    TableChange::AddColumn(AddColumn { column, .. }) => TableChange::DropColumn(DropColumn {
+                        name: column.name.clone(),
+                    }),
+                    TableChange::DropColumn(DropColumn { name }) => TableChange::AddColumn(AddColumn {
+                        column: previous_table.column(name)?.clone(),
+                        backfill: None,
+                    }),
+                    TableChange::AlterColumn(AlterColumn { name, .. }) => TableChange::AlterColumn(AlterColumn {
+                        name: name.clone(),
+                        column: previous_table.column(name)?.clone(),
+                    }),
+                    // The foreign keys come back with the columns they are on.
+                    TableChange::DropForeignKey(_) => continue,
+                };
+
+                reverted_changes.push(reverted_change);
+            }
+
+            SqlMigrationStep::AlterTable(AlterTable {
+                table: previous_table.clone(),
+                changes: reverted_changes,
+            })
+        }
+        SqlMigrationStep::RawSql { .. } => return None,
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => SqlMigrationStep::DropIndex(DropIndex {
+            table: table.clone(),
+            name: index.name.clone(),
+        }),
+        SqlMigrationStep::DropIndex(DropIndex { table, name }) => {
+            let index = before
+                .table(table)
+                .ok()?
+                .indices
+                .iter()
+                .find(|index| index.name == *name)?;
+
+            SqlMigrationStep::CreateIndex(CreateIndex {
+                table: table.clone(),
+                index: index.clone(),
+            })
+        }
+        SqlMigrationStep::AlterIndex(AlterIndex {
+            table,
+            index_name,
+            index_new_name,
+        }) => SqlMigrationStep::AlterIndex(AlterIndex {
+            table: table.clone(),
+            index_name: index_new_name.clone(),
+            index_new_name: index_name.clone(),
+        }),
+        SqlMigrationStep::CreateEnum(CreateEnum { name, .. }) => {
+            SqlMigrationStep::DropEnum(DropEnum { name: name.clone() })
+        }
+        SqlMigrationStep::AlterEnum(AlterEnum {
+            name,
+            created_variants,
+            dropped_variants,
+        }) => SqlMigrationStep::AlterEnum(AlterEnum {
+            name: name.clone(),
+            created_variants: dropped_variants.clone(),
+            dropped_variants: created_variants.clone(),
+        }),
+        SqlMigrationStep::DropEnum(DropEnum { name }) => SqlMigrationStep::CreateEnum(CreateEnum {
+            name: name.clone(),
+            values: before.get_enum(name)?.values.clone(),
+        }),
+    };
+
+    Some(vec![reverted_step])
+}
+
+/// Postgres can add values to an enum in place, which can't be combined with other changes in a transaction.
+fn adds_enum_values(steps: &[SqlMigrationStep]) -> bool {
+    steps.iter().any(|step| match step {
+        SqlMigrationStep::AlterEnum(alter_enum) => {
+            !alter_enum.created_variants.is_empty() && alter_enum.dropped_variants.is_empty()
+        }
+        _ => false,
+    })
+}
+
+fn is_foreign_keys_toggle(step: &SqlMigrationStep) -> bool {
+    match step {
+        SqlMigrationStep::RawSql { raw } => raw == SQLITE_DISABLE_FOREIGN_KEYS || raw == SQLITE_ENABLE_FOREIGN_KEYS,
+        _ => false,
+    }
 }
 
 fn render_steps_pretty(
@@ -60,23 +414,64 @@ fn render_steps_pretty(
         .iter()
         .map(|step| PrettySqlMigrationStep {
             step: step.clone(),
-            raw: render_raw_sql(&step, sql_family, schema_name),
+            raw: render_raw_sql(
+                &step,
+                sql_family,
+                schema_name,
+                &database_migration.before,
+                &database_migration.after,
+            )
+            .join("\n"),
         })
         .collect();
     Ok(steps)
 }
 
-fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &str) -> String {
+/// Renders the steps into a single script, with every statement terminated by exactly one semicolon.
+fn render_script(
+    steps: &[SqlMigrationStep],
+    sql_family: SqlFamily,
+    schema_name: &str,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> String {
+    let mut script = String::new();
+
+    for step in steps {
+        for statement in render_raw_sql(step, sql_family, schema_name, current_schema, next_schema) {
+            let statement = statement.trim().trim_end_matches(';');
+
+            if statement.is_empty() {
+                continue;
+            }
+
+            script.push_str(statement);
+            script.push_str(";\n\n");
+        }
+    }
+
+    script
+}
+
+/// Renders a step into the SQL statements executing it. Most steps are a single statement, but some
+/// need none or several, depending on the database.
+fn render_raw_sql(
+    step: &SqlMigrationStep,
+    sql_family: SqlFamily,
+    schema_name: &str,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> Vec<String> {
     let schema_name = schema_name.to_string();
     let renderer = SqlRenderer::for_family(&sql_family);
 
-    match step {
+    let sql = match step {
         SqlMigrationStep::CreateTable(CreateTable { table }) => {
             let cloned_columns = table.columns.clone();
             let primary_columns = table.primary_key_columns();
             let mut lines = Vec::new();
             for column in cloned_columns.clone() {
-                let col_sql = renderer.render_column(&schema_name, &table, &column, false);
+                let col_sql = renderer.render_column(&schema_name, &table, &column, false, next_schema);
                 lines.push(format!("  {}", col_sql));
             }
             let primary_key_was_already_set_in_column_line = lines.join(",").contains(&"PRIMARY KEY");
@@ -89,6 +484,9 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                     .collect();
                 lines.push(format!("  PRIMARY KEY ({})", column_names.join(",")))
             }
+            for check_constraint in &table.check_constraints {
+                lines.push(format!("  {}", render_check_constraint(renderer, check_constraint)));
+            }
             format!(
                 "CREATE TABLE {} (\n{}\n){};",
                 renderer.quote_with_schema(&schema_name, &table.name),
@@ -117,13 +515,75 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                 new_name
             )
         }
+        SqlMigrationStep::RenameColumn(RenameColumn { table, name, column }) => match sql_family {
+            // MySQL 5.7 only renames a column along with its definition.
+            SqlFamily::Mysql => {
+                let tpe_str = renderer.render_column_type(&schema_name, &column.tpe, next_schema);
+                let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+                format!(
+                    "ALTER TABLE {} CHANGE {} {} {} {} {} {} {};",
+                    renderer.quote_with_schema(&schema_name, &table),
+                    renderer.quote(&name),
+                    renderer.quote(&column.name),
+                    tpe_str,
+                    render_nullability(&column),
+                    render_default(&column),
+                    auto_increment_str,
+                    render_comment(current_comment(current_schema, table, name))
+                )
+            }
+            // SQLite only supports this since 3.25.0, which is checked before the step is applied.
+            SqlFamily::Postgres | SqlFamily::Sqlite => format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                renderer.quote_with_schema(&schema_name, &table),
+                renderer.quote(&name),
+                renderer.quote(&column.name)
+            ),
+        },
         SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+            let table_name = renderer.quote_with_schema(&schema_name, &table.name);
             let mut lines = Vec::new();
+            // The statements filling the added required columns, once they exist.
+            let mut backfills = Vec::new();
             for change in changes.clone() {
                 match change {
-                    TableChange::AddColumn(AddColumn { column }) => {
-                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
-                        lines.push(format!("ADD COLUMN {}", col_sql));
+                    TableChange::AddColumn(AddColumn { column, backfill }) => {
+                        let backfill = match sql_family {
+                            // SQLite rebuilds the table to add a required column, and fills it while copying the rows.
+                            SqlFamily::Postgres | SqlFamily::Mysql if needs_backfill(&column) => {
+                                backfill.or_else(|| backfill_value(&column, next_schema))
+                            }
+                            _ => None,
+                        };
+
+                        match backfill {
+                            // The column is added as nullable, filled, and then made required.
+                            Some(backfill) => {
+                                let nullable_column = Column {
+                                    arity: ColumnArity::Nullable,
+                                    ..column.clone()
+                                };
+                                let col_sql =
+                                    renderer.render_column(&schema_name, &table, &nullable_column, true, next_schema);
+                                lines.push(format!("ADD COLUMN {}", col_sql));
+
+                                let name = renderer.quote(&column.name);
+                                backfills.push(format!("UPDATE {} SET {} = {};", table_name, name, backfill));
+                                backfills.push(match sql_family {
+                                    SqlFamily::Mysql => format!(
+                                        "ALTER TABLE {} MODIFY {} {} NOT NULL;",
+                                        table_name,
+                                        name,
+                                        renderer.render_column_type(&schema_name, &column.tpe, next_schema)
+                                    ),
+                                    _ => format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL;", table_name, name),
+                                });
+                            }
+                            None => {
+                                let col_sql = renderer.render_column(&schema_name, &table, &column, true, next_schema);
+                                lines.push(format!("ADD COLUMN {}", col_sql));
+                            }
+                        }
                     }
                     TableChange::DropColumn(DropColumn { name }) => {
                         // TODO: this does not work on MySQL for columns with foreign keys. Here the FK must be dropped first by name.
@@ -131,10 +591,54 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                         lines.push(format!("DROP COLUMN {}", name));
                     }
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
-                        let name = renderer.quote(&name);
-                        lines.push(format!("DROP COLUMN {}", name));
-                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
-                        lines.push(format!("ADD COLUMN {}", col_sql));
+                        let current_table = current_schema.table(&table.name).ok();
+                        let type_change_only = current_table
+                            .map(|current_table| is_type_change_only(sql_family, current_table, &table, &name))
+                            .unwrap_or(false);
+                        let default_change_only = current_table
+                            .map(|current_table| is_default_change_only(sql_family, current_table, &table, &name))
+                            .unwrap_or(false);
+                        let default_changed = current_table
+                            .and_then(|current_table| current_table.column(&name))
+                            .map(|current_column| column_defaults_differ(sql_family, current_column, &column))
+                            .unwrap_or(false);
+
+                        match sql_family {
+                            SqlFamily::Postgres | SqlFamily::Mysql if default_change_only => {
+                                lines.push(render_alter_column_default(renderer, &name, &column));
+                            }
+                            SqlFamily::Postgres if type_change_only => {
+                                let tpe_str = renderer.render_column_type(&schema_name, &column.tpe, next_schema);
+                                let quoted_name = renderer.quote(&name);
+                                lines.push(format!(
+                                    "ALTER COLUMN {} TYPE {} USING {}::{}",
+                                    quoted_name, tpe_str, quoted_name, tpe_str
+                                ));
+
+                                if default_changed {
+                                    lines.push(render_alter_column_default(renderer, &name, &column));
+                                }
+                            }
+                            SqlFamily::Mysql if type_change_only => {
+                                let tpe_str = renderer.render_column_type(&schema_name, &column.tpe, next_schema);
+                                let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+                                lines.push(format!(
+                                    "MODIFY {} {} {} {} {} {}",
+                                    renderer.quote(&name),
+                                    tpe_str,
+                                    render_nullability(&column),
+                                    render_default(&column),
+                                    auto_increment_str,
+                                    render_comment(current_comment(current_schema, &table.name, &name))
+                                ));
+                            }
+                            _ => {
+                                let name = renderer.quote(&name);
+                                lines.push(format!("DROP COLUMN {}", name));
+                                let col_sql = renderer.render_column(&schema_name, &table, &column, true, next_schema);
+                                lines.push(format!("ADD COLUMN {}", col_sql));
+                            }
+                        }
                     }
                     TableChange::DropForeignKey(DropForeignKey { constraint_name }) => match sql_family {
                         SqlFamily::Mysql => {
@@ -145,11 +649,9 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
                     },
                 }
             }
-            format!(
-                "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
-                lines.join(",\n")
-            )
+            let alter_table = format!("ALTER TABLE {} {};", table_name, lines.join(",\n"));
+
+            return std::iter::once(alter_table).chain(backfills).collect();
         }
         SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
             let Index { name, columns, tpe } = index;
@@ -203,9 +705,157 @@ fn render_raw_sql(step: &SqlMigrationStep, sql_family: SqlFamily, schema_name: &
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
         SqlMigrationStep::RawSql { raw } => raw.to_string(),
+        SqlMigrationStep::CreateEnum(CreateEnum { name, values }) => match sql_family {
+            SqlFamily::Postgres => format!(
+                "CREATE TYPE {} AS ENUM ({})",
+                renderer.quote_with_schema(&schema_name, &name),
+                render_enum_values(&values)
+            ),
+            // MySQL enums are created inline with their column.
+            SqlFamily::Mysql | SqlFamily::Sqlite => return Vec::new(),
+        },
+        SqlMigrationStep::DropEnum(DropEnum { name }) => match sql_family {
+            SqlFamily::Postgres => format!("DROP TYPE {}", renderer.quote_with_schema(&schema_name, &name)),
+            SqlFamily::Mysql | SqlFamily::Sqlite => return Vec::new(),
+        },
+        SqlMigrationStep::AlterEnum(alter_enum) => {
+            return render_alter_enum(
+                alter_enum,
+                renderer,
+                sql_family,
+                &schema_name,
+                current_schema,
+                next_schema,
+            )
+        }
+    };
+
+    vec![sql]
+}
+
+/// Sets or drops the default of a column in place. MySQL and Postgres share the syntax.
+fn render_alter_column_default(renderer: &dyn SqlRenderer, name: &str, column: &Column) -> String {
+    match column.default {
+        Some(_) => format!("ALTER COLUMN {} SET {}", renderer.quote(name), render_default(column)),
+        None => format!("ALTER COLUMN {} DROP DEFAULT", renderer.quote(name)),
     }
 }
 
+fn render_alter_enum(
+    alter_enum: &AlterEnum,
+    renderer: &dyn SqlRenderer,
+    sql_family: SqlFamily,
+    schema_name: &str,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> Vec<String> {
+    match sql_family {
+        // Postgres can add values to an enum, but not remove them.
+        SqlFamily::Postgres if alter_enum.dropped_variants.is_empty() => alter_enum
+            .created_variants
+            .iter()
+            .map(|value| {
+                format!(
+                    "ALTER TYPE {} ADD VALUE {}",
+                    renderer.quote_with_schema(schema_name, &alter_enum.name),
+                    render_enum_values(&[value.clone()])
+                )
+            })
+            .collect(),
+        // To remove values, the enum gets recreated and the existing columns are converted to the new enum.
+        SqlFamily::Postgres => {
+            let next_values = next_schema
+                .get_enum(&alter_enum.name)
+                .map(|next_enum| next_enum.values.clone())
+                .unwrap_or_default();
+            let enum_name = renderer.quote_with_schema(schema_name, &alter_enum.name);
+            let old_enum_name = format!("{}_old", alter_enum.name);
+
+            let mut statements = vec![
+                format!("ALTER TYPE {} RENAME TO {}", enum_name, renderer.quote(&old_enum_name)),
+                format!(
+                    "CREATE TYPE {} AS ENUM ({})",
+                    enum_name,
+                    render_enum_values(&next_values)
+                ),
+            ];
+
+            let existing_columns = current_schema
+                .enum_columns(&alter_enum.name)
+                .filter(|(table, _)| next_schema.has_table(&table.name));
+
+            for (table, column) in existing_columns {
+                let column_name = renderer.quote(&column.name);
+                let (column_type, text_type) = match column.arity {
+                    ColumnArity::List => (format!("{}[]", enum_name), "text[]"),
+                    _ => (enum_name.clone(), "text"),
+                };
+
+                let mut changes = vec![
+                    format!("ALTER COLUMN {} DROP DEFAULT", column_name),
+                    format!(
+                        "ALTER COLUMN {column} TYPE {tpe} USING ({column}::{text_type}::{tpe})",
+                        column = column_name,
+                        tpe = column_type,
+                        text_type = text_type
+                    ),
+                ];
+
+                if let Some(default) = column.default.as_ref().filter(|default| next_values.contains(default)) {
+                    changes.push(format!(
+                        "ALTER COLUMN {} SET DEFAULT {}",
+                        column_name,
+                        render_enum_values(&[default.clone()])
+                    ));
+                }
+
+                statements.push(format!(
+                    "ALTER TABLE {} {}",
+                    renderer.quote_with_schema(schema_name, &table.name),
+                    changes.join(", ")
+                ));
+            }
+
+            statements.push(format!(
+                "DROP TYPE {}",
+                renderer.quote_with_schema(schema_name, &old_enum_name)
+            ));
+            statements
+        }
+        // MySQL enums are defined inline, so the columns of existing tables are redefined.
+        SqlFamily::Mysql => next_schema
+            .enum_columns(&alter_enum.name)
+            .filter(|(table, column)| {
+                current_schema
+                    .get_table(&table.name)
+                    .map(|current_table| current_table.has_column(&column.name))
+                    .unwrap_or(false)
+            })
+            .map(|(table, column)| {
+                let column = Column {
+                    comment: current_comment(current_schema, &table.name, &column.name).cloned(),
+                    ..column.clone()
+                };
+
+                format!(
+                    "ALTER TABLE {} MODIFY {}",
+                    renderer.quote_with_schema(schema_name, &table.name),
+                    renderer.render_column(schema_name, table, &column, false, next_schema)
+                )
+            })
+            .collect(),
+        SqlFamily::Sqlite => Vec::new(),
+    }
+}
+
+/// The comment of a column as it currently exists, to keep it when the column gets redefined.
+fn current_comment<'a>(current_schema: &'a SqlSchema, table: &str, column: &str) -> Option<&'a String> {
+    current_schema
+        .get_table(table)
+        .and_then(|table| table.column(column))
+        .and_then(|column| column.comment.as_ref())
+}
+
 fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
     match sql_family {
         SqlFamily::Sqlite => "",
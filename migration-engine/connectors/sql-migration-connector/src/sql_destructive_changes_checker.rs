@@ -1,27 +1,46 @@
 use crate::{
-    AlterColumn, DropColumn, DropTable, DropTables, SqlError, SqlMigration, SqlMigrationStep, SqlResult, TableChange,
+    sql_column_defaults::{backfill_value, needs_backfill},
+    sql_column_types::{is_narrowing_type_change, normalized_raw_type},
+    sql_renderer::{render_enum_values, SqlRenderer},
+    sql_schema_differ::{is_default_change_only, is_type_change_only},
+    AddColumn, AlterColumn, AlterEnum, DropColumn, DropTable, DropTables, SqlError, SqlFamily, SqlMigration,
+    SqlMigrationStep, SqlResult, TableChange,
 };
 use migration_connector::*;
 use quaint::ast::*;
 use sql_connection::SyncSqlConnection;
+use sql_schema_describer::{ColumnArity, SqlSchema};
 use std::sync::Arc;
 
 pub struct SqlDestructiveChangesChecker {
+    pub sql_family: SqlFamily,
     pub schema_name: String,
     pub database: Arc<dyn SyncSqlConnection + Send + Sync>,
 }
 
 impl SqlDestructiveChangesChecker {
-    fn check_table_drop(&self, table_name: &str, diagnostics: &mut DestructiveChangeDiagnostics) -> SqlResult<()> {
+    fn count_rows_in_table(&self, table_name: &str) -> SqlResult<i64> {
         let query = Select::from_table((self.schema_name.as_str(), table_name)).value(count(asterisk()));
         let result_set = self.database.query(query.into())?;
         let first_row = result_set.first().ok_or_else(|| {
-            SqlError::Generic("No row was returned when checking for existing rows in dropped table.".to_owned())
+            SqlError::Generic(format!(
+                "No row was returned when checking for existing rows in table {}.",
+                table_name
+            ))
         })?;
         let rows_count: i64 = first_row.at(0).and_then(|value| value.as_i64()).ok_or_else(|| {
-            SqlError::Generic("No count was returned when checking for existing rows in dropped table.".to_owned())
+            SqlError::Generic(format!(
+                "No count was returned when checking for existing rows in table {}.",
+                table_name
+            ))
         })?;
 
+        Ok(rows_count)
+    }
+
+    fn check_table_drop(&self, table_name: &str, diagnostics: &mut DestructiveChangeDiagnostics) -> SqlResult<()> {
+        let rows_count = self.count_rows_in_table(table_name)?;
+
         if rows_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
@@ -81,15 +100,59 @@ impl SqlDestructiveChangesChecker {
         Ok(())
     }
 
-    /// Emit a warning when we alter a column that contains non-null values. We will implement
-    /// non-destructive alter column for a subset of changes in the future, but at the moment all
-    /// alter columns are destructive.
+    /// Emit a warning when we add a required column without default to a table with rows, and no expression was
+    /// provided to fill it. The rows are then backfilled with the zero value of the column type, if it has one.
+    fn check_add_column(
+        &self,
+        add_column: &AddColumn,
+        table: &sql_schema_describer::Table,
+        next_schema: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        if !needs_backfill(&add_column.column) || add_column.backfill.is_some() {
+            return Ok(());
+        }
+
+        let rows_count = self.count_rows_in_table(&table.name)?;
+
+        if rows_count > 0 {
+            let backfill = match backfill_value(&add_column.column, next_schema) {
+                Some(value) => format!("The existing rows will be backfilled with `{}`.", value),
+                None => "The existing rows can't be backfilled.".to_owned(),
+            };
+
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "You are about to add the required column `{column_name}` without a default value to the `{table_name}` table, which is not empty ({rows_count} rows). {backfill} Add a default value or provide a backfill expression to fill them.",
+                    column_name = add_column.column.name,
+                    table_name = table.name,
+                    rows_count = rows_count,
+                    backfill = backfill,
+                ),
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Emit a warning when we alter a column that contains non-null values. Changes of the type alone
+    /// are applied in place and only checked for narrowing, but all other alter columns are destructive.
     fn check_alter_column(
         &self,
         alter_column: &AlterColumn,
         table: &sql_schema_describer::Table,
+        next_table: &sql_schema_describer::Table,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
+        if is_type_change_only(self.sql_family, table, next_table, &alter_column.name) {
+            return self.check_column_type_change(alter_column, table, diagnostics);
+        }
+
+        // Changing the default leaves the existing values alone.
+        if is_default_change_only(self.sql_family, table, next_table, &alter_column.name) {
+            return Ok(());
+        }
+
         let values_count = self.count_values_in_column(&alter_column.name, table)?;
 
         if values_count > 0 {
@@ -105,6 +168,100 @@ impl SqlDestructiveChangesChecker {
 
         Ok(())
     }
+
+    /// Emit a warning when we narrow the type of a column that contains non-null values, e.g. from
+    /// `BIGINT` to `INT`, since some of the values may not fit the new type.
+    fn check_column_type_change(
+        &self,
+        alter_column: &AlterColumn,
+        table: &sql_schema_describer::Table,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let previous_type = &table.column_bang(&alter_column.name).tpe;
+        let next_type = &alter_column.column.tpe;
+
+        if !is_narrowing_type_change(self.sql_family, previous_type, next_type) {
+            return Ok(());
+        }
+
+        let values_count = self.count_values_in_column(&alter_column.name, table)?;
+
+        if values_count > 0 {
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "You are about to change the type of the column `{column_name}` on the `{table_name}` table from `{previous_type}` to `{next_type}`. {values_count} non-null values in that column may not fit the new type.",
+                    column_name = alter_column.name,
+                    table_name = &table.name,
+                    previous_type = normalized_raw_type(self.sql_family, previous_type).unwrap_or_default(),
+                    next_type = normalized_raw_type(self.sql_family, next_type).unwrap_or_default(),
+                    values_count = values_count,
+                ),
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Emit a warning for every column that still contains values of the variants removed from an enum.
+    fn check_alter_enum(
+        &self,
+        alter_enum: &AlterEnum,
+        before: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        if alter_enum.dropped_variants.is_empty() {
+            return Ok(());
+        }
+
+        let renderer = SqlRenderer::for_family(&self.sql_family);
+        let columns = before
+            .enum_columns(&alter_enum.name)
+            .filter(|(_, column)| column.arity != ColumnArity::List);
+
+        for (table, column) in columns {
+            // The values are compared as text, Postgres does not compare enums to text parameters.
+            let column_value = match self.sql_family {
+                SqlFamily::Postgres => format!("{}::text", renderer.quote(&column.name)),
+                _ => renderer.quote(&column.name),
+            };
+            let query = format!(
+                "SELECT COUNT(*) FROM {} WHERE {} IN ({})",
+                renderer.quote_with_schema(&self.schema_name, &table.name),
+                column_value,
+                render_enum_values(&alter_enum.dropped_variants)
+            );
+
+            let values_count: i64 =
+                self.database
+                    .query_raw(&query, &[])
+                    .map_err(SqlError::from)
+                    .and_then(|result_set| {
+                        result_set
+                            .first()
+                            .as_ref()
+                            .and_then(|row| row.at(0))
+                            .and_then(|count| count.as_i64())
+                            .ok_or_else(|| {
+                                SqlError::Generic("Unexpected result set shape when checking enums.".to_owned())
+                            })
+                    })?;
+
+            if values_count > 0 {
+                diagnostics.add_warning(MigrationWarning {
+                    description: format!(
+                        "You are about to remove the variants {variants:?} from the enum `{enum_name}`, which are still used by {values_count} rows in the column `{column_name}` on the `{table_name}` table.",
+                        variants = alter_enum.dropped_variants,
+                        enum_name = alter_enum.name,
+                        values_count = values_count,
+                        column_name = column.name,
+                        table_name = table.name,
+                    ),
+                })
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
@@ -130,12 +287,21 @@ impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
                         .changes
                         .iter()
                         .map(|change| match *change {
+                            TableChange::AddColumn(ref add_column) => self.check_add_column(
+                                add_column,
+                                before_table,
+                                &database_migration.after,
+                                &mut diagnostics,
+                            ),
                             TableChange::DropColumn(ref drop_column) => {
                                 self.check_column_drop(drop_column, before_table, &mut diagnostics)
                             }
-                            TableChange::AlterColumn(ref alter_column) => {
-                                self.check_alter_column(alter_column, before_table, &mut diagnostics)
-                            }
+                            TableChange::AlterColumn(ref alter_column) => self.check_alter_column(
+                                alter_column,
+                                before_table,
+                                &alter_table.table,
+                                &mut diagnostics,
+                            ),
                             _ => Ok(()),
                         })
                         .collect::<Result<(), SqlError>>()?;
@@ -150,6 +316,9 @@ impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker {
                         self.check_table_drop(name, &mut diagnostics)?;
                     }
                 }
+                SqlMigrationStep::AlterEnum(alter_enum) => {
+                    self.check_alter_enum(alter_enum, &database_migration.before, &mut diagnostics)?;
+                }
                 // do nothing
                 _ => (),
             }
@@ -0,0 +1,200 @@
+use crate::sql_column_defaults::{column_defaults_differ, without_legacy_defaults};
+use crate::sql_column_types::column_types_differ;
+use crate::sql_schema_calculator::SqlSchemaCalculator;
+use crate::sql_schema_differ::{foreign_keys_match, SqlSchemaDiffer};
+use crate::*;
+use datamodel::Datamodel;
+use migration_connector::*;
+use sql_schema_describer::*;
+use std::sync::Arc;
+
+pub struct SqlDriftDetector {
+    pub sql_family: SqlFamily,
+    pub schema_name: String,
+    pub introspector: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
+}
+
+impl DriftDetector for SqlDriftDetector {
+    fn detect_drift(&self, expected: &Datamodel) -> ConnectorResult<Vec<Drift>> {
+        Ok(self.detect_drift_internal(expected)?)
+    }
+}
+
+impl SqlDriftDetector {
+    fn detect_drift_internal(&self, expected: &Datamodel) -> SqlResult<Vec<Drift>> {
+        let expected_schema = SqlSchemaCalculator::calculate(expected, self.sql_family)?;
+        let actual_schema = without_legacy_defaults(
+            self.sql_family,
+            &self.introspector.describe(&self.schema_name)?,
+            &expected_schema,
+        );
+
+        Ok(detect_drift(&expected_schema, &actual_schema, self.sql_family))
+    }
+}
+
+/// Diff the expected schema against the actual one. Everything the differ would create is extra in the database,
+/// everything it would drop is missing.
+fn detect_drift(expected: &SqlSchema, actual: &SqlSchema, sql_family: SqlFamily) -> Vec<Drift> {
+    let diff = SqlSchemaDiffer::diff(expected, actual, sql_family);
+    let mut drifts = Vec::new();
+
+    for drop_table in diff.drop_tables {
+        drifts.push(Drift {
+            kind: DriftKind::MissingTable,
+            description: format!("The table `{}` is missing from the database.", drop_table.name),
+            table: drop_table.name,
+            name: None,
+        });
+    }
+
+    for create_table in diff.create_tables {
+        drifts.push(Drift {
+            kind: DriftKind::ExtraTable,
+            description: format!(
+                "The table `{}` exists in the database, but not in the migration history.",
+                create_table.table.name
+            ),
+            table: create_table.table.name,
+            name: None,
+        });
+    }
+
+    for alter_table in diff.alter_tables {
+        let table_name = &alter_table.table.name;
+
+        for change in &alter_table.changes {
+            let (kind, column_name, description) = match change {
+                TableChange::DropColumn(drop_column) => (
+                    DriftKind::MissingColumn,
+                    drop_column.name.clone(),
+                    "is missing from the database",
+                ),
+                TableChange::AddColumn(add_column) => (
+                    DriftKind::ExtraColumn,
+                    add_column.column.name.clone(),
+                    "exists in the database, but not in the migration history",
+                ),
+                // Foreign key changes also show up as altered columns. They are reported separately below.
+                TableChange::AlterColumn(alter_column)
+                    if column_changed(expected, table_name, alter_column, sql_family) =>
+                {
+                    (
+                        DriftKind::ChangedColumn,
+                        alter_column.name.clone(),
+                        "differs from the migration history",
+                    )
+                }
+                TableChange::AlterColumn(_) | TableChange::DropForeignKey(_) => continue,
+            };
+
+            drifts.push(Drift {
+                kind,
+                description: format!(
+                    "The column `{}` on the table `{}` {}.",
+                    column_name, table_name, description
+                ),
+                table: table_name.clone(),
+                name: Some(column_name),
+            });
+        }
+    }
+
+    for drop_index in diff.drop_indexes {
+        drifts.push(Drift {
+            kind: DriftKind::MissingIndex,
+            description: format!(
+                "The index `{}` on the table `{}` is missing from the database.",
+                drop_index.name, drop_index.table
+            ),
+            table: drop_index.table,
+            name: Some(drop_index.name),
+        });
+    }
+
+    for create_index in diff.create_indexes {
+        drifts.push(Drift {
+            kind: DriftKind::ExtraIndex,
+            description: format!(
+                "The index `{}` on the table `{}` exists in the database, but not in the migration history.",
+                create_index.index.name, create_index.table
+            ),
+            table: create_index.table,
+            name: Some(create_index.index.name),
+        });
+    }
+
+    for alter_index in diff.alter_indexes {
+        drifts.push(Drift {
+            kind: DriftKind::RenamedIndex,
+            description: format!(
+                "The index `{}` on the table `{}` is named `{}` in the database.",
+                alter_index.index_name, alter_index.table, alter_index.index_new_name
+            ),
+            table: alter_index.table,
+            name: Some(alter_index.index_name),
+        });
+    }
+
+    for expected_table in &expected.tables {
+        if let Ok(actual_table) = actual.table(&expected_table.name) {
+            drifts.extend(foreign_key_drifts(expected_table, actual_table));
+        }
+    }
+
+    drifts
+}
+
+fn column_changed(expected: &SqlSchema, table_name: &str, alter_column: &AlterColumn, sql_family: SqlFamily) -> bool {
+    expected
+        .table(table_name)
+        .ok()
+        .and_then(|table| table.column(&alter_column.name))
+        .map(|column| {
+            column.differs_in_something_except_default(&alter_column.column)
+                || column_types_differ(sql_family, &column.tpe, &alter_column.column.tpe)
+                || column_defaults_differ(sql_family, column, &alter_column.column)
+        })
+        .unwrap_or(true)
+}
+
+fn foreign_key_drifts<'a>(expected: &'a Table, actual: &'a Table) -> impl Iterator<Item = Drift> + 'a {
+    let missing = expected
+        .foreign_keys
+        .iter()
+        .filter(move |expected_fk| !actual.foreign_keys.iter().any(|fk| foreign_keys_match(expected_fk, fk)))
+        .map(move |fk| Drift {
+            kind: DriftKind::MissingForeignKey,
+            table: expected.name.clone(),
+            name: Some(foreign_key_name(fk)),
+            description: format!(
+                "The foreign key on `{}` of the table `{}`, referencing `{}`, is missing from the database.",
+                fk.columns.join(", "),
+                expected.name,
+                fk.referenced_table
+            ),
+        });
+
+    let extra = actual
+        .foreign_keys
+        .iter()
+        .filter(move |actual_fk| !expected.foreign_keys.iter().any(|fk| foreign_keys_match(fk, actual_fk)))
+        .map(move |fk| Drift {
+            kind: DriftKind::ExtraForeignKey,
+            table: actual.name.clone(),
+            name: Some(foreign_key_name(fk)),
+            description: format!(
+                "The foreign key on `{}` of the table `{}`, referencing `{}`, exists in the database, but not in the migration history.",
+                fk.columns.join(", "),
+                actual.name,
+                fk.referenced_table
+            ),
+        });
+
+    missing.chain(extra)
+}
+
+/// The constraint name when there is one, the constrained columns otherwise.
+fn foreign_key_name(fk: &ForeignKey) -> String {
+    fk.constraint_name.clone().unwrap_or_else(|| fk.columns.join(", "))
+}
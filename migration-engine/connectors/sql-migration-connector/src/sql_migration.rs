@@ -39,10 +39,14 @@ pub enum SqlMigrationStep {
     DropTable(DropTable),
     DropTables(DropTables),
     RenameTable { name: String, new_name: String },
+    RenameColumn(RenameColumn),
     RawSql { raw: String },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    CreateEnum(CreateEnum),
+    AlterEnum(AlterEnum),
+    DropEnum(DropEnum),
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -88,6 +92,10 @@ pub enum TableChange {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AddColumn {
     pub column: Column,
+    /// The SQL expression filling the column in the existing rows, if the user provided one. Required columns
+    /// without default are otherwise backfilled with the zero value of their type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backfill: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -101,6 +109,14 @@ pub struct AlterColumn {
     pub column: Column,
 }
 
+/// The column is the renamed column. MySQL needs its definition to rename it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameColumn {
+    pub table: String,
+    pub name: String,
+    pub column: Column,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropForeignKey {
     pub constraint_name: String,
@@ -124,3 +140,21 @@ pub struct AlterIndex {
     pub index_name: String,
     pub index_new_name: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEnum {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlterEnum {
+    pub name: String,
+    pub created_variants: Vec<String>,
+    pub dropped_variants: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropEnum {
+    pub name: String,
+}
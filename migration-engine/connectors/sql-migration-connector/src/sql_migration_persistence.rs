@@ -37,6 +37,25 @@ impl MigrationPersistence for SqlMigrationPersistence {
         };
 
         let _ = self.connection.query_raw(&sql_str, &[]);
+
+        // Migration tables created before scripts were persisted lack the column. Errors are ignored because the
+        // column usually exists already.
+        let add_script_column = match self.sql_family {
+            SqlFamily::Sqlite => format!(
+                r#"ALTER TABLE "{}"."{}" ADD COLUMN "{}" TEXT;"#,
+                self.schema_name, TABLE_NAME, SCRIPT_COLUMN
+            ),
+            SqlFamily::Postgres => format!(
+                r#"ALTER TABLE "{}"."{}" ADD COLUMN IF NOT EXISTS "{}" TEXT;"#,
+                self.schema_name, TABLE_NAME, SCRIPT_COLUMN
+            ),
+            SqlFamily::Mysql => format!(
+                "ALTER TABLE `{}`.`{}` ADD COLUMN `{}` LONGTEXT;",
+                self.schema_name, TABLE_NAME, SCRIPT_COLUMN
+            ),
+        };
+
+        let _ = self.connection.query_raw(&add_script_column, &[]);
     }
 
     fn reset(&self) {
@@ -86,14 +105,14 @@ impl MigrationPersistence for SqlMigrationPersistence {
             .order_by(REVISION_COLUMN.descend());
 
         let result_set = self.connection.query(query.into()).unwrap();
-        parse_rows_new(result_set).into_iter().next()
+        parse_rows_new(result_set, self.sql_family).into_iter().next()
     }
 
     fn load_all(&self) -> Vec<Migration> {
         let query = Select::from_table(self.table()).order_by(REVISION_COLUMN.ascend());
 
         let result_set = self.connection.query(query.into()).unwrap();
-        parse_rows_new(result_set)
+        parse_rows_new(result_set, self.sql_family)
     }
 
     fn by_name(&self, name: &str) -> Option<Migration> {
@@ -103,7 +122,7 @@ impl MigrationPersistence for SqlMigrationPersistence {
             .order_by(REVISION_COLUMN.descend());
 
         let result_set = self.connection.query(query.into()).unwrap();
-        parse_rows_new(result_set).into_iter().next()
+        parse_rows_new(result_set, self.sql_family).into_iter().next()
     }
 
     fn create(&self, migration: Migration) -> Migration {
@@ -111,6 +130,7 @@ impl MigrationPersistence for SqlMigrationPersistence {
         let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
         let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
         let errors_json = serde_json::to_string(&migration.errors).unwrap();
+        let script_json = serde_json::to_string(&migration.script).unwrap();
         let serialized_datamodel = datamodel::render_datamodel_to_string(&migration.datamodel).unwrap();
 
         let insert = Insert::single_into(self.table())
@@ -122,6 +142,7 @@ impl MigrationPersistence for SqlMigrationPersistence {
             .value(DATAMODEL_STEPS_COLUMN, model_steps_json)
             .value(DATABASE_MIGRATION_COLUMN, database_migration_json)
             .value(ERRORS_COLUMN, errors_json)
+            .value(SCRIPT_COLUMN, script_json)
             .value(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
             .value(FINISHED_AT_COLUMN, ParameterizedValue::Null);
 
@@ -193,6 +214,7 @@ fn migration_table_setup(
     t.add_column(DATAMODEL_STEPS_COLUMN, unlimited_text_type.clone());
     t.add_column(DATABASE_MIGRATION_COLUMN, unlimited_text_type.clone());
     t.add_column(ERRORS_COLUMN, unlimited_text_type.clone());
+    t.add_column(SCRIPT_COLUMN, unlimited_text_type.clone().nullable(true));
     t.add_column(STARTED_AT_COLUMN, datetime_type.clone());
     t.add_column(FINISHED_AT_COLUMN, datetime_type.clone().nullable(true));
 }
@@ -234,7 +256,7 @@ fn timestamp_to_datetime(timestamp: i64) -> DateTime<Utc> {
     datetime
 }
 
-fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
+fn parse_rows_new(result_set: ResultSet, sql_family: SqlFamily) -> Vec<Migration> {
     result_set
         .into_iter()
         .map(|row| {
@@ -251,12 +273,18 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
 
             let datamodel_steps =
                 serde_json::from_str(&datamodel_steps_json).expect("Error parsing the migration steps");
-            let datamodel = datamodel::parse_datamodel(&datamodel_string).unwrap();
+            let datamodel =
+                datamodel::parse_datamodel_for_connector_type(&datamodel_string, sql_family.as_str()).unwrap();
 
             let database_migration_json =
                 serde_json::from_str(&database_migration_string).expect("Error parsing the database migration steps");
             let errors: Vec<String> = serde_json::from_str(&errors_json).unwrap();
 
+            let script = match &row[SCRIPT_COLUMN] {
+                ParameterizedValue::Null => MigrationScript::default(),
+                x => serde_json::from_str(&x.to_string().unwrap()).expect("Error parsing the migration script"),
+            };
+
             Migration {
                 name: row[NAME_COLUMN].to_string().unwrap(),
                 revision: row[REVISION_COLUMN].as_i64().unwrap() as usize,
@@ -267,6 +295,7 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
                 rolled_back: row[ROLLED_BACK_COLUMN].as_i64().unwrap() as usize,
                 datamodel_steps,
                 database_migration: database_migration_json,
+                script,
                 errors,
                 started_at: convert_parameterized_date_value(&row[STARTED_AT_COLUMN]),
                 finished_at,
@@ -285,5 +314,6 @@ static ROLLED_BACK_COLUMN: &str = "rolled_back";
 static DATAMODEL_STEPS_COLUMN: &str = "datamodel_steps";
 static DATABASE_MIGRATION_COLUMN: &str = "database_migration";
 static ERRORS_COLUMN: &str = "errors";
+static SCRIPT_COLUMN: &str = "script";
 static STARTED_AT_COLUMN: &str = "started_at";
 static FINISHED_AT_COLUMN: &str = "finished_at";
@@ -1,3 +1,5 @@
+use super::SqlRenderer;
+use crate::sql_column_defaults::is_expression;
 use sql_schema_describer::*;
 
 pub fn render_nullability(column: &Column) -> &'static str {
@@ -11,8 +13,13 @@ pub fn render_nullability(column: &Column) -> &'static str {
 pub fn render_default(column: &Column) -> String {
     match &column.default {
         Some(value) => {
-            let default = match column.tpe.family {
-                ColumnTypeFamily::String | ColumnTypeFamily::DateTime => {
+            match column.tpe.family {
+                _ if is_expression(value) => format!("DEFAULT {}", value),
+                ColumnTypeFamily::String
+                | ColumnTypeFamily::DateTime
+                | ColumnTypeFamily::Uuid
+                | ColumnTypeFamily::Json
+                | ColumnTypeFamily::Enum(_) => {
                     // TODO: find a better solution for this amazing hack. the default value must not be a String
                     if value.starts_with("'") {
                         format!("DEFAULT {}", value)
@@ -21,18 +28,41 @@ pub fn render_default(column: &Column) -> String {
                     }
                 }
                 _ => format!("DEFAULT {}", value),
-            };
-            // we use the default value right now only to smoothen migrations. So we only use it when absolutely needed.
-            if column.is_required() {
-                default
-            } else {
-                "".to_string()
             }
         }
         None => "".to_string(),
     }
 }
 
+/// Renders the comment of a MySQL column. MySQL drops the comment of a column that gets redefined without one.
+pub fn render_comment(comment: Option<&String>) -> String {
+    match comment {
+        Some(comment) => format!("COMMENT '{}'", comment.replace("'", "''")),
+        None => "".to_string(),
+    }
+}
+
+/// Renders a check constraint as part of a table definition, e.g. `CONSTRAINT "positive" CHECK (amount > 0)`.
+pub fn render_check_constraint(renderer: &dyn SqlRenderer, check_constraint: &CheckConstraint) -> String {
+    match &check_constraint.name {
+        Some(name) => format!(
+            "CONSTRAINT {} CHECK ({})",
+            renderer.quote(name),
+            check_constraint.expression
+        ),
+        None => format!("CHECK ({})", check_constraint.expression),
+    }
+}
+
+/// Renders enum values as a list of string literals, e.g. `'A', 'B'`.
+pub fn render_enum_values(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| format!("'{}'", value.replace("'", "''")))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     match on_delete {
         ForeignKeyAction::NoAction => "",
@@ -43,6 +73,18 @@ pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     }
 }
 
+/// Unlike `ON DELETE`, `NO ACTION` is rendered explicitly here, since some MySQL versions report an
+/// omitted update rule as `RESTRICT`, which would make the foreign key differ on every migration.
+pub fn render_on_update(on_update: &ForeignKeyAction) -> &'static str {
+    match on_update {
+        ForeignKeyAction::NoAction => "ON UPDATE NO ACTION",
+        ForeignKeyAction::SetNull => "ON UPDATE SET NULL",
+        ForeignKeyAction::Cascade => "ON UPDATE CASCADE",
+        ForeignKeyAction::SetDefault => "ON UPDATE SET DEFAULT",
+        ForeignKeyAction::Restrict => "ON UPDATE RESTRICT",
+    }
+}
+
 // TODO: this returns None for expressions
 // TODO: bring back once values for columns are not untyped Strings anymore
 //fn render_value(value: &Value) -> Option<String> {
@@ -10,6 +10,8 @@ use mysql_renderer::MySqlRenderer;
 use postgres_renderer::PostgresRenderer;
 use sqlite_renderer::SqliteRenderer;
 
+pub use common::{render_check_constraint, render_comment, render_default, render_enum_values, render_nullability};
+
 pub trait SqlRenderer {
     fn quote_with_schema(&self, schema: &str, name: &str) -> String {
         format!("{}.{}", self.quote(&schema), self.quote(&name),)
@@ -17,9 +19,17 @@ pub trait SqlRenderer {
 
     fn quote(&self, name: &str) -> String;
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String;
-
-    fn render_column_type(&self, t: &ColumnType) -> String;
+    /// Renders a column definition. The schema the migration leads to is needed to resolve enums.
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String;
+
+    fn render_column_type(&self, schema_name: &str, t: &ColumnType, next_schema: &SqlSchema) -> String;
 
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String;
 }
@@ -7,33 +7,50 @@ impl super::SqlRenderer for MySqlRenderer {
         format!("`{}`", name)
     }
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(&schema_name, &column.tpe, next_schema);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
         let references_str = self.render_references(&schema_name, foreign_key);
         let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+        let comment_str = render_comment(column.comment.as_ref());
 
         match foreign_key {
             Some(_) => {
                 let add = if add_fk_prefix { "ADD" } else { "" };
                 let fk_line = format!("{} FOREIGN KEY ({}) {}", add, column_name, references_str);
                 format!(
-                    "{} {} {} {},\n{}",
-                    column_name, tpe_str, nullability_str, default_str, fk_line
+                    "{} {} {} {} {},\n{}",
+                    column_name, tpe_str, nullability_str, default_str, comment_str, fk_line
                 )
             }
             None => format!(
-                "{} {} {} {} {}",
-                column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{} {} {} {} {} {}",
+                column_name, tpe_str, nullability_str, default_str, auto_increment_str, comment_str
             ),
         }
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, _schema_name: &str, t: &ColumnType, next_schema: &SqlSchema) -> String {
         match &t.family {
+            ColumnTypeFamily::Enum(name) => {
+                let enum_type = next_schema
+                    .get_enum(name)
+                    .unwrap_or_else(|| panic!("Enum {} was not present in the schema.", name));
+
+                format!("ENUM({})", render_enum_values(&enum_type.values))
+            }
+            // Native types from the datamodel are rendered as given.
+            _ if !t.raw.is_empty() => t.raw.clone(),
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("datetime(3)"),
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
@@ -41,18 +58,27 @@ impl super::SqlRenderer for MySqlRenderer {
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => format!("varchar(191)"),
-            x => unimplemented!("{:?} not handled yet", x),
+            ColumnTypeFamily::Binary => format!("longblob"),
+            ColumnTypeFamily::Json => format!("json"),
+            ColumnTypeFamily::Uuid => format!("char(36)"),
+            ColumnTypeFamily::Geometric => format!("geometry"),
+            // Types MySQL has no equivalent for are stored as strings.
+            ColumnTypeFamily::LogSequenceNumber
+            | ColumnTypeFamily::TextSearch
+            | ColumnTypeFamily::TransactionId
+            | ColumnTypeFamily::Unknown => format!("varchar(191)"),
         }
     }
 
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES `{}`.`{}`(`{}`) {}",
+                "REFERENCES `{}`.`{}`(`{}`) {} {}",
                 schema_name,
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
@@ -7,9 +7,16 @@ impl super::SqlRenderer for PostgresRenderer {
         format!("\"{}\"", name)
     }
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(&schema_name, &column.tpe, next_schema);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -27,25 +34,36 @@ impl super::SqlRenderer for PostgresRenderer {
         }
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, schema_name: &str, t: &ColumnType, _next_schema: &SqlSchema) -> String {
         match &t.family {
+            ColumnTypeFamily::Enum(name) => self.quote_with_schema(&schema_name, &name),
+            // Native types from the datamodel are rendered as given.
+            _ if !t.raw.is_empty() => t.raw.clone(),
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("timestamp(3)"),
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
             ColumnTypeFamily::Int => format!("integer"),
             ColumnTypeFamily::String => format!("text"),
-            x => unimplemented!("{:?} not handled yet", x),
+            ColumnTypeFamily::Binary => format!("bytea"),
+            ColumnTypeFamily::Json => format!("jsonb"),
+            ColumnTypeFamily::Uuid => format!("uuid"),
+            ColumnTypeFamily::Geometric => format!("point"),
+            ColumnTypeFamily::LogSequenceNumber => format!("pg_lsn"),
+            ColumnTypeFamily::TextSearch => format!("tsvector"),
+            ColumnTypeFamily::TransactionId => format!("xid"),
+            ColumnTypeFamily::Unknown => format!("text"),
         }
     }
 
     fn render_references(&self, schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES \"{}\".\"{}\"(\"{}\") {}",
+                "REFERENCES \"{}\".\"{}\"(\"{}\") {} {}",
                 schema_name,
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
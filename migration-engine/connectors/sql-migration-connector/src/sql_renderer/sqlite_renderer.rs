@@ -7,9 +7,16 @@ impl super::SqlRenderer for SqliteRenderer {
         format!("\"{}\"", name)
     }
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = self.render_column_type(&schema_name, &column.tpe, next_schema);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
@@ -26,24 +33,36 @@ impl super::SqlRenderer for SqliteRenderer {
         )
     }
 
-    fn render_column_type(&self, t: &ColumnType) -> String {
+    fn render_column_type(&self, _schema_name: &str, t: &ColumnType, _next_schema: &SqlSchema) -> String {
         match &t.family {
+            // Native types from the datamodel are rendered as given.
+            _ if !t.raw.is_empty() => t.raw.clone(),
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
             ColumnTypeFamily::String => format!("TEXT"),
-            x => unimplemented!("{:?} not handled yet", x),
+            ColumnTypeFamily::Binary => format!("BLOB"),
+            // SQLite has no enums or dedicated types for the remaining families, they are stored as text.
+            ColumnTypeFamily::Enum(_)
+            | ColumnTypeFamily::Json
+            | ColumnTypeFamily::Uuid
+            | ColumnTypeFamily::Geometric
+            | ColumnTypeFamily::LogSequenceNumber
+            | ColumnTypeFamily::TextSearch
+            | ColumnTypeFamily::TransactionId
+            | ColumnTypeFamily::Unknown => format!("TEXT"),
         }
     }
 
     fn render_references(&self, _schema_name: &str, foreign_key: Option<&ForeignKey>) -> String {
         match foreign_key {
             Some(fk) => format!(
-                "REFERENCES \"{}\"({}) {}",
+                "REFERENCES \"{}\"({}) {} {}",
                 fk.referenced_table,
                 fk.referenced_columns.first().unwrap(),
-                render_on_delete(&fk.on_delete_action)
+                render_on_delete(&fk.on_delete_action),
+                render_on_update(&fk.on_update_action)
             ),
             None => "".to_string(),
         }
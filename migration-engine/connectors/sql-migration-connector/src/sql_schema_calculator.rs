@@ -1,4 +1,4 @@
-use crate::SqlResult;
+use crate::{sql_column_types::DECIMAL_RAW_TYPE, SqlFamily, SqlResult};
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
@@ -8,11 +8,12 @@ use sql_schema_describer as sql;
 
 pub struct SqlSchemaCalculator<'a> {
     data_model: &'a Datamodel,
+    sql_family: SqlFamily,
 }
 
 impl<'a> SqlSchemaCalculator<'a> {
-    pub fn calculate(data_model: &Datamodel) -> SqlResult<sql::SqlSchema> {
-        let calculator = SqlSchemaCalculator { data_model };
+    pub fn calculate(data_model: &Datamodel, sql_family: SqlFamily) -> SqlResult<sql::SqlSchema> {
+        let calculator = SqlSchemaCalculator { data_model, sql_family };
         calculator.calculate_internal()
     }
 
@@ -32,16 +33,72 @@ impl<'a> SqlSchemaCalculator<'a> {
             table.columns.sort_unstable_by_key(|col| col.name.clone());
         }
 
-        let enums = Vec::new();
+        let enums = self.calculate_enums();
         let sequences = Vec::new();
 
         Ok(sql::SqlSchema {
             tables,
             enums,
             sequences,
+            views: vec![],
         })
     }
 
+    /// Postgres enums are types of their own, while MySQL enums are defined inline on each column using
+    /// them. SQLite has no enums, they are stored as text there.
+    fn calculate_enums(&self) -> Vec<sql::Enum> {
+        match self.sql_family {
+            SqlFamily::Postgres => self
+                .data_model
+                .enums()
+                .map(|dml_enum| sql::Enum {
+                    name: dml_enum.db_name(),
+                    values: dml_enum.values.clone(),
+                })
+                .collect(),
+            SqlFamily::Mysql => self
+                .data_model
+                .models()
+                .flat_map(|model| model.fields().map(move |field| (model, field)))
+                .filter_map(|(model, field)| match &field.field_type {
+                    FieldType::Enum(enum_name) => {
+                        let (table_name, column_name) = if field.is_list() {
+                            (scalar_list_table_name(model, field), "value".to_string())
+                        } else {
+                            (model.db_name(), field.db_name())
+                        };
+
+                        Some(sql::Enum {
+                            name: sql::mysql::enum_name(&table_name, &column_name),
+                            values: self.find_enum(enum_name).values.clone(),
+                        })
+                    }
+                    _ => None,
+                })
+                .collect(),
+            SqlFamily::Sqlite => Vec::new(),
+        }
+    }
+
+    /// The column type of a scalar field, taking native enums into account.
+    fn scalar_column_type(&self, table_name: &str, column_name: &str, field: &Field) -> sql::ColumnType {
+        match (&field.field_type, self.sql_family) {
+            (FieldType::Enum(enum_name), SqlFamily::Postgres) => {
+                sql::ColumnType::pure(sql::ColumnTypeFamily::Enum(self.find_enum(enum_name).db_name()))
+            }
+            (FieldType::Enum(_), SqlFamily::Mysql) => sql::ColumnType::pure(sql::ColumnTypeFamily::Enum(
+                sql::mysql::enum_name(table_name, column_name),
+            )),
+            _ => column_type(field),
+        }
+    }
+
+    fn find_enum(&self, enum_name: &str) -> &Enum {
+        self.data_model
+            .find_enum(enum_name)
+            .expect(&format!("Enum {} was not present in the Datamodel.", enum_name))
+    }
+
     fn calculate_model_tables(&self) -> SqlResult<Vec<ModelTable>> {
         self.data_model
             .models()
@@ -49,12 +106,16 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let columns = model
                     .fields()
                     .flat_map(|f| match (&f.field_type, &f.arity) {
-                        (FieldType::Base(_), arity) | (FieldType::Enum(_), arity) if arity != &FieldArity::List => {
+                        (FieldType::Base(_), arity)
+                        | (FieldType::ConnectorSpecific(_), arity)
+                        | (FieldType::Enum(_), arity)
+                            if arity != &FieldArity::List =>
+                        {
                             Some(sql::Column {
                                 name: f.db_name(),
-                                tpe: column_type(f),
+                                tpe: self.scalar_column_type(&model.db_name(), &f.db_name(), f),
                                 arity: column_arity(&f),
-                                default: f.migration_value_new(&self.data_model),
+                                default: f.column_default(),
                                 auto_increment: {
                                     if column_type(f).family == sql::ColumnTypeFamily::Int {
                                         f.is_id()
@@ -62,6 +123,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                         false
                                     }
                                 },
+                                comment: None,
                             })
                         }
                         _ => None,
@@ -117,6 +179,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                     primary_key: Some(primary_key),
                     foreign_keys: Vec::new(),
+                    check_constraints: vec![],
+                    comment: None,
                 };
 
                 Ok(ModelTable {
@@ -147,9 +211,11 @@ impl<'a> SqlSchemaCalculator<'a> {
                     referenced_table: model.db_name(),
                     referenced_columns: vec![model.id_field()?.db_name()],
                     on_delete_action: sql::ForeignKeyAction::Cascade,
+                    on_update_action: sql::ForeignKeyAction::NoAction,
                 }];
+                let table_name = scalar_list_table_name(model, field);
                 let table = sql::Table {
-                    name: format!("{}_{}", model.db_name(), field.db_name()),
+                    name: table_name.clone(),
                     columns: vec![
                         sql::Column {
                             name: "nodeId".to_string(),
@@ -157,6 +223,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             arity: sql::ColumnArity::Required,
                             default: None,
                             auto_increment: false,
+                            comment: None,
                         },
                         sql::Column {
                             name: "position".to_string(),
@@ -164,18 +231,22 @@ impl<'a> SqlSchemaCalculator<'a> {
                             arity: sql::ColumnArity::Required,
                             default: None,
                             auto_increment: false,
+                            comment: None,
                         },
                         sql::Column {
                             name: "value".to_string(),
-                            tpe: column_type(&field),
+                            tpe: self.scalar_column_type(&table_name, "value", &field),
                             arity: sql::ColumnArity::Required,
                             default: None,
                             auto_increment: false,
+                            comment: None,
                         },
                     ],
                     indices: Vec::new(),
                     primary_key: Some(primary_key),
                     foreign_keys,
+                    check_constraints: vec![],
+                    comment: None,
                 };
                 result.push(table);
             }
@@ -200,23 +271,26 @@ impl<'a> SqlSchemaCalculator<'a> {
                             (&relation.model_b, &relation.model_a)
                         };
                         let field = model.fields().find(|f| &f.db_name() == column_name).unwrap();
+                        let related_field = if field == &relation.field_a {
+                            &relation.field_b
+                        } else {
+                            &relation.field_a
+                        };
                         let column = sql::Column {
                             name: column_name.to_string(),
                             tpe: column_type(related_model.id_field()?),
                             arity: column_arity(&field),
                             default: None,
                             auto_increment: false,
+                            comment: None,
                         };
                         let foreign_key = sql::ForeignKey {
                             constraint_name: None,
                             columns: vec![column_name.to_string()],
                             referenced_table: related_model.db_name(),
                             referenced_columns: vec![related_model.id_field()?.db_name()],
-                            on_delete_action: if column.is_required() {
-                                sql::ForeignKeyAction::Restrict
-                            } else {
-                                sql::ForeignKeyAction::SetNull
-                            },
+                            on_delete_action: on_delete_action(&field, related_field, &column),
+                            on_update_action: on_update_action(&field),
                         };
                         model_table.table.columns.push(column);
                         model_table.table.foreign_keys.push(foreign_key)
@@ -241,6 +315,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             referenced_table: relation.model_a.db_name(),
                             referenced_columns: vec![relation.model_a.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::NoAction,
                         },
                         sql::ForeignKey {
                             constraint_name: None,
@@ -248,6 +323,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             referenced_table: relation.model_b.db_name(),
                             referenced_columns: vec![relation.model_b.id_field()?.db_name()],
                             on_delete_action: sql::ForeignKeyAction::Cascade,
+                            on_update_action: sql::ForeignKeyAction::NoAction,
                         },
                     ];
                     let table = sql::Table {
@@ -259,6 +335,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 arity: sql::ColumnArity::Required,
                                 default: None,
                                 auto_increment: false,
+                                comment: None,
                             },
                             sql::Column {
                                 name: relation.model_b_column(),
@@ -266,6 +343,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 arity: sql::ColumnArity::Required,
                                 default: None,
                                 auto_increment: false,
+                                comment: None,
                             },
                         ],
                         indices: vec![sql::Index {
@@ -275,6 +353,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                         }],
                         primary_key: None,
                         foreign_keys,
+                        check_constraints: vec![],
+                        comment: None,
                     };
                     result.push(table);
                 }
@@ -314,6 +394,16 @@ impl ModelExtensions for Model {
     }
 }
 
+pub trait EnumExtensions {
+    fn db_name(&self) -> String;
+}
+
+impl EnumExtensions for Enum {
+    fn db_name(&self) -> String {
+        self.database_name.clone().unwrap_or_else(|| self.name.clone())
+    }
+}
+
 pub trait FieldExtensions {
     fn is_id(&self) -> bool;
 
@@ -325,7 +415,9 @@ pub trait FieldExtensions {
 
     fn migration_value(&self, datamodel: &Datamodel) -> ScalarValue;
 
-    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String>;
+    /// The default declared in the datamodel, as a string. Expressions like `now()` have no default in the
+    /// database.
+    fn column_default(&self) -> Option<String>;
 }
 
 impl FieldExtensions for Field {
@@ -351,13 +443,10 @@ impl FieldExtensions for Field {
             .unwrap_or_else(|| default_migration_value(&self.field_type, datamodel))
     }
 
-    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String> {
+    fn column_default(&self) -> Option<String> {
         let value = match &self.default_value {
-            Some(x) => match x {
-                ScalarValue::Expression(_, _, _) => default_migration_value(&self.field_type, datamodel),
-                x => x.clone(),
-            },
-            None => default_migration_value(&self.field_type, datamodel),
+            Some(ScalarValue::Expression(_, _, _)) | None => return None,
+            Some(x) => x.clone(),
         };
         let result = match value {
             ScalarValue::Boolean(x) => {
@@ -392,16 +481,19 @@ impl FieldExtensions for Field {
 
 fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> ScalarValue {
     match field_type {
-        FieldType::Base(ScalarType::Boolean) => ScalarValue::Boolean(false),
-        FieldType::Base(ScalarType::Int) => ScalarValue::Int(0),
-        FieldType::Base(ScalarType::Float) => ScalarValue::Float(0.0),
-        FieldType::Base(ScalarType::String) => ScalarValue::String("".to_string()),
-        FieldType::Base(ScalarType::Decimal) => ScalarValue::Decimal(0.0),
-        FieldType::Base(ScalarType::DateTime) => {
-            let naive = NaiveDateTime::from_timestamp(0, 0);
-            let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
-            ScalarValue::DateTime(datetime)
-        }
+        FieldType::Base(_) | FieldType::ConnectorSpecific(_) => match field_type.scalar_type() {
+            Some(ScalarType::Boolean) => ScalarValue::Boolean(false),
+            Some(ScalarType::Int) => ScalarValue::Int(0),
+            Some(ScalarType::Float) => ScalarValue::Float(0.0),
+            Some(ScalarType::String) => ScalarValue::String("".to_string()),
+            Some(ScalarType::Decimal) => ScalarValue::Decimal(0.into()),
+            Some(ScalarType::DateTime) => {
+                let naive = NaiveDateTime::from_timestamp(0, 0);
+                let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
+                ScalarValue::DateTime(datetime)
+            }
+            None => unreachable!("base and connector specific types have a scalar type"),
+        },
         FieldType::Enum(ref enum_name) => {
             let inum = datamodel
                 .find_enum(&enum_name)
@@ -416,9 +508,57 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Sca
     }
 }
 
+/// Required relations restrict deletions and optional ones are set to null, unless the datamodel says otherwise.
+/// Earlier versions declared cascading deletes on the list side of the relation.
+fn on_delete_action(field: &Field, related_field: &Field, column: &sql::Column) -> sql::ForeignKeyAction {
+    match (&field.field_type, &related_field.field_type) {
+        (
+            FieldType::Relation(RelationInfo {
+                on_delete: Some(action),
+                ..
+            }),
+            _,
+        ) => foreign_key_action(action),
+        (
+            _,
+            FieldType::Relation(RelationInfo {
+                on_delete: Some(action),
+                ..
+            }),
+        ) if related_field.is_list() => foreign_key_action(action),
+        _ if column.is_required() => sql::ForeignKeyAction::Restrict,
+        _ => sql::ForeignKeyAction::SetNull,
+    }
+}
+
+fn on_update_action(field: &Field) -> sql::ForeignKeyAction {
+    match &field.field_type {
+        FieldType::Relation(RelationInfo {
+            on_update: Some(action),
+            ..
+        }) => foreign_key_action(action),
+        _ => sql::ForeignKeyAction::NoAction,
+    }
+}
+
+fn foreign_key_action(action: &ReferentialAction) -> sql::ForeignKeyAction {
+    match action {
+        ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+        ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+        ReferentialAction::NoAction => sql::ForeignKeyAction::NoAction,
+        ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+        ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+    }
+}
+
+fn scalar_list_table_name(model: &Model, field: &Field) -> String {
+    format!("{}_{}", model.db_name(), field.db_name())
+}
+
 fn is_scalar(field: &Field) -> bool {
     match field.field_type {
         FieldType::Base(_) => true,
+        FieldType::ConnectorSpecific(_) => true,
         FieldType::Enum(_) => true,
         _ => false,
     }
@@ -427,6 +567,7 @@ fn is_scalar(field: &Field) -> bool {
 fn column_type(field: &Field) -> sql::ColumnType {
     match &field.field_type {
         FieldType::Base(ref scalar) => column_type_for_scalar_type(&scalar),
+        FieldType::ConnectorSpecific(native_type) => column_type_for_native_type(native_type),
         FieldType::Enum(_) => column_type_for_scalar_type(&ScalarType::String),
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
@@ -450,6 +591,24 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType) -> sql::ColumnType {
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime),
-        ScalarType::Decimal => unimplemented!(),
+        // Decimals share the float family, but keep their exact type on SQLite too, where floats are `REAL`.
+        ScalarType::Decimal => sql::ColumnType {
+            raw: DECIMAL_RAW_TYPE.to_owned(),
+            family: sql::ColumnTypeFamily::Float,
+        },
+    }
+}
+
+/// The exact type given in the datamodel, with the family the describers assign to it.
+fn column_type_for_native_type(native_type: &ScalarFieldType) -> sql::ColumnType {
+    let family = match native_type.name().to_lowercase().as_str() {
+        "uuid" => sql::ColumnTypeFamily::Uuid,
+        "json" | "jsonb" => sql::ColumnTypeFamily::Json,
+        _ => column_type_for_scalar_type(&native_type.prisma_type()).family,
+    };
+
+    sql::ColumnType {
+        raw: native_type.datasource_type().to_owned(),
+        family,
     }
 }
@@ -1,13 +1,18 @@
+use crate::sql_column_defaults::column_defaults_differ;
+use crate::sql_column_types::column_types_differ;
 use crate::*;
 use log::debug;
 use sql_schema_describer::*;
 
 const MIGRATION_TABLE_NAME: &str = "_Migration";
 
+/// Diffs the tables, indexes and enums of two schemas. Views, check constraints and comments are not managed by
+/// migrations, so they are never diffed, and whatever exists in the database is left alone.
 #[derive(Debug)]
 pub struct SqlSchemaDiffer<'a> {
     previous: &'a SqlSchema,
     next: &'a SqlSchema,
+    sql_family: SqlFamily,
 }
 
 #[derive(Debug, Clone)]
@@ -18,6 +23,9 @@ pub struct SqlSchemaDiff {
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub create_enums: Vec<CreateEnum>,
+    pub alter_enums: Vec<AlterEnum>,
+    pub drop_enums: Vec<DropEnum>,
 }
 
 impl SqlSchemaDiff {
@@ -25,12 +33,19 @@ impl SqlSchemaDiff {
         let mut steps = Vec::new();
         steps.append(&mut wrap_as_step(self.drop_indexes, |x| SqlMigrationStep::DropIndex(x)));
         steps.append(&mut wrap_as_step(self.drop_tables, |x| SqlMigrationStep::DropTable(x)));
+        // Enums must exist before the columns using them are created, and can only be dropped once
+        // no column uses them anymore.
+        steps.append(&mut wrap_as_step(self.create_enums, |x| {
+            SqlMigrationStep::CreateEnum(x)
+        }));
+        steps.append(&mut wrap_as_step(self.alter_enums, |x| SqlMigrationStep::AlterEnum(x)));
         steps.append(&mut wrap_as_step(self.create_tables, |x| {
             SqlMigrationStep::CreateTable(x)
         }));
         steps.append(&mut wrap_as_step(self.alter_tables, |x| {
             SqlMigrationStep::AlterTable(x)
         }));
+        steps.append(&mut wrap_as_step(self.drop_enums, |x| SqlMigrationStep::DropEnum(x)));
         steps.append(&mut wrap_as_step(self.create_indexes, |x| {
             SqlMigrationStep::CreateIndex(x)
         }));
@@ -42,8 +57,12 @@ impl SqlSchemaDiff {
 }
 
 impl<'a> SqlSchemaDiffer<'a> {
-    pub fn diff(previous: &SqlSchema, next: &SqlSchema) -> SqlSchemaDiff {
-        let differ = SqlSchemaDiffer { previous, next };
+    pub fn diff(previous: &SqlSchema, next: &SqlSchema, sql_family: SqlFamily) -> SqlSchemaDiff {
+        let differ = SqlSchemaDiffer {
+            previous,
+            next,
+            sql_family,
+        };
         differ.diff_internal()
     }
 
@@ -57,6 +76,9 @@ impl<'a> SqlSchemaDiffer<'a> {
             create_indexes: self.create_indexes(&alter_indexes),
             drop_indexes: self.drop_indexes(&alter_indexes),
             alter_indexes,
+            create_enums: self.create_enums(),
+            alter_enums: self.alter_enums(),
+            drop_enums: self.drop_enums(),
         }
     }
 
@@ -95,7 +117,7 @@ impl<'a> SqlSchemaDiffer<'a> {
                 changes.extend(Self::drop_foreign_keys(&previous_table, &next_table));
                 changes.append(&mut Self::drop_columns(&previous_table, &next_table));
                 changes.append(&mut Self::add_columns(&previous_table, &next_table));
-                changes.append(&mut Self::alter_columns(&previous_table, &next_table));
+                changes.append(&mut Self::alter_columns(&previous_table, &next_table, self.sql_family));
 
                 if !changes.is_empty() {
                     let update = AlterTable {
@@ -128,6 +150,7 @@ impl<'a> SqlSchemaDiffer<'a> {
             if !previous.has_column(&next_column.name) {
                 let change = AddColumn {
                     column: next_column.clone(),
+                    backfill: None,
                 };
                 result.push(TableChange::AddColumn(change));
             }
@@ -135,7 +158,7 @@ impl<'a> SqlSchemaDiffer<'a> {
         result
     }
 
-    fn alter_columns(previous: &Table, next: &Table) -> Vec<TableChange> {
+    fn alter_columns(previous: &Table, next: &Table, sql_family: SqlFamily) -> Vec<TableChange> {
         let mut result = Vec::new();
         for next_column in &next.columns {
             if let Some(previous_column) = previous.column(&next_column.name) {
@@ -143,6 +166,8 @@ impl<'a> SqlSchemaDiffer<'a> {
                 let next_fk = next.foreign_key_for_column(&next_column.name);
 
                 if previous_column.differs_in_something_except_default(next_column)
+                    || column_types_differ(sql_family, &previous_column.tpe, &next_column.tpe)
+                    || column_defaults_differ(sql_family, previous_column, next_column)
                     || foreign_key_changed(previous_fk, next_fk)
                 {
                     let change = AlterColumn {
@@ -238,6 +263,65 @@ impl<'a> SqlSchemaDiffer<'a> {
         result
     }
 
+    fn create_enums(&self) -> Vec<CreateEnum> {
+        self.next
+            .enums
+            .iter()
+            .filter(|next_enum| self.previous.get_enum(&next_enum.name).is_none())
+            .map(|next_enum| CreateEnum {
+                name: next_enum.name.clone(),
+                values: next_enum.values.clone(),
+            })
+            .collect()
+    }
+
+    fn drop_enums(&self) -> Vec<DropEnum> {
+        self.previous
+            .enums
+            .iter()
+            .filter(|previous_enum| self.next.get_enum(&previous_enum.name).is_none())
+            .map(|previous_enum| DropEnum {
+                name: previous_enum.name.clone(),
+            })
+            .collect()
+    }
+
+    fn alter_enums(&self) -> Vec<AlterEnum> {
+        self.previous
+            .enums
+            .iter()
+            .filter_map(|previous_enum| {
+                self.next
+                    .get_enum(&previous_enum.name)
+                    .map(|next_enum| (previous_enum, next_enum))
+            })
+            .filter_map(|(previous_enum, next_enum)| {
+                let created_variants: Vec<String> = next_enum
+                    .values
+                    .iter()
+                    .filter(|value| !previous_enum.values.contains(value))
+                    .cloned()
+                    .collect();
+                let dropped_variants: Vec<String> = previous_enum
+                    .values
+                    .iter()
+                    .filter(|value| !next_enum.values.contains(value))
+                    .cloned()
+                    .collect();
+
+                if created_variants.is_empty() && dropped_variants.is_empty() {
+                    None
+                } else {
+                    Some(AlterEnum {
+                        name: next_enum.name.clone(),
+                        created_variants,
+                        dropped_variants,
+                    })
+                }
+            })
+            .collect()
+    }
+
     /// An iterator over the tables that are present in both schemas. The yielded tuples should be interpreted as `(previous_table, next_table)`.
     fn table_pairs(&self) -> impl Iterator<Item = (&Table, &Table)> {
         self.previous.tables.iter().filter_map(move |previous_table| {
@@ -280,6 +364,49 @@ fn indexes_are_equivalent(first: &Index, second: &Index) -> bool {
     first.columns == second.columns && first.tpe == second.tpe
 }
 
+/// Returns whether the type of the column is the only thing that changed between the two tables besides its default,
+/// within the same type family. Such changes are applied in place, without dropping the column and its data.
+pub(crate) fn is_type_change_only(
+    sql_family: SqlFamily,
+    previous_table: &Table,
+    next_table: &Table,
+    column_name: &str,
+) -> bool {
+    match (previous_table.column(column_name), next_table.column(column_name)) {
+        (Some(previous_column), Some(next_column)) => {
+            !previous_column.differs_in_something_except_default(next_column)
+                && column_types_differ(sql_family, &previous_column.tpe, &next_column.tpe)
+                && !foreign_key_changed(
+                    previous_table.foreign_key_for_column(column_name),
+                    next_table.foreign_key_for_column(column_name),
+                )
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the default of the column is the only thing that changed between the two tables. Such changes are
+/// applied in place.
+pub(crate) fn is_default_change_only(
+    sql_family: SqlFamily,
+    previous_table: &Table,
+    next_table: &Table,
+    column_name: &str,
+) -> bool {
+    match (previous_table.column(column_name), next_table.column(column_name)) {
+        (Some(previous_column), Some(next_column)) => {
+            !previous_column.differs_in_something_except_default(next_column)
+                && !column_types_differ(sql_family, &previous_column.tpe, &next_column.tpe)
+                && column_defaults_differ(sql_family, previous_column, next_column)
+                && !foreign_key_changed(
+                    previous_table.foreign_key_for_column(column_name),
+                    next_table.foreign_key_for_column(column_name),
+                )
+        }
+        _ => false,
+    }
+}
+
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether a
 /// migration needs to be applied.
 fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>) -> bool {
@@ -292,9 +419,10 @@ fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>)
 
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether they
 /// should be considered equivalent for schema diffing purposes.
-fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
+pub(crate) fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
     previous.referenced_table == next.referenced_table
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
         && previous.on_delete_action == next.on_delete_action
+        && previous.on_update_action == next.on_update_action
 }
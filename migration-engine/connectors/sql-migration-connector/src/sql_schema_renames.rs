@@ -0,0 +1,193 @@
+use crate::sql_schema_calculator::{FieldExtensions, ModelExtensions};
+use crate::*;
+use datamodel::*;
+use sql_schema_describer::*;
+
+/// The tables and columns renamed between two datamodels. A model or field keeps its name while its database name
+/// changes through `@@map` or `@map`, so the table or column is renamed instead of being dropped and recreated.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct SqlSchemaRenames {
+    /// `(name, new_name)` pairs.
+    tables: Vec<(String, String)>,
+    /// `(table, name, new_name)` triples. The table is named as after the table renames.
+    columns: Vec<(String, String, String)>,
+}
+
+impl SqlSchemaRenames {
+    /// The renames between the two datamodels that apply to `current_schema`: the renamed tables and columns must
+    /// exist there, and their new names must not.
+    pub(crate) fn calculate(previous: &Datamodel, next: &Datamodel, current_schema: &SqlSchema) -> SqlSchemaRenames {
+        let mut renames = SqlSchemaRenames::default();
+
+        for next_model in next.models() {
+            // A model renamed in the datamodel keeps its database name.
+            let previous_model = previous
+                .find_model(&next_model.name)
+                .or_else(|| previous.models().find(|model| model.db_name() == next_model.db_name()));
+
+            let previous_model = match previous_model {
+                Some(previous_model) => previous_model,
+                None => continue,
+            };
+
+            let previous_table = match current_schema.table(&previous_model.db_name()) {
+                Ok(previous_table) => previous_table,
+                Err(_) => continue,
+            };
+
+            let next_table_name = next_model.db_name();
+
+            if previous_table.name != next_table_name {
+                if current_schema.has_table(&next_table_name) {
+                    continue;
+                }
+
+                renames
+                    .tables
+                    .push((previous_table.name.clone(), next_table_name.clone()));
+            }
+
+            for next_field in next_model.fields().filter(|field| is_column(field)) {
+                let previous_column = previous_model
+                    .find_field(&next_field.name)
+                    .filter(|previous_field| is_column(previous_field))
+                    .and_then(|previous_field| previous_table.column(&previous_field.db_name()));
+
+                if let Some(previous_column) = previous_column {
+                    let next_column_name = next_field.db_name();
+
+                    if previous_column.name != next_column_name && !previous_table.has_column(&next_column_name) {
+                        renames
+                            .columns
+                            .push((next_table_name.clone(), previous_column.name.clone(), next_column_name));
+                    }
+                }
+            }
+        }
+
+        renames
+    }
+
+    /// A copy of the schema with the renames applied, to be diffed against the next schema.
+    pub(crate) fn apply(&self, schema: &SqlSchema) -> SqlSchema {
+        let mut schema = schema.clone();
+
+        for (name, new_name) in &self.tables {
+            rename_table(&mut schema, name, new_name);
+        }
+
+        for (table, name, new_name) in &self.columns {
+            rename_column(&mut schema, table, name, new_name);
+        }
+
+        schema
+    }
+
+    /// The renames undoing these renames.
+    pub(crate) fn reversed(&self) -> SqlSchemaRenames {
+        let previous_table_name = |table: &String| {
+            self.tables
+                .iter()
+                .find(|(_, new_name)| new_name == table)
+                .map(|(name, _)| name.clone())
+                .unwrap_or_else(|| table.clone())
+        };
+
+        SqlSchemaRenames {
+            tables: self
+                .tables
+                .iter()
+                .map(|(name, new_name)| (new_name.clone(), name.clone()))
+                .collect(),
+            columns: self
+                .columns
+                .iter()
+                .map(|(table, name, new_name)| (previous_table_name(table), new_name.clone(), name.clone()))
+                .collect(),
+        }
+    }
+
+    /// The steps renaming the tables, then their columns. The renamed columns are taken from `next_schema`.
+    pub(crate) fn steps(&self, next_schema: &SqlSchema) -> Vec<SqlMigrationStep> {
+        let rename_tables = self
+            .tables
+            .iter()
+            .map(|(name, new_name)| SqlMigrationStep::RenameTable {
+                name: name.clone(),
+                new_name: new_name.clone(),
+            });
+
+        let rename_columns = self.columns.iter().filter_map(move |(table, name, new_name)| {
+            next_schema
+                .table(table)
+                .ok()
+                .and_then(|next_table| next_table.column(new_name))
+                .map(|column| {
+                    SqlMigrationStep::RenameColumn(RenameColumn {
+                        table: table.clone(),
+                        name: name.clone(),
+                        column: column.clone(),
+                    })
+                })
+        });
+
+        rename_tables.chain(rename_columns).collect()
+    }
+}
+
+fn is_column(field: &Field) -> bool {
+    match field.field_type {
+        FieldType::Base(_) | FieldType::ConnectorSpecific(_) | FieldType::Enum(_) => !field.is_list(),
+        _ => false,
+    }
+}
+
+fn rename_table(schema: &mut SqlSchema, name: &str, new_name: &str) {
+    for table in schema.tables.iter_mut() {
+        if table.name == name {
+            table.name = new_name.to_owned();
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == name {
+                foreign_key.referenced_table = new_name.to_owned();
+            }
+        }
+    }
+}
+
+fn rename_column(schema: &mut SqlSchema, table_name: &str, name: &str, new_name: &str) {
+    let rename = |column: &mut String| {
+        if *column == name {
+            *column = new_name.to_owned();
+        }
+    };
+
+    for table in schema.tables.iter_mut() {
+        if table.name == table_name {
+            table.columns.iter_mut().for_each(|column| rename(&mut column.name));
+            table
+                .indices
+                .iter_mut()
+                .flat_map(|index| index.columns.iter_mut())
+                .for_each(rename);
+            table
+                .primary_key
+                .iter_mut()
+                .flat_map(|primary_key| primary_key.columns.iter_mut())
+                .for_each(rename);
+            table
+                .foreign_keys
+                .iter_mut()
+                .flat_map(|foreign_key| foreign_key.columns.iter_mut())
+                .for_each(rename);
+        }
+
+        table
+            .foreign_keys
+            .iter_mut()
+            .filter(|foreign_key| foreign_key.referenced_table == table_name)
+            .flat_map(|foreign_key| foreign_key.referenced_columns.iter_mut())
+            .for_each(rename);
+    }
+}
@@ -42,9 +42,12 @@ pub trait GenericApi: Send + Sync + 'static {
         input: &CalculateDatabaseStepsInput,
     ) -> crate::Result<MigrationStepsResultOutput>;
     fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> crate::Result<CalculateDatamodelOutput>;
+    fn detect_drift(&self, input: &serde_json::Value) -> crate::Result<DetectDriftOutput>;
     fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> crate::Result<MigrationStepsResultOutput>;
     fn list_migrations(&self, input: &serde_json::Value) -> crate::Result<Vec<ListMigrationStepsOutput>>;
     fn migration_progress(&self, input: &MigrationProgressInput) -> crate::Result<MigrationProgressOutput>;
+    fn render_migration_script(&self, input: &RenderMigrationScriptInput)
+        -> crate::Result<RenderMigrationScriptOutput>;
     fn reset(&self, input: &serde_json::Value) -> crate::Result<serde_json::Value>;
     fn unapply_migration(&self, input: &UnapplyMigrationInput) -> crate::Result<UnapplyMigrationOutput>;
     fn migration_persistence(&self) -> Arc<dyn MigrationPersistence>;
@@ -71,6 +74,10 @@ where
         self.handle_command::<CalculateDatamodelCommand>(input)
     }
 
+    fn detect_drift(&self, input: &serde_json::Value) -> crate::Result<DetectDriftOutput> {
+        self.handle_command::<DetectDriftCommand>(input)
+    }
+
     fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> crate::Result<MigrationStepsResultOutput> {
         self.handle_command::<InferMigrationStepsCommand>(input)
     }
@@ -83,6 +90,13 @@ where
         self.handle_command::<MigrationProgressCommand>(input)
     }
 
+    fn render_migration_script(
+        &self,
+        input: &RenderMigrationScriptInput,
+    ) -> crate::Result<RenderMigrationScriptOutput> {
+        self.handle_command::<RenderMigrationScriptCommand>(input)
+    }
+
     fn reset(&self, input: &serde_json::Value) -> crate::Result<serde_json::Value> {
         self.handle_command::<ResetCommand>(input)
     }
@@ -28,6 +28,8 @@ enum RpcCommand {
     Reset,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    RenderMigrationScript,
+    DetectDrift,
 }
 
 impl RpcCommand {
@@ -41,6 +43,8 @@ impl RpcCommand {
             RpcCommand::Reset => "reset",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::RenderMigrationScript => "renderMigrationScript",
+            RpcCommand::DetectDrift => "detectDrift",
         }
     }
 }
@@ -54,6 +58,8 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::Reset,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::RenderMigrationScript,
+    RpcCommand::DetectDrift,
 ];
 
 impl RpcApi {
@@ -187,6 +193,17 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 let result = executor.calculate_database_steps(&input)?;
 
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::RenderMigrationScript => {
+                let input: RenderMigrationScriptInput = params.clone().parse()?;
+                let result = executor.render_migration_script(&input)?;
+
+                serde_json::to_value(result).expect("Rendering of RPC response failed")
+            }
+            RpcCommand::DetectDrift => {
+                let result = executor.detect_drift(&serde_json::Value::Null)?;
+
                 serde_json::to_value(result).expect("Rendering of RPC response failed")
             }
         };
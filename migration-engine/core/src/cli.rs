@@ -1,10 +1,12 @@
+use crate::{commands::*, migration_engine::MigrationEngine};
 use clap::ArgMatches;
 use failure::Fail;
 use itertools::Itertools;
+use log::*;
 use migration_connector::*;
 use quaint::prelude::SqlFamily;
 use sql_migration_connector::SqlMigrationConnector;
-use std::collections::HashMap;
+use std::{collections::HashMap, fs};
 use url::Url;
 
 #[derive(Debug, Fail, PartialEq)]
@@ -82,6 +84,48 @@ pub fn run(matches: &ArgMatches, datasource: &str) -> std::result::Result<String
     }
 }
 
+/// Render the migration script for the datamodel without applying it. The rollback script is written to the
+/// `rollback_output` file if one is given. With a previous datamodel, the database is never contacted.
+pub fn render_migration_script(matches: &ArgMatches) -> std::result::Result<String, CliError> {
+    let datamodel = read_file(matches.value_of("datamodel").unwrap())?;
+    let previous_datamodel = match matches.value_of("previous_datamodel") {
+        Some(path) => Some(read_file(path)?),
+        None => None,
+    };
+
+    let config = datamodel::parse_configuration(&datamodel).map_err(crate::Error::from)?;
+    let source = config
+        .datasources
+        .first()
+        .ok_or_else(|| CliError::Other("There is no datasource in the configuration.".into()))?;
+
+    let connector = SqlMigrationConnector::new_offline(source.as_ref())?;
+    let engine = MigrationEngine::new_uninitialized(connector);
+
+    let input = RenderMigrationScriptInput {
+        datamodel,
+        previous_datamodel,
+    };
+
+    let output = RenderMigrationScriptCommand::new(&input)
+        .execute(&engine)
+        .map_err(crate::Error::from)?;
+
+    for warning in output.warnings {
+        warn!("{}", warning.description);
+    }
+
+    if let Some(path) = matches.value_of("rollback_output") {
+        fs::write(path, &output.rollback_script).map_err(crate::Error::from)?;
+    }
+
+    Ok(output.script)
+}
+
+fn read_file(path: &str) -> std::result::Result<String, CliError> {
+    Ok(fs::read_to_string(path).map_err(crate::Error::from)?)
+}
+
 fn fetch_db_name(url: &Url, default: &str) -> String {
     let result = match url.path_segments() {
         Some(mut segments) => segments.next().unwrap_or(default),
@@ -217,6 +261,37 @@ pub fn clap_app() -> clap::App<'static, 'static> {
                         .required(false),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("render_migration_script")
+                .about("Prints the SQL script migrating the database to the datamodel, without applying it.")
+                .arg(
+                    Arg::with_name("datamodel")
+                        .long("datamodel")
+                        .short("d")
+                        .value_name("FILE")
+                        .help("Path to the datamodel to migrate to.")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("previous_datamodel")
+                        .long("previous_datamodel")
+                        .short("p")
+                        .value_name("FILE")
+                        .help("Path to the datamodel to migrate from. When given, the database is not used at all.")
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("rollback_output")
+                        .long("rollback_output")
+                        .short("r")
+                        .value_name("FILE")
+                        .help("Path to write the rollback script to.")
+                        .takes_value(true)
+                        .required(false),
+                ),
+        )
 }
 
 #[cfg(test)]
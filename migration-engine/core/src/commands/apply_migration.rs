@@ -57,7 +57,7 @@ impl<'a> ApplyMigrationCommand<'a> {
         let next_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&last_non_watch_datamodel, self.input.steps.as_slice())?;
-        let next_datamodel = datamodel::lift_ast(&next_datamodel_ast)?;
+        let next_datamodel = datamodel::lift_ast_for_connector_type(&next_datamodel_ast, connector.connector_type())?;
 
         self.handle_migration(&engine, current_datamodel, next_datamodel)
     }
@@ -75,7 +75,7 @@ impl<'a> ApplyMigrationCommand<'a> {
         let next_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&current_datamodel_ast, self.input.steps.as_slice())?;
-        let next_datamodel = datamodel::lift_ast(&next_datamodel_ast)?;
+        let next_datamodel = datamodel::lift_ast_for_connector_type(&next_datamodel_ast, connector.connector_type())?;
 
         self.handle_migration(&engine, current_datamodel, next_datamodel)
     }
@@ -92,11 +92,14 @@ impl<'a> ApplyMigrationCommand<'a> {
     {
         let connector = engine.connector();
         let migration_persistence = connector.migration_persistence();
+        let script = self.input.migration_script()?;
 
-        let database_migration =
-            connector
-                .database_migration_inferrer()
-                .infer(&current_datamodel, &next_datamodel, &self.input.steps)?; // TODO: those steps are a lie right now. Does not matter because we don't use them at the moment.
+        let database_migration = connector.database_migration_inferrer().infer(
+            &current_datamodel,
+            &next_datamodel,
+            &self.input.steps,
+            &script,
+        )?; // TODO: those steps are a lie right now. Does not matter because we don't use them at the moment.
 
         let database_steps_json_pretty = connector
             .database_migration_step_applier()
@@ -108,24 +111,31 @@ impl<'a> ApplyMigrationCommand<'a> {
         migration.datamodel_steps = self.input.steps.clone();
         migration.database_migration = database_migration_json;
         migration.datamodel = next_datamodel.clone();
+        migration.script = script;
 
         let diagnostics = connector.destructive_changes_checker().check(&database_migration)?;
+        let has_warnings = diagnostics.has_warnings();
+        let DestructiveChangeDiagnostics { warnings, mut errors } = diagnostics;
 
-        match (diagnostics.has_warnings(), self.input.force.unwrap_or(false)) {
+        match (has_warnings, self.input.force.unwrap_or(false)) {
             // We have no warnings, or the force flag is passed.
             (false, _) | (true, true) => {
                 let saved_migration = migration_persistence.create(migration);
 
-                connector
+                match connector
                     .migration_applier()
-                    .apply(&saved_migration, &database_migration)?;
+                    .apply(&saved_migration, &database_migration)
+                {
+                    Ok(()) => (),
+                    // The migration was rolled back. The violations are reported like the checker's errors.
+                    Err(ConnectorError::ForeignKeyViolations(violations)) => errors.extend(violations),
+                    Err(err) => return Err(err.into()),
+                }
             }
             // We have warnings, but no force flag was passed.
             (true, false) => (),
         }
 
-        let DestructiveChangeDiagnostics { warnings, errors } = diagnostics;
-
         Ok(MigrationStepsResultOutput {
             datamodel: datamodel::render_datamodel_to_string(&next_datamodel).unwrap(),
             datamodel_steps: self.input.steps.clone(),
@@ -143,6 +153,32 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    /// SQL of the user to run at the points of the migration it defines.
+    pub script: Option<MigrationScript>,
+    /// The path of a JSON file containing the script, as an alternative to `script`.
+    pub script_file: Option<String>,
+}
+
+impl ApplyMigrationInput {
+    fn migration_script(&self) -> CommandResult<MigrationScript> {
+        let script_file = match (&self.script, &self.script_file) {
+            (Some(_), Some(_)) => {
+                let error = "Only one of script and scriptFile can be provided.".to_string();
+                return Err(CommandError::Input { code: 1002, error });
+            }
+            (Some(script), None) => return Ok(script.clone()),
+            (None, None) => return Ok(MigrationScript::default()),
+            (None, Some(script_file)) => script_file,
+        };
+
+        std::fs::read_to_string(script_file)
+            .map_err(|err| err.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+            .map_err(|err| {
+                let error = format!("Could not read the migration script at {}: {}", script_file, err);
+                CommandError::Input { code: 1002, error }
+            })
+    }
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
@@ -0,0 +1,39 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::Serialize;
+
+/// Compare the schema the applied migrations lead to with the schema of the database, and report every difference,
+/// e.g. the ones introduced by hand-applied changes.
+pub struct DetectDriftCommand;
+
+impl<'a> MigrationCommand<'a> for DetectDriftCommand {
+    type Input = serde_json::Value;
+    type Output = DetectDriftOutput;
+
+    fn new(_: &'a Self::Input) -> Box<Self> {
+        Box::new(DetectDriftCommand {})
+    }
+
+    fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + 'static,
+    {
+        let connector = engine.connector();
+        let expected_datamodel = connector.migration_persistence().current_datamodel();
+        let drifts = connector.drift_detector().detect_drift(&expected_datamodel)?;
+
+        Ok(DetectDriftOutput {
+            has_drift: !drifts.is_empty(),
+            drifts,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectDriftOutput {
+    pub has_drift: bool,
+    pub drifts: Vec<Drift>,
+}
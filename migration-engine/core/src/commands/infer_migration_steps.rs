@@ -34,17 +34,22 @@ impl<'a> MigrationCommand<'a> for InferMigrationStepsCommand<'a> {
         let assumed_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&current_datamodel_ast, self.input.assume_to_be_applied.as_slice())?;
-        let assumed_datamodel = datamodel::lift_ast(&assumed_datamodel_ast)?;
+        let assumed_datamodel =
+            datamodel::lift_ast_for_connector_type(&assumed_datamodel_ast, connector.connector_type())?;
 
-        let next_datamodel = parse_datamodel(&self.input.datamodel)?;
+        let next_datamodel = parse_datamodel(&self.input.datamodel, connector.connector_type())?;
         let next_datamodel_ast = parse(&self.input.datamodel)?;
 
         let model_migration_steps = engine
             .datamodel_migration_steps_inferrer()
             .infer(&assumed_datamodel_ast, &next_datamodel_ast);
 
-        let database_migration =
-            database_migration_inferrer.infer(&assumed_datamodel, &next_datamodel, &model_migration_steps)?;
+        let database_migration = database_migration_inferrer.infer(
+            &assumed_datamodel,
+            &next_datamodel,
+            &model_migration_steps,
+            &MigrationScript::default(),
+        )?;
 
         let DestructiveChangeDiagnostics { warnings, errors: _ } =
             connector.destructive_changes_checker().check(&database_migration)?;
@@ -2,9 +2,11 @@ mod apply_migration;
 mod calculate_database_steps;
 mod calculate_datamodel;
 mod command;
+mod detect_drift;
 mod infer_migration_steps;
 mod list_migrations;
 mod migration_progress;
+mod render_migration_script;
 mod reset;
 mod unapply_migration;
 
@@ -12,9 +14,11 @@ pub use apply_migration::*;
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
 pub use command::*;
+pub use detect_drift::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
 pub use migration_progress::*;
+pub use render_migration_script::*;
 pub use reset::*;
 pub use unapply_migration::*;
 
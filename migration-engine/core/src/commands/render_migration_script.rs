@@ -0,0 +1,99 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use crate::*;
+use datamodel::ast::{parser::parse, SchemaAst};
+use log::*;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+/// Render the SQL script migrating to the given datamodel, together with its rollback script, without applying
+/// anything. When a previous datamodel is given, both are compared directly and the database is not used at all.
+/// Otherwise the current state of the database is described and used as the starting point.
+pub struct RenderMigrationScriptCommand<'a> {
+    input: &'a RenderMigrationScriptInput,
+}
+
+impl<'a> MigrationCommand<'a> for RenderMigrationScriptCommand<'a> {
+    type Input = RenderMigrationScriptInput;
+    type Output = RenderMigrationScriptOutput;
+
+    fn new(input: &'a Self::Input) -> Box<Self> {
+        Box::new(RenderMigrationScriptCommand { input })
+    }
+
+    fn execute<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        debug!("{:?}", self.input);
+
+        let connector = engine.connector();
+        let database_migration_inferrer = connector.database_migration_inferrer();
+
+        let next_datamodel = parse_datamodel(&self.input.datamodel, connector.connector_type())?;
+        let next_datamodel_ast = parse(&self.input.datamodel)?;
+
+        let (database_migration, warnings) = match &self.input.previous_datamodel {
+            Some(previous_datamodel_str) => {
+                let previous_datamodel = parse_datamodel(previous_datamodel_str, connector.connector_type())?;
+                let previous_datamodel_ast = parse(previous_datamodel_str)?;
+
+                let model_migration_steps = engine
+                    .datamodel_migration_steps_inferrer()
+                    .infer(&previous_datamodel_ast, &next_datamodel_ast);
+
+                let database_migration = database_migration_inferrer.infer_from_datamodels(
+                    &previous_datamodel,
+                    &next_datamodel,
+                    &model_migration_steps,
+                )?;
+
+                // Without a database, there is no data to check for destructive changes.
+                (database_migration, Vec::new())
+            }
+            None => {
+                // The migrations table is not consulted: the inferrer works from the described database schema.
+                let model_migration_steps = engine
+                    .datamodel_migration_steps_inferrer()
+                    .infer(&SchemaAst::empty(), &next_datamodel_ast);
+
+                let database_migration = database_migration_inferrer.infer(
+                    &Datamodel::empty(),
+                    &next_datamodel,
+                    &model_migration_steps,
+                    &MigrationScript::default(),
+                )?;
+
+                let DestructiveChangeDiagnostics { warnings, errors: _ } =
+                    connector.destructive_changes_checker().check(&database_migration)?;
+
+                (database_migration, warnings)
+            }
+        };
+
+        let step_applier = connector.database_migration_step_applier();
+
+        Ok(RenderMigrationScriptOutput {
+            script: step_applier.render_script(&database_migration)?,
+            rollback_script: step_applier.render_rollback_script(&database_migration)?,
+            warnings,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderMigrationScriptInput {
+    #[serde(alias = "dataModel")]
+    pub datamodel: String,
+    pub previous_datamodel: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderMigrationScriptOutput {
+    pub script: String,
+    pub rollback_script: String,
+    pub warnings: Vec<MigrationWarning>,
+}
@@ -10,8 +10,12 @@ use datamodel::{self, Datamodel};
 pub use error::Error;
 pub use migration_engine::*;
 
-pub fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
-    let result = datamodel::parse_datamodel_or_pretty_error(&datamodel, "datamodel file, line");
+pub fn parse_datamodel(datamodel: &str, connector_type: &str) -> CommandResult<Datamodel> {
+    let result = datamodel::parse_datamodel_for_connector_type_or_pretty_error(
+        &datamodel,
+        "datamodel file, line",
+        connector_type,
+    );
     result.map_err(|e| CommandError::Generic { code: 1001, error: e })
 }
 
@@ -16,8 +16,12 @@ pub use migration_engine::*;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-pub(crate) fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
-    let result = datamodel::parse_datamodel_or_pretty_error(&datamodel, "datamodel file, line");
+pub(crate) fn parse_datamodel(datamodel: &str, connector_type: &str) -> CommandResult<Datamodel> {
+    let result = datamodel::parse_datamodel_for_connector_type_or_pretty_error(
+        &datamodel,
+        "datamodel file, line",
+        connector_type,
+    );
     result.map_err(|e| CommandError::Generic { code: 1001, error: e })
 }
 
@@ -57,6 +61,18 @@ fn main() {
             Err(error) => {
                 error!("{}", error);
 
+                std::process::exit(error.exit_code());
+            }
+        }
+    } else if let Some(matches) = matches.subcommand_matches("render_migration_script") {
+        match cli::render_migration_script(&matches) {
+            Ok(script) => {
+                print!("{}", script);
+                std::process::exit(0);
+            }
+            Err(error) => {
+                error!("{}", error);
+
                 std::process::exit(error.exit_code());
             }
         }
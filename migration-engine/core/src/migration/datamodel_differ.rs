@@ -6,7 +6,7 @@ mod fields;
 mod models;
 mod top_level;
 
-use directives::DirectiveDiffer;
+use directives::{directives_are_identical, DirectiveDiffer};
 use enums::EnumDiffer;
 use fields::FieldDiffer;
 use models::ModelDiffer;
@@ -188,7 +188,17 @@ fn push_deleted_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
 
 fn push_updated_models<'a>(steps: &mut Steps, models: impl Iterator<Item = ModelDiffer<'a>>) {
     models.for_each(|model| {
-        let model_name = &model.previous.name.name;
+        let update_model_step = steps::UpdateModel {
+            model: model.previous.name.name.clone(),
+            new_name: diff_value(&model.previous.name.name, &model.next.name.name),
+        };
+
+        if update_model_step.is_any_option_set() {
+            steps.push(MigrationStep::UpdateModel(update_model_step));
+        }
+
+        // The steps below apply after the rename.
+        let model_name = &model.next.name.name;
 
         push_created_fields(steps, model_name, model.created_fields());
         push_deleted_fields(steps, model_name, model.deleted_fields());
@@ -259,7 +269,7 @@ fn push_updated_fields<'a>(steps: &mut Steps, model_name: &'a str, fields: impl
 
         let directive_location = steps::DirectiveType::Field {
             model: model_name.to_owned(),
-            field: field.previous.name.name.clone(),
+            field: field.next.name.name.clone(),
         };
 
         push_created_directives(steps, &directive_location, field.created_directives());
@@ -361,6 +371,17 @@ fn push_updated_directives<'a>(
 }
 
 fn push_updated_directive(steps: &mut Steps, location: steps::DirectiveType, directive: DirectiveDiffer<'_>) {
+    // Unnamed arguments can't be told apart by name, e.g. in `@db.Decimal(10, 2)`. Such directives are replaced as a
+    // whole.
+    if directive.has_several_unnamed_arguments() {
+        if !directives_are_identical(directive.previous, directive.next) {
+            push_deleted_directive(steps, location.clone(), directive.previous);
+            push_created_directive(steps, location, directive.next);
+        }
+
+        return;
+    }
+
     let locator = steps::DirectiveLocation {
         arguments: None,
         directive: directive.previous.name.name.clone(),
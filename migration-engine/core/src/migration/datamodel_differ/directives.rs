@@ -32,6 +32,19 @@ impl<'a> DirectiveDiffer<'a> {
         })
     }
 
+    /// Whether either side of the diff has more than one unnamed argument.
+    pub(crate) fn has_several_unnamed_arguments(&self) -> bool {
+        let count_unnamed = |directive: &ast::Directive| {
+            directive
+                .arguments
+                .iter()
+                .filter(|arg| arg.name.name.is_empty())
+                .count()
+        };
+
+        count_unnamed(self.previous) > 1 || count_unnamed(self.next) > 1
+    }
+
     fn previous_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
         self.previous.arguments.iter()
     }
@@ -41,6 +54,19 @@ impl<'a> DirectiveDiffer<'a> {
     }
 }
 
+/// The name in the database of a model or field: the argument of its `@map` or `@@map` directive, or its name.
+pub(crate) fn database_name<'a>(name: &'a ast::Identifier, directives: &'a [ast::Directive]) -> &'a str {
+    directives
+        .iter()
+        .find(|directive| directive.name.name == "map")
+        .and_then(|directive| directive.arguments.first())
+        .and_then(|argument| match &argument.value {
+            ast::Expression::StringValue(value, _) => Some(value.as_str()),
+            _ => None,
+        })
+        .unwrap_or(&name.name)
+}
+
 pub(crate) fn directives_match(previous: &ast::Directive, next: &ast::Directive) -> bool {
     previous.name.name == next.name.name
 }
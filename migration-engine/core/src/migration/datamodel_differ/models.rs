@@ -1,5 +1,5 @@
 use super::{
-    directives::{directives_are_identical, directives_match, DirectiveDiffer},
+    directives::{database_name, directives_are_identical, directives_match, DirectiveDiffer},
     FieldDiffer,
 };
 use datamodel::ast;
@@ -21,7 +21,7 @@ impl<'a> ModelDiffer<'a> {
     pub(crate) fn created_fields(&self) -> impl Iterator<Item = &ast::Field> {
         self.next_fields().filter(move |next_field| {
             self.previous_fields()
-                .find(|previous_field| fields_match(previous_field, next_field))
+                .find(|previous_field| self.fields_match(previous_field, next_field))
                 .is_none()
         })
     }
@@ -30,7 +30,7 @@ impl<'a> ModelDiffer<'a> {
     pub(crate) fn deleted_fields(&self) -> impl Iterator<Item = &ast::Field> {
         self.previous_fields().filter(move |previous_field| {
             self.next_fields()
-                .find(|next_field| fields_match(previous_field, next_field))
+                .find(|next_field| self.fields_match(previous_field, next_field))
                 .is_none()
         })
     }
@@ -39,7 +39,7 @@ impl<'a> ModelDiffer<'a> {
     pub(crate) fn field_pairs(&self) -> impl Iterator<Item = FieldDiffer<'_>> {
         self.previous_fields().filter_map(move |previous_field| {
             self.next_fields()
-                .find(|next_field| fields_match(previous_field, next_field))
+                .find(|next_field| self.fields_match(previous_field, next_field))
                 .map(|next_field| FieldDiffer {
                     previous: previous_field,
                     next: next_field,
@@ -94,6 +94,18 @@ impl<'a> ModelDiffer<'a> {
         })
     }
 
+    /// Fields match by name. A field whose name changed while its database name was preserved with `@map` is
+    /// renamed.
+    fn fields_match(&self, previous: &ast::Field, next: &ast::Field) -> bool {
+        if previous.name.name == next.name.name {
+            return true;
+        }
+
+        database_name(&previous.name, &previous.directives) == database_name(&next.name, &next.directives)
+            && self.next_fields().all(|field| field.name.name != previous.name.name)
+            && self.previous_fields().all(|field| field.name.name != next.name.name)
+    }
+
     fn previous_fields(&self) -> impl Iterator<Item = &ast::Field> {
         self.previous.fields.iter()
     }
@@ -119,10 +131,6 @@ impl<'a> ModelDiffer<'a> {
     }
 }
 
-fn fields_match(previous: &ast::Field, next: &ast::Field) -> bool {
-    previous.name.name == next.name.name
-}
-
 /// Model directives that can appear multiple times on the same model. Unlike others, they cannot be matched based only on the directive name.
 const REPEATED_MODEL_DIRECTIVES: &[&str] = &["unique", "index"];
 
@@ -1,4 +1,4 @@
-use super::{EnumDiffer, FieldDiffer, ModelDiffer};
+use super::{directives::database_name, EnumDiffer, FieldDiffer, ModelDiffer};
 use datamodel::ast::{self, Top};
 
 /// Implements the logic to diff top-level items in a pair of [Datamodel ASTs](/datamodel/ast/struct.Datamodel.html).
@@ -12,7 +12,7 @@ impl<'a> TopDiffer<'a> {
     pub(crate) fn model_pairs(&self) -> impl Iterator<Item = ModelDiffer<'_>> {
         self.previous_models().filter_map(move |previous_model| {
             self.next_models()
-                .find(|next_model| models_match(previous_model, next_model))
+                .find(|next_model| self.models_match(previous_model, next_model))
                 .map(|next_model| ModelDiffer {
                     previous: previous_model,
                     next: next_model,
@@ -24,7 +24,7 @@ impl<'a> TopDiffer<'a> {
     pub(crate) fn created_models(&self) -> impl Iterator<Item = &ast::Model> {
         self.next_models().filter(move |next_model| {
             self.previous_models()
-                .find(|previous_model| models_match(previous_model, next_model))
+                .find(|previous_model| self.models_match(previous_model, next_model))
                 .is_none()
         })
     }
@@ -33,7 +33,7 @@ impl<'a> TopDiffer<'a> {
     pub(crate) fn deleted_models(&self) -> impl Iterator<Item = &ast::Model> {
         self.previous_models().filter(move |previous_model| {
             self.next_models()
-                .find(|next_model| models_match(previous_model, next_model))
+                .find(|next_model| self.models_match(previous_model, next_model))
                 .is_none()
         })
     }
@@ -97,6 +97,18 @@ impl<'a> TopDiffer<'a> {
         })
     }
 
+    /// Models match by name. A model whose name changed while its database name was preserved with `@@map` is
+    /// renamed, e.g. `model User` becoming `model Account { @@map("User") }`.
+    fn models_match(&self, previous: &ast::Model, next: &ast::Model) -> bool {
+        if previous.name.name == next.name.name {
+            return true;
+        }
+
+        database_name(&previous.name, &previous.directives) == database_name(&next.name, &next.directives)
+            && self.next_models().all(|model| model.name.name != previous.name.name)
+            && self.previous_models().all(|model| model.name.name != next.name.name)
+    }
+
     /// Iterator over the models in `previous`.
     fn previous_models(&self) -> impl Iterator<Item = &ast::Model> {
         walk_models(self.previous)
@@ -140,10 +152,6 @@ fn walk_models(ast: &ast::SchemaAst) -> impl Iterator<Item = &ast::Model> {
     ast.tops.iter().filter_map(Top::as_model)
 }
 
-fn models_match(previous: &ast::Model, next: &ast::Model) -> bool {
-    previous.name.name == next.name.name
-}
-
 fn walk_type_aliases(ast: &ast::SchemaAst) -> impl Iterator<Item = &ast::Field> {
     ast.tops.iter().filter_map(Top::as_type_alias)
 }
@@ -32,6 +32,16 @@ where
         Ok(engine)
     }
 
+    /// Create an engine without initializing the connector, for commands that must not write anything to the
+    /// database, like rendering migration scripts.
+    pub fn new_uninitialized(connector: C) -> Self {
+        MigrationEngine {
+            datamodel_migration_steps_inferrer: Arc::new(DataModelMigrationStepsInferrerImplWrapper {}),
+            datamodel_calculator: Arc::new(DataModelCalculatorImpl),
+            connector,
+        }
+    }
+
     pub fn init(&self) -> CommandResult<()> {
         self.connector().initialize()?;
         Ok(())
@@ -101,6 +101,54 @@ fn infer_UpdateModel() {
     assert_eq!(steps, expected);
 }
 
+#[test]
+fn infer_UpdateModel_when_a_model_is_renamed_and_keeps_its_database_name() {
+    let dm1 = parse(
+        r#"
+        model User {
+            id Int @id
+        }
+    "#,
+    );
+
+    let dm2 = parse(
+        r#"
+        model Account {
+            id Int @id
+
+            @@map("User")
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+
+    let locator = DirectiveLocation {
+        directive: "map".to_owned(),
+        arguments: None,
+        location: DirectiveType::Model {
+            model: "Account".to_owned(),
+        },
+    };
+
+    let expected = &[
+        MigrationStep::UpdateModel(UpdateModel {
+            model: "User".to_owned(),
+            new_name: Some("Account".to_owned()),
+        }),
+        MigrationStep::CreateDirective(CreateDirective {
+            locator: locator.clone(),
+        }),
+        MigrationStep::CreateDirectiveArgument(CreateDirectiveArgument {
+            directive_location: locator,
+            argument: "".to_owned(),
+            value: MigrationExpression("\"User\"".to_owned()),
+        }),
+    ];
+
+    assert_eq!(steps, expected);
+}
+
 #[test]
 fn infer_CreateField_if_it_does_not_exist_yet() {
     let dm1 = parse(
@@ -319,6 +367,75 @@ fn infer_UpdateField_simple() {
     assert_eq!(steps, expected);
 }
 
+#[test]
+fn infer_UpdateField_when_a_field_is_renamed_and_keeps_its_database_name() {
+    let dm1 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            name String @map("full_name")
+        }
+    "#,
+    );
+
+    let dm2 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            fullName String @map("full_name")
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+    let expected = &[MigrationStep::UpdateField(UpdateField {
+        model: "Test".to_owned(),
+        field: "name".to_owned(),
+        new_name: Some("fullName".to_owned()),
+        tpe: None,
+        arity: None,
+    })];
+
+    assert_eq!(steps, expected);
+}
+
+#[test]
+fn infer_DeleteField_and_CreateField_when_the_database_name_changes_with_the_name() {
+    let dm1 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            name String
+        }
+    "#,
+    );
+
+    let dm2 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            fullName String
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+    let expected = &[
+        MigrationStep::CreateField(CreateField {
+            model: "Test".to_owned(),
+            field: "fullName".to_owned(),
+            tpe: "String".to_owned(),
+            arity: FieldArity::Required,
+        }),
+        MigrationStep::DeleteField(DeleteField {
+            model: "Test".to_owned(),
+            field: "name".to_owned(),
+        }),
+    ];
+
+    assert_eq!(steps, expected);
+}
+
 #[test]
 fn infer_CreateEnum() {
     let dm1 = SchemaAst::empty();
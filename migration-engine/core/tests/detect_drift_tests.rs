@@ -0,0 +1,316 @@
+mod test_harness;
+
+use barrel::types;
+use migration_connector::DriftKind;
+use pretty_assertions::assert_eq;
+use quaint::prelude::SqlFamily;
+use test_harness::*;
+
+#[test_each_connector]
+async fn no_drift_must_be_reported_for_a_migrated_database(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+            title String @unique
+            posts Post[]
+        }
+
+        model Post {
+            id Int @id
+            blog Blog
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+
+    let output = api.detect_drift();
+
+    assert_eq!(output.drifts, vec![]);
+    assert!(!output.has_drift);
+}
+
+#[test_each_connector]
+async fn tables_created_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+    api.barrel().execute(|migration| {
+        migration.create_table("Hotfix", |t| {
+            t.add_column("id", types::primary());
+        });
+    });
+
+    let output = api.detect_drift();
+
+    assert!(output.has_drift);
+    assert_eq!(output.drifts.len(), 1);
+    assert_eq!(output.drifts[0].kind, DriftKind::ExtraTable);
+    assert_eq!(output.drifts[0].table, "Hotfix");
+    assert_eq!(output.drifts[0].name, None);
+}
+
+#[test_each_connector]
+async fn tables_dropped_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+        }
+
+        model Post {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+    api.barrel().execute(|migration| {
+        migration.drop_table("Post");
+    });
+
+    let output = api.detect_drift();
+
+    assert_eq!(output.drifts.len(), 1);
+    assert_eq!(output.drifts[0].kind, DriftKind::MissingTable);
+    assert_eq!(output.drifts[0].table, "Post");
+}
+
+#[test_each_connector]
+async fn columns_added_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+    api.barrel().execute(|migration| {
+        migration.change_table("Blog", |t| {
+            t.add_column("hotfix", types::text().nullable(true));
+        });
+    });
+
+    let output = api.detect_drift();
+
+    assert_eq!(output.drifts.len(), 1);
+    assert_eq!(output.drifts[0].kind, DriftKind::ExtraColumn);
+    assert_eq!(output.drifts[0].table, "Blog");
+    assert_eq!(output.drifts[0].name.as_ref().map(String::as_str), Some("hotfix"));
+    assert_eq!(
+        output.drifts[0].description,
+        "The column `hotfix` on the table `Blog` exists in the database, but not in the migration history."
+    );
+}
+
+#[test_each_connector]
+async fn indexes_dropped_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+            title String @unique
+        }
+    "#;
+
+    let schema = api.infer_and_apply(dm).await.sql_schema;
+    let index_name = schema
+        .table_bang("Blog")
+        .indices
+        .iter()
+        .find(|index| index.columns == &["title"])
+        .unwrap()
+        .name
+        .clone();
+
+    let drop_index = match api.sql_family() {
+        SqlFamily::Mysql => format!("DROP INDEX `{}` ON `{}`.`Blog`", index_name, SCHEMA_NAME),
+        _ => format!("DROP INDEX \"{}\".\"{}\"", SCHEMA_NAME, index_name),
+    };
+    api.database().query_raw(&drop_index, &[]).unwrap();
+
+    let output = api.detect_drift();
+
+    assert_eq!(output.drifts.len(), 1);
+    assert_eq!(output.drifts[0].kind, DriftKind::MissingIndex);
+    assert_eq!(output.drifts[0].table, "Blog");
+    assert_eq!(output.drifts[0].name, Some(index_name));
+}
+
+#[test_each_connector]
+async fn indexes_created_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+            views Int
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+
+    let create_index = match api.sql_family() {
+        SqlFamily::Mysql => format!("CREATE INDEX `hotfix` ON `{}`.`Blog` (`views`)", SCHEMA_NAME),
+        SqlFamily::Postgres => format!("CREATE INDEX \"hotfix\" ON \"{}\".\"Blog\" (\"views\")", SCHEMA_NAME),
+        SqlFamily::Sqlite => format!("CREATE INDEX \"{}\".\"hotfix\" ON \"Blog\" (\"views\")", SCHEMA_NAME),
+    };
+    api.database().query_raw(&create_index, &[]).unwrap();
+
+    let output = api.detect_drift();
+
+    assert_eq!(output.drifts.len(), 1);
+    assert_eq!(output.drifts[0].kind, DriftKind::ExtraIndex);
+    assert_eq!(output.drifts[0].table, "Blog");
+    assert_eq!(output.drifts[0].name.as_ref().map(String::as_str), Some("hotfix"));
+    assert_eq!(
+        output.drifts[0].description,
+        "The index `hotfix` on the table `Blog` exists in the database, but not in the migration history."
+    );
+}
+
+#[test_each_connector]
+async fn indexes_renamed_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+            title String @unique
+        }
+    "#;
+
+    let schema = api.infer_and_apply(dm).await.sql_schema;
+    let index_name = schema
+        .table_bang("Blog")
+        .indices
+        .iter()
+        .find(|index| index.columns == &["title"])
+        .unwrap()
+        .name
+        .clone();
+
+    // SQLite can't rename indexes, the index is recreated under the new name instead.
+    let statements = match api.sql_family() {
+        SqlFamily::Mysql => vec![format!(
+            "ALTER TABLE `{}`.`Blog` RENAME INDEX `{}` TO `renamed`",
+            SCHEMA_NAME, index_name
+        )],
+        SqlFamily::Postgres => vec![format!(
+            "ALTER INDEX \"{}\".\"{}\" RENAME TO \"renamed\"",
+            SCHEMA_NAME, index_name
+        )],
+        SqlFamily::Sqlite => vec![
+            format!("DROP INDEX \"{}\".\"{}\"", SCHEMA_NAME, index_name),
+            format!(
+                "CREATE UNIQUE INDEX \"{}\".\"renamed\" ON \"Blog\" (\"title\")",
+                SCHEMA_NAME
+            ),
+        ],
+    };
+
+    for statement in statements {
+        api.database().query_raw(&statement, &[]).unwrap();
+    }
+
+    let output = api.detect_drift();
+
+    assert_eq!(output.drifts.len(), 1);
+    assert_eq!(output.drifts[0].kind, DriftKind::RenamedIndex);
+    assert_eq!(output.drifts[0].table, "Blog");
+    assert_eq!(
+        output.drifts[0].description,
+        format!(
+            "The index `{}` on the table `Blog` is named `renamed` in the database.",
+            index_name
+        )
+    );
+    assert_eq!(output.drifts[0].name, Some(index_name));
+}
+
+// SQLite can't add or drop foreign keys on existing tables.
+#[test_each_connector(ignore = "sqlite")]
+async fn foreign_keys_dropped_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+            posts Post[]
+        }
+
+        model Post {
+            id Int @id
+            blog Blog
+        }
+    "#;
+
+    let schema = api.infer_and_apply(dm).await.sql_schema;
+    let constraint_name = schema.table_bang("Post").foreign_keys[0]
+        .constraint_name
+        .clone()
+        .unwrap();
+
+    let drop_foreign_key = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "ALTER TABLE `{}`.`Post` DROP FOREIGN KEY `{}`",
+            SCHEMA_NAME, constraint_name
+        ),
+        _ => format!(
+            "ALTER TABLE \"{}\".\"Post\" DROP CONSTRAINT \"{}\"",
+            SCHEMA_NAME, constraint_name
+        ),
+    };
+    api.database().query_raw(&drop_foreign_key, &[]).unwrap();
+
+    let output = api.detect_drift();
+    let drift = output
+        .drifts
+        .iter()
+        .find(|drift| drift.kind == DriftKind::MissingForeignKey)
+        .unwrap();
+
+    assert!(output.has_drift);
+    assert_eq!(drift.table, "Post");
+    assert_eq!(
+        drift.description,
+        "The foreign key on `blog` of the table `Post`, referencing `Blog`, is missing from the database."
+    );
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn foreign_keys_created_by_hand_must_be_reported(api: &TestApi) {
+    let dm = r#"
+        model Blog {
+            id Int @id
+        }
+
+        model Post {
+            id Int @id
+            blogId Int
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+
+    let add_foreign_key = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "ALTER TABLE `{0}`.`Post` ADD CONSTRAINT `hotfix` FOREIGN KEY (`blogId`) REFERENCES `{0}`.`Blog` (`id`)",
+            SCHEMA_NAME
+        ),
+        _ => format!(
+            "ALTER TABLE \"{0}\".\"Post\" ADD CONSTRAINT \"hotfix\" FOREIGN KEY (\"blogId\") REFERENCES \"{0}\".\"Blog\" (\"id\")",
+            SCHEMA_NAME
+        ),
+    };
+    api.database().query_raw(&add_foreign_key, &[]).unwrap();
+
+    let output = api.detect_drift();
+    let drift = output
+        .drifts
+        .iter()
+        .find(|drift| drift.kind == DriftKind::ExtraForeignKey)
+        .unwrap();
+
+    assert!(output.has_drift);
+    assert_eq!(drift.table, "Post");
+    assert_eq!(drift.name.as_ref().map(String::as_str), Some("hotfix"));
+    assert_eq!(
+        drift.description,
+        "The foreign key on `blogId` of the table `Post`, referencing `Blog`, exists in the database, but not in the migration history."
+    );
+}
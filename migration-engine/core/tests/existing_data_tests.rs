@@ -1,8 +1,10 @@
 mod test_harness;
 
-use migration_connector::MigrationWarning;
+use barrel::types;
+use migration_connector::{Backfill, MigrationError, MigrationScript, MigrationStatus, MigrationWarning};
 use pretty_assertions::assert_eq;
 use quaint::ast::*;
+use sql_migration_connector::SqlMigrationStep;
 use test_harness::*;
 
 #[test_each_connector]
@@ -38,7 +40,19 @@ async fn adding_a_required_field_if_there_is_data(api: &TestApi) {
                 A
             }
         "#;
-    api.infer_and_apply(&dm).await;
+
+    // The rows need values for the new columns, so the migration waits for the user's confirmation.
+    let result = api.infer_and_apply(&dm).await;
+    assert_eq!(result.migration_output.warnings.len(), 6);
+    assert!(result.sql_schema.table_bang("Test").column("myint").is_none());
+
+    // Forcing the migration backfills the rows with the zero values of the column types.
+    api.infer_and_apply_forcefully(&dm).await;
+
+    let query = Select::from_table((SCHEMA_NAME, "Test"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["myint"].as_i64().unwrap(), 0);
+    assert_eq!(row["string"].as_str().unwrap(), "");
 }
 
 #[test_each_connector]
@@ -73,6 +87,154 @@ async fn adding_a_required_field_must_use_the_default_value_for_migrations(api:
                 // enum MyEnum @default(C)
             }
 
+            enum MyEnum {
+                B
+                A
+                C
+            }
+        "#;
+
+    // The columns with a default fill the rows themselves, the date time needs the user's confirmation.
+    let result = api.infer_and_apply(&dm).await;
+    let warnings: Vec<&str> = result
+        .migration_output
+        .warnings
+        .iter()
+        .map(|warning| warning.description.as_str())
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("You are about to add the required column `dateTime`"));
+    assert!(result.sql_schema.table_bang("Test").column("myint").is_none());
+
+    api.infer_and_apply_forcefully(&dm).await;
+
+    let conditions = "id".equals("test");
+    let table_for_select: Table = (SCHEMA_NAME, "Test").into();
+    let query = Select::from_table(table_for_select).so_that(conditions);
+    let result_set = conn.query(query.into()).unwrap();
+    let row = result_set.into_iter().next().expect("query returned no results");
+    assert_eq!(row["myint"].as_i64().unwrap(), 1);
+    assert_eq!(row["string"].as_str().unwrap(), "test_string");
+    if let ParameterizedValue::Null = row["dateTime"] {
+        panic!("The dateTime column was not backfilled.");
+    }
+}
+
+#[test_each_connector]
+async fn adding_a_required_field_must_use_the_backfill_expression(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "a")
+        .value("name", "Rex");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String
+            slug String
+        }
+    "#;
+
+    let script = MigrationScript {
+        backfills: vec![Backfill {
+            table: "Test".to_owned(),
+            column: "slug".to_owned(),
+            expression: "upper(name)".to_owned(),
+        }],
+        ..MigrationScript::default()
+    };
+
+    let result = api.infer_and_apply_with_script(dm2, script, None).await;
+    assert!(result.migration_output.warnings.is_empty());
+
+    let query = Select::from_table((SCHEMA_NAME, "Test"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["slug"].as_str().unwrap(), "REX");
+    assert!(result.sql_schema.table_bang("Test").column_bang("slug").is_required());
+}
+
+#[test_each_connector]
+async fn changing_the_default_of_a_field_must_alter_the_column_default(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id    String @id
+            name  String @default("a")
+            count Int    @default(1)
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    // The described defaults match the datamodel, so there is nothing left to migrate.
+    let output = api.infer_and_apply(dm1).await;
+    assert!(output.sql_migration().is_empty());
+
+    let dm2 = r#"
+        model Test {
+            id    String @id
+            name  String @default("b")
+            count Int
+        }
+    "#;
+
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.migration_output.warnings.is_empty());
+    assert!(!output.sql_migration().is_empty());
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "a")
+        .value("count", 3);
+    api.database().execute(insert.into()).unwrap();
+
+    let query = Select::from_table((SCHEMA_NAME, "Test"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["name"].as_str().unwrap(), "b");
+
+    let output = api.infer_and_apply(dm2).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn adding_a_required_field_must_use_the_default_value_for_migrations(api: &TestApi) {
+    let dm = r#"
+            model Test {
+                id String @id @default(cuid())
+            }
+
+            enum MyEnum {
+                B
+                A
+            }
+        "#;
+    api.infer_and_apply(&dm).await;
+
+    let conn = api.database();
+    let insert = Insert::single_into((SCHEMA_NAME, "Test")).value("id", "test");
+
+    conn.execute(insert.into()).unwrap();
+
+    let dm = r#"
+            model Test {
+                id String @id @default(cuid())
+                myint Int @default(1)
+                myfloat Float @default(2)
+                boolean Boolean @default(true)
+                string String @default("test_string")
+                dateTime DateTime?
+                // TODO: Currently failing because of ambiguity concerning expressions. Pending on
+                // spec work.
+                // enum MyEnum @default(C)
+            }
+
             enum MyEnum {
                 B
                 A
@@ -239,3 +401,502 @@ async fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) {
         }]
     );
 }
+
+#[test_each_connector(ignore = "sqlite")]
+async fn removing_an_enum_variant_that_is_in_use_should_warn(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+            HAPPY
+        }
+    "#;
+
+    let original_database_schema = api.infer_and_apply(&dm).await.sql_schema;
+
+    // Postgres does not accept text parameters for enum columns, so the values are inlined.
+    let insert = match api.sql_family() {
+        quaint::prelude::SqlFamily::Mysql => format!(
+            "INSERT INTO `{}`.`Cat` (id, mood) VALUES ('felix', 'HUNGRY'), ('garfield', 'HAPPY')",
+            SCHEMA_NAME
+        ),
+        _ => format!(
+            "INSERT INTO \"{}\".\"Cat\" (id, mood) VALUES ('felix', 'HUNGRY'), ('garfield', 'HAPPY')",
+            SCHEMA_NAME
+        ),
+    };
+
+    api.database().query_raw(&insert, &[]).unwrap();
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm2).await;
+
+    assert_eq!(original_database_schema, result.sql_schema);
+
+    let enum_name = match api.sql_family() {
+        quaint::prelude::SqlFamily::Mysql => "Cat_mood",
+        _ => "CatMood",
+    };
+
+    assert_eq!(
+        result.migration_output.warnings,
+        &[MigrationWarning {
+            description: format!(
+                "You are about to remove the variants [\"HUNGRY\"] from the enum `{}`, which are still used by 1 rows in the column `mood` on the `Cat` table.",
+                enum_name
+            ),
+        }]
+    );
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn narrowing_the_type_of_a_column_with_non_null_values_should_warn(api: &TestApi) {
+    let original_database_schema = api.barrel().execute(|migration| {
+        migration.create_table("Test", |t| {
+            t.add_column("id", types::primary());
+            t.add_column("amount", types::custom("BIGINT").nullable(true));
+        });
+    });
+
+    let insert = Insert::multi_into((SCHEMA_NAME, "Test"), vec!["id", "amount"])
+        .values((1, 12))
+        .values((2, 22));
+
+    api.database().execute(insert.into()).unwrap();
+
+    let (native_type, previous_type, next_type) = match api.sql_family() {
+        quaint::prelude::SqlFamily::Postgres => ("Integer", "int8", "int4"),
+        _ => ("Int", "bigint", "int"),
+    };
+
+    let dm = format!(
+        r#"
+        model Test {{
+            id Int @id
+            amount Int? @db.{}
+        }}
+    "#,
+        native_type
+    );
+
+    let result = api.infer_and_apply(&dm).await;
+
+    // The schema should not change because the migration should not run if there are warnings
+    // and the force flag isn't passed.
+    assert_eq!(original_database_schema, result.sql_schema);
+
+    assert_eq!(
+        result.migration_output.warnings,
+        &[MigrationWarning {
+            description: format!(
+                "You are about to change the type of the column `amount` on the `Test` table from `{}` to `{}`. \
+                 2 non-null values in that column may not fit the new type.",
+                previous_type, next_type
+            ),
+        }]
+    );
+}
+
+#[test_each_connector]
+async fn a_failing_migration_must_not_leave_the_database_half_migrated(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let conn = api.database();
+    for id in &["a", "b"] {
+        let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+            .value("id", *id)
+            .value("name", "duplicate");
+        conn.execute(insert.into()).unwrap();
+    }
+
+    // The new table can be created, but the unique index fails on the duplicate names.
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String @unique
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    assert!(api.try_infer_and_apply(dm2).await.is_err());
+
+    // Postgres and SQLite roll back the transaction, MySQL reverts the applied steps.
+    let sql_schema = api.introspect_database();
+    assert!(sql_schema.table("Dog").is_err());
+    assert!(sql_schema.table_bang("Test").indices.is_empty());
+
+    let migrations = api.migration_persistence().load_all();
+    assert_eq!(migrations.last().unwrap().status, MigrationStatus::MigrationFailure);
+}
+
+#[test_one_connector(connector = "mysql")]
+async fn a_migration_failing_midway_on_mysql_must_only_revert_the_applied_steps(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    for id in &["a", "b"] {
+        let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+            .value("id", *id)
+            .value("name", "duplicate");
+        api.database().execute(insert.into()).unwrap();
+    }
+
+    // The table and the column are added, the first unique index fails, and the second one is never created.
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String @unique
+            age  Int?
+        }
+
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    assert!(api.try_infer_and_apply(dm2).await.is_err());
+
+    let sql_schema = api.introspect_database();
+    let table = sql_schema.table_bang("Test");
+    assert!(sql_schema.table("Dog").is_err());
+    assert!(!table.has_column("age"));
+    assert!(table.indices.is_empty());
+    assert!(sql_schema.table_bang("Cat").indices.is_empty());
+
+    // Only the failing step is reported, nothing went wrong while reverting.
+    let migration = api.migration_persistence().load_all().pop().unwrap();
+    assert_eq!(migration.status, MigrationStatus::MigrationFailure);
+    assert_eq!(migration.errors.len(), 1);
+}
+
+#[test_one_connector(connector = "sqlite")]
+async fn rows_violating_a_foreign_key_after_a_table_rebuild_must_roll_back_the_migration(api: &TestApi) {
+    let dm1 = r#"
+        model A {
+            id Int @id
+            b  Int
+        }
+
+        model B {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let insert = Insert::single_into((SCHEMA_NAME, "A")).value("id", 1).value("b", 2);
+    api.database().execute(insert.into()).unwrap();
+
+    // Adding the foreign key rebuilds the table, and row 1 references a B that does not exist.
+    let dm2 = r#"
+        model A {
+            id Int @id
+            b  B   @relation(references: [id])
+        }
+
+        model B {
+            id Int @id
+            a  A
+        }
+    "#;
+
+    let output = api.try_infer_and_apply(dm2).await.unwrap();
+
+    assert_eq!(
+        output.errors,
+        &[MigrationError {
+            tpe: "ForeignKeyViolation".to_owned(),
+            description: "The row with rowid 1 in table `A` references a row in table `B` that does not exist."
+                .to_owned(),
+            field: None,
+        }]
+    );
+
+    let sql_schema = api.introspect_database();
+    assert!(sql_schema.table_bang("A").foreign_keys.is_empty());
+
+    let migrations = api.migration_persistence().load_all();
+    assert_eq!(migrations.last().unwrap().status, MigrationStatus::MigrationFailure);
+}
+
+#[test_each_connector]
+async fn changing_the_database_name_of_a_model_must_rename_the_table(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "a")
+        .value("name", "Rex");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String
+
+            @@map("tests")
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm2).await;
+
+    assert!(result.migration_output.warnings.is_empty());
+    assert_eq!(
+        result.sql_migration()[0],
+        SqlMigrationStep::RenameTable {
+            name: "Test".to_owned(),
+            new_name: "tests".to_owned(),
+        }
+    );
+    assert!(result.sql_schema.table("Test").is_err());
+
+    let query = Select::from_table((SCHEMA_NAME, "tests"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["name"].as_str().unwrap(), "Rex");
+}
+
+#[test_each_connector]
+async fn changing_the_database_name_of_a_field_must_rename_the_column(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "a")
+        .value("name", "Rex");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String @map("full_name")
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm2).await;
+
+    assert!(result.migration_output.warnings.is_empty());
+    match &result.sql_migration()[0] {
+        SqlMigrationStep::RenameColumn(rename_column) => {
+            assert_eq!(rename_column.table, "Test");
+            assert_eq!(rename_column.name, "name");
+            assert_eq!(rename_column.column.name, "full_name");
+        }
+        step => panic!("Expected a RenameColumn step, got {:?}", step),
+    }
+
+    let query = Select::from_table((SCHEMA_NAME, "Test"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["full_name"].as_str().unwrap(), "Rex");
+}
+
+#[test_one_connector(connector = "mysql")]
+async fn renaming_a_column_on_mysql_must_keep_its_type(api: &TestApi) {
+    api.barrel().execute(|migration| {
+        migration.create_table("Test", |t| {
+            t.add_column("id", types::primary());
+            t.add_column("name", types::varchar(50));
+        });
+    });
+
+    let dm1 = r#"
+        model Test {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    // MySQL renames a column along with its definition, in both directions.
+    let dm2 = r#"
+        model Test {
+            id   Int    @id
+            name String @map("full_name")
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm2).await.sql_schema;
+    let column = result.table_bang("Test").column_bang("full_name");
+    assert_eq!(column.tpe.raw, "varchar(50)");
+
+    let result = api.unapply_migration().await.sql_schema;
+    let column = result.table_bang("Test").column_bang("name");
+    assert_eq!(column.tpe.raw, "varchar(50)");
+}
+
+#[test_each_connector]
+async fn renaming_a_model_and_a_field_that_keep_their_database_names_must_not_touch_the_data(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Test"))
+        .value("id", "a")
+        .value("name", "Rex");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Dog {
+            id       String @id
+            nickname String @map("name")
+
+            @@map("Test")
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm2).await;
+
+    assert!(result.migration_output.warnings.is_empty());
+    assert!(result.sql_migration().is_empty());
+
+    let query = Select::from_table((SCHEMA_NAME, "Test"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["name"].as_str().unwrap(), "Rex");
+}
+
+#[test_each_connector]
+async fn a_migration_script_must_backfill_split_columns_and_restore_them_on_unapply(api: &TestApi) {
+    let dm1 = r#"
+        model Person {
+            id   String  @id
+            name String?
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Person"))
+        .value("id", "a")
+        .value("name", "Ada Lovelace");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm2 = r#"
+        model Person {
+            id        String  @id
+            firstName String?
+            lastName  String?
+        }
+    "#;
+
+    let (quote, first_name, last_name, full_name) = match api.sql_family() {
+        quaint::prelude::SqlFamily::Postgres => (
+            "\"",
+            r#"split_part("name", ' ', 1)"#,
+            r#"split_part("name", ' ', 2)"#,
+            r#""firstName" || ' ' || "lastName""#,
+        ),
+        quaint::prelude::SqlFamily::Mysql => (
+            "`",
+            "SUBSTRING_INDEX(`name`, ' ', 1)",
+            "SUBSTRING_INDEX(`name`, ' ', -1)",
+            "CONCAT(`firstName`, ' ', `lastName`)",
+        ),
+        quaint::prelude::SqlFamily::Sqlite => (
+            "\"",
+            r#"substr("name", 1, instr("name", ' ') - 1)"#,
+            r#"substr("name", instr("name", ' ') + 1)"#,
+            r#""firstName" || ' ' || "lastName""#,
+        ),
+    };
+    let table = format!("{q}{}{q}.{q}Person{q}", SCHEMA_NAME, q = quote);
+
+    let script = MigrationScript {
+        before_drops: Some(format!(
+            "UPDATE {} SET {q}firstName{q} = {},\n    {q}lastName{q} = {};",
+            table,
+            first_name,
+            last_name,
+            q = quote
+        )),
+        down: Some(format!("UPDATE {} SET {q}name{q} = {};", table, full_name, q = quote)),
+        ..MigrationScript::default()
+    };
+
+    // Dropping the column loses its data, whatever the script moved out of it.
+    let result = api.infer_and_apply_with_script(dm2, script.clone(), None).await;
+
+    assert_eq!(
+        result.migration_output.warnings,
+        &[MigrationWarning {
+            description: "You are about to drop the column `name` on the `Person` table, which still contains 1 \
+                          non-null values."
+                .to_owned(),
+        }]
+    );
+    assert!(result.sql_schema.table_bang("Person").has_column("name"));
+
+    let result = api.infer_and_apply_with_script(dm2, script.clone(), Some(true)).await;
+
+    assert!(result.migration_output.errors.is_empty());
+    assert_eq!(api.migration_persistence().last().unwrap().script, script);
+
+    let query = Select::from_table((SCHEMA_NAME, "Person"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["firstName"].as_str().unwrap(), "Ada");
+    assert_eq!(row["lastName"].as_str().unwrap(), "Lovelace");
+
+    let result = api.unapply_migration().await;
+
+    assert!(result.output.errors.is_empty());
+
+    let query = Select::from_table((SCHEMA_NAME, "Person"));
+    let row = api.database().query(query.into()).unwrap().into_iter().next().unwrap();
+    assert_eq!(row["name"].as_str().unwrap(), "Ada Lovelace");
+}
@@ -5,6 +5,7 @@ use barrel::{types, Migration, SqlVariant};
 use migration_core::api::GenericApi;
 use pretty_assertions::{assert_eq, assert_ne};
 use sql_connection::SyncSqlConnection;
+use quaint::ast::{Insert, Select};
 use quaint::prelude::SqlFamily;
 use sql_migration_connector::SqlMigrationConnector;
 use sql_schema_describer::*;
@@ -109,6 +110,45 @@ async fn creating_a_field_for_an_existing_column_and_changing_its_type_must_work
     assert_eq!(index.unwrap().tpe, IndexType::Unique);
 }
 
+#[test_each_connector(ignore = "sqlite")]
+async fn changing_the_type_of_an_existing_column_within_its_family_must_keep_its_data(api: &TestApi) {
+    let is_mysql = api.sql_family() == SqlFamily::Mysql;
+    let initial_result = api.barrel().execute(|migration| {
+        migration.create_table("Blog", |t| {
+            t.add_column("id", types::primary());
+            if is_mysql {
+                t.add_column("title", types::custom("TINYTEXT"));
+            } else {
+                t.add_column("title", types::varchar(191));
+            }
+        });
+    });
+    let initial_column = initial_result.table_bang("Blog").column_bang("title");
+    assert_eq!(initial_column.tpe.family, ColumnTypeFamily::String);
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Blog"))
+        .value("id", 1)
+        .value("title", "Type changes");
+    api.database().execute(insert.into()).unwrap();
+
+    let dm = r#"
+            model Blog {
+                id Int @id
+                title String @db.Text
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.migration_output.warnings.is_empty());
+
+    let column = result.sql_schema.table_bang("Blog").column_bang("title");
+    assert_eq!(column.tpe.raw, "text");
+
+    let query = Select::from_table((SCHEMA_NAME, "Blog")).column("title");
+    let result_set = api.database().query(query.into()).unwrap();
+    let row = result_set.into_iter().next().expect("query returned no results");
+    assert_eq!(row["title"].as_str().unwrap(), "Type changes");
+}
+
 #[test_each_connector]
 async fn creating_a_field_for_an_existing_column_and_simultaneously_making_it_optional(api: &TestApi) {
     let initial_result = api.barrel().execute(|migration| {
@@ -313,3 +353,210 @@ async fn renaming_a_field_where_the_column_was_already_renamed_must_work(api: &T
     assert_eq!(final_column.tpe.family, ColumnTypeFamily::Float);
     assert_eq!(final_result.table_bang("Blog").column("title").is_some(), false);
 }
+
+#[test_each_connector]
+async fn migrations_must_leave_unmanaged_views_alone(api: &TestApi) {
+    let dm1 = r#"
+            model Blog {
+                id Int @id
+            }
+        "#;
+    api.infer_and_apply(&dm1).await;
+
+    let create_view = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "CREATE VIEW `{schema}`.`BlogIds` AS SELECT id FROM `{schema}`.`Blog`",
+            schema = SCHEMA_NAME
+        ),
+        SqlFamily::Postgres => format!(
+            "CREATE VIEW \"{schema}\".\"BlogIds\" AS SELECT id FROM \"{schema}\".\"Blog\"",
+            schema = SCHEMA_NAME
+        ),
+        SqlFamily::Sqlite => format!(
+            "CREATE VIEW \"{schema}\".\"BlogIds\" AS SELECT id FROM \"Blog\"",
+            schema = SCHEMA_NAME
+        ),
+    };
+    api.database().query_raw(&create_view, &[]).unwrap();
+
+    let dm2 = r#"
+            model Blog {
+                id Int @id
+            }
+
+            model Post {
+                id Int @id
+            }
+        "#;
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+
+    assert!(result.has_table("Post"));
+    assert!(!result.has_table("BlogIds"));
+    assert!(result.get_view("BlogIds").is_some());
+}
+
+#[test_one_connector(connector = "sqlite")]
+async fn rebuilding_a_table_on_sqlite_must_keep_its_check_constraints(api: &TestApi) {
+    let create_table = format!(
+        r#"CREATE TABLE "{schema}"."Product" (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL,
+            discount INTEGER,
+            CONSTRAINT "positive_price" CHECK (price > 0),
+            CHECK (discount < 100)
+        )"#,
+        schema = SCHEMA_NAME
+    );
+    api.database().query_raw(&create_table, &[]).unwrap();
+
+    // Dropping a column rebuilds the table. The check constraint on the dropped column goes with it.
+    let dm = r#"
+            model Product {
+                id    Int @id
+                price Int
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    let table = result.table_bang("Product");
+
+    assert!(!table.has_column("discount"));
+    assert_eq!(
+        table.check_constraints,
+        vec![CheckConstraint {
+            name: Some("positive_price".to_owned()),
+            expression: "price > 0".to_owned(),
+        }]
+    );
+}
+
+#[test_one_connector(connector = "mysql")]
+async fn changing_a_column_type_on_mysql_must_keep_its_comment(api: &TestApi) {
+    let create_table = format!(
+        "CREATE TABLE `{schema}`.`Product` (id INTEGER PRIMARY KEY, name VARCHAR(100) NOT NULL COMMENT 'The display name')",
+        schema = SCHEMA_NAME
+    );
+    api.database().query_raw(&create_table, &[]).unwrap();
+
+    let dm = r#"
+            model Product {
+                id   Int    @id
+                name String @db.Text
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    let column = result.table_bang("Product").column_bang("name");
+
+    assert_eq!(column.tpe.raw, "text");
+    assert_eq!(column.comment.as_ref().map(String::as_str), Some("The display name"));
+}
+
+#[test_one_connector(connector = "sqlite")]
+async fn columns_with_the_type_affinity_of_their_field_must_not_be_migrated_on_sqlite(api: &TestApi) {
+    let create_table = format!(
+        r#"CREATE TABLE "{schema}"."Blog" (id INTEGER PRIMARY KEY, views BIGINT NOT NULL)"#,
+        schema = SCHEMA_NAME
+    );
+    api.database().query_raw(&create_table, &[]).unwrap();
+
+    let insert = Insert::single_into((SCHEMA_NAME, "Blog"))
+        .value("id", 1)
+        .value("views", 1000);
+    api.database().execute(insert.into()).unwrap();
+
+    let dm = r#"
+            model Blog {
+                id    Int @id
+                views Int
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+
+    assert!(result.migration_output.warnings.is_empty());
+    assert!(result.sql_migration().is_empty());
+
+    let column = result.sql_schema.table_bang("Blog").column_bang("views");
+    assert_eq!(column.tpe.raw, "BIGINT");
+}
+
+#[test_each_connector]
+async fn exact_numeric_columns_must_not_be_migrated_for_decimal_fields(api: &TestApi) {
+    api.barrel().execute(|migration| {
+        migration.create_table("Invoice", |t| {
+            t.add_column("id", types::primary());
+            t.add_column("amount", types::custom("DECIMAL(10,2)"));
+        });
+    });
+
+    let dm = r#"
+            model Invoice {
+                id     Int     @id
+                amount Decimal
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+
+    assert!(result.migration_output.warnings.is_empty());
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn zero_value_defaults_of_earlier_migration_engines_must_not_be_migrated(api: &TestApi) {
+    // The tables created by earlier versions of the migration engine, which gave every required column the zero
+    // value of its type as default.
+    let (quote, string, int, float, boolean, date_time) = match api.sql_family() {
+        SqlFamily::Postgres => ("\"", "text", "integer", "Decimal(65,30)", "boolean", "timestamp(3)"),
+        SqlFamily::Mysql => ("`", "varchar(191)", "int", "Decimal(65,30)", "boolean", "datetime(3)"),
+        SqlFamily::Sqlite => ("\"", "TEXT", "INTEGER", "REAL", "BOOLEAN", "DATE"),
+    };
+    let create_table = format!(
+        r#"CREATE TABLE {q}{schema}{q}.{q}Test{q} (
+            {q}id{q} {string} NOT NULL,
+            {q}name{q} {string} NOT NULL DEFAULT '',
+            {q}count{q} {int} NOT NULL DEFAULT 0,
+            {q}price{q} {float} NOT NULL DEFAULT 0,
+            {q}active{q} {boolean} NOT NULL DEFAULT false,
+            {q}createdAt{q} {date_time} NOT NULL DEFAULT '1970-01-01 00:00:00',
+            PRIMARY KEY ({q}id{q})
+        )"#,
+        q = quote,
+        schema = SCHEMA_NAME,
+        string = string,
+        int = int,
+        float = float,
+        boolean = boolean,
+        date_time = date_time,
+    );
+    api.database().query_raw(&create_table, &[]).unwrap();
+
+    let dm = r#"
+            model Test {
+                id        String   @id
+                name      String
+                count     Int
+                price     Float
+                active    Boolean
+                createdAt DateTime
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+
+    assert!(result.migration_output.warnings.is_empty());
+    assert!(result.sql_migration().is_empty());
+
+    // A default declared in the datamodel is still migrated.
+    let dm = r#"
+            model Test {
+                id        String   @id
+                name      String
+                count     Int      @default(1)
+                price     Float
+                active    Boolean
+                createdAt DateTime
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+
+    assert!(!result.sql_migration().is_empty());
+    let column = result.sql_schema.table_bang("Test").column_bang("count");
+    assert_eq!(column.default.as_ref().map(String::as_str), Some("1"));
+}
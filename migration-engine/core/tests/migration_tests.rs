@@ -34,7 +34,17 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) {
     assert_eq!(table.column_bang("boolean").tpe.family, ColumnTypeFamily::Boolean);
     assert_eq!(table.column_bang("string").tpe.family, ColumnTypeFamily::String);
     assert_eq!(table.column_bang("dateTime").tpe.family, ColumnTypeFamily::DateTime);
-    assert_eq!(table.column_bang("enum").tpe.family, ColumnTypeFamily::String);
+    match api.sql_family() {
+        SqlFamily::Postgres => assert_eq!(
+            table.column_bang("enum").tpe.family,
+            ColumnTypeFamily::Enum("MyEnum".to_owned())
+        ),
+        SqlFamily::Mysql => assert_eq!(
+            table.column_bang("enum").tpe.family,
+            ColumnTypeFamily::Enum("Test_enum".to_owned())
+        ),
+        SqlFamily::Sqlite => assert_eq!(table.column_bang("enum").tpe.family, ColumnTypeFamily::String),
+    }
 }
 
 //#[test]
@@ -226,6 +236,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 
@@ -254,6 +265,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -309,6 +321,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 
@@ -371,6 +384,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -412,6 +426,7 @@ async fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_t
                 referenced_table: "A".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::NoAction,
             },
             ForeignKey {
                 constraint_name: match api.sql_family() {
@@ -423,6 +438,7 @@ async fn adding_a_many_to_many_relation_must_result_in_a_prisma_style_relation_t
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::NoAction,
             },
         ]
     );
@@ -463,6 +479,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 referenced_table: "A".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::NoAction,
             },
             ForeignKey {
                 constraint_name: match api.sql_family() {
@@ -474,6 +491,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 referenced_table: "B".to_string(),
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
+                on_update_action: ForeignKeyAction::NoAction,
             }
         ]
     );
@@ -549,6 +567,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                     referenced_table: "B".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict, // required relations can't set ON DELETE SET NULL
+                    on_update_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
                     constraint_name: match api.sql_family() {
@@ -560,11 +579,96 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                     referenced_table: "C".to_string(),
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
+                    on_update_action: ForeignKeyAction::NoAction,
                 }
             ]
         );
 }
 
+#[test_each_connector]
+async fn referential_actions_must_be_applied_to_foreign_keys(api: &TestApi) {
+    let dm1 = r#"
+            model A {
+                id Int @id
+                b  B   @relation(references: [id], onDelete: Cascade, onUpdate: Cascade)
+                c  C?  @relation(references: [id], onDelete: Restrict)
+            }
+
+            model B {
+                id Int @id
+            }
+
+            model C {
+                id Int @id
+            }
+        "#;
+
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    let table = result.table_bang("A");
+
+    let b_fk = table.foreign_key_for_column("b").unwrap();
+    assert_eq!(b_fk.on_delete_action, ForeignKeyAction::Cascade);
+    assert_eq!(b_fk.on_update_action, ForeignKeyAction::Cascade);
+
+    let c_fk = table.foreign_key_for_column("c").unwrap();
+    assert_eq!(c_fk.on_delete_action, ForeignKeyAction::Restrict);
+    assert_eq!(c_fk.on_update_action, ForeignKeyAction::NoAction);
+}
+
+#[test_each_connector]
+async fn cascading_deletes_declared_on_the_list_side_must_be_applied_to_foreign_keys(api: &TestApi) {
+    let dm1 = r#"
+            model A {
+                id Int @id
+                bs B[] @relation(onDelete: CASCADE)
+            }
+
+            model B {
+                id Int @id
+                a  A
+            }
+        "#;
+
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    let fk = result.table_bang("B").foreign_key_for_column("a").unwrap();
+    assert_eq!(fk.on_delete_action, ForeignKeyAction::Cascade);
+    assert_eq!(fk.on_update_action, ForeignKeyAction::NoAction);
+}
+
+#[test_each_connector]
+async fn changing_the_referential_action_of_a_relation_must_work(api: &TestApi) {
+    let dm1 = r#"
+            model A {
+                id Int @id
+                b  B?  @relation(references: [id])
+            }
+
+            model B {
+                id Int @id
+            }
+        "#;
+
+    let result = api.infer_and_apply(&dm1).await.sql_schema;
+    let fk = result.table_bang("A").foreign_key_for_column("b").unwrap();
+    assert_eq!(fk.on_delete_action, ForeignKeyAction::SetNull);
+
+    let dm2 = r#"
+            model A {
+                id Int @id
+                b  B?  @relation(references: [id], onDelete: Cascade, onUpdate: SetNull)
+            }
+
+            model B {
+                id Int @id
+            }
+        "#;
+
+    let result = api.infer_and_apply(&dm2).await.sql_schema;
+    let fk = result.table_bang("A").foreign_key_for_column("b").unwrap();
+    assert_eq!(fk.on_delete_action, ForeignKeyAction::Cascade);
+    assert_eq!(fk.on_update_action, ForeignKeyAction::SetNull);
+}
+
 #[test_each_connector]
 async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
     let dm1 = r#"
@@ -593,6 +697,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -625,6 +730,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -685,6 +791,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) {
             referenced_table: "B".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 
@@ -712,6 +819,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) {
             referenced_table: "A".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Restrict,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -1307,6 +1415,7 @@ async fn reserved_sql_key_words_must_work(api: &TestApi) {
             referenced_table: "Group".to_string(),
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::SetNull,
+            on_update_action: ForeignKeyAction::NoAction,
         }]
     );
 }
@@ -1431,3 +1540,223 @@ async fn simple_type_aliases_in_migrations_must_work(api: &TestApi) {
 
     api.infer_and_apply(dm1);
 }
+
+#[test_each_connector(ignore = "sqlite")]
+async fn enums_must_be_created_as_native_enums(api: &TestApi) {
+    let dm = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+            HAPPY
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(&dm).await.sql_schema;
+    let enum_name = match api.sql_family() {
+        SqlFamily::Mysql => "Cat_mood",
+        _ => "CatMood",
+    };
+
+    assert_eq!(
+        sql_schema.get_enum(enum_name),
+        Some(&Enum {
+            name: enum_name.to_owned(),
+            values: vec!["HUNGRY".to_owned(), "HAPPY".to_owned()],
+        })
+    );
+    assert_eq!(
+        sql_schema.table_bang("Cat").column_bang("mood").tpe.family,
+        ColumnTypeFamily::Enum(enum_name.to_owned())
+    );
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn enum_variants_can_be_added_and_removed(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+            HAPPY
+        }
+    "#;
+
+    api.infer_and_apply(&dm1).await;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            SLEEPY
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(&dm2).await.sql_schema;
+    let enum_name = match api.sql_family() {
+        SqlFamily::Mysql => "Cat_mood",
+        _ => "CatMood",
+    };
+
+    assert_eq!(
+        sql_schema.get_enum(enum_name).map(|e| e.values.clone()),
+        Some(vec!["HAPPY".to_owned(), "SLEEPY".to_owned()])
+    );
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn enums_must_be_dropped_when_no_longer_used(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+            HAPPY
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(&dm1).await.sql_schema;
+    assert_eq!(sql_schema.enums.len(), 1);
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+        }
+    "#;
+
+    let sql_schema = api.infer_and_apply(&dm2).await.sql_schema;
+    assert!(sql_schema.enums.is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn native_types_must_be_used_for_columns(api: &TestApi) {
+    let dm1 = r#"
+        model Blog {
+            id     Int    @id
+            title  String @db.VarChar(50)
+            views  Int    @db.BigInt
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm1).await.sql_schema;
+    let table = result.table_bang("Blog");
+    let bigint = match api.sql_family() {
+        SqlFamily::Postgres => "int8",
+        _ => "bigint",
+    };
+
+    assert_eq!(table.column_bang("title").tpe.raw, "varchar(50)");
+    assert_eq!(table.column_bang("title").tpe.family, ColumnTypeFamily::String);
+    assert_eq!(table.column_bang("views").tpe.raw, bigint);
+    assert_eq!(table.column_bang("views").tpe.family, ColumnTypeFamily::Int);
+
+    // The described types match the native types, so there is nothing left to migrate.
+    let output = api.infer_and_apply(dm1).await;
+    assert!(output.sql_migration().is_empty());
+
+    let dm2 = r#"
+        model Blog {
+            id     Int    @id
+            title  String @db.Text
+            views  Int    @db.BigInt
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm2).await.sql_schema;
+    let table = result.table_bang("Blog");
+
+    assert_eq!(table.column_bang("title").tpe.raw, "text");
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn changing_the_length_of_a_native_type_must_be_migrated(api: &TestApi) {
+    let dm1 = r#"
+        model Blog {
+            id     Int    @id
+            title  String @db.VarChar(50)
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let dm2 = r#"
+        model Blog {
+            id     Int    @id
+            title  String @db.VarChar(100)
+        }
+    "#;
+
+    let output = api.infer_and_apply(dm2).await;
+    let column = output.sql_schema.table_bang("Blog").column_bang("title");
+
+    assert!(!output.sql_migration().is_empty());
+    assert_eq!(column.tpe.raw, "varchar(100)");
+
+    // A column without native type keeps whatever type it has in the family.
+    let dm3 = r#"
+        model Blog {
+            id     Int    @id
+            title  String
+        }
+    "#;
+
+    let output = api.infer_and_apply(dm3).await;
+
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn native_types_of_other_families_must_be_migrated(api: &TestApi) {
+    let dm = r#"
+        model Event {
+            id      Int    @id
+            payload String @db.Json
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm).await.sql_schema;
+    let column = result.table_bang("Event").column_bang("payload");
+
+    assert_eq!(column.tpe.raw, "json");
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Json);
+
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn decimal_fields_must_be_migrated_to_exact_numeric_columns(api: &TestApi) {
+    let dm = r#"
+        model Invoice {
+            id     Int     @id
+            amount Decimal @default(12.50)
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm).await.sql_schema;
+    let column = result.table_bang("Invoice").column_bang("amount");
+    let numeric = match api.sql_family() {
+        SqlFamily::Postgres => "numeric(28,10)",
+        SqlFamily::Mysql => "decimal(28,10)",
+        SqlFamily::Sqlite => "Decimal(28,10)",
+    };
+
+    assert_eq!(column.tpe.raw, numeric);
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Float);
+
+    let output = api.infer_and_apply(dm).await;
+    assert!(output.sql_migration().is_empty());
+}
@@ -6,10 +6,13 @@ use super::{
     },
     InferAndApplyOutput, SCHEMA_NAME,
 };
-use migration_connector::{MigrationPersistence, MigrationStep};
+use migration_connector::{MigrationPersistence, MigrationScript, MigrationStep};
 use migration_core::{
     api::GenericApi,
-    commands::{ApplyMigrationInput, InferMigrationStepsInput, UnapplyMigrationInput, UnapplyMigrationOutput},
+    commands::{
+        ApplyMigrationInput, DetectDriftOutput, InferMigrationStepsInput, MigrationStepsResultOutput,
+        RenderMigrationScriptInput, RenderMigrationScriptOutput, UnapplyMigrationInput, UnapplyMigrationOutput,
+    },
 };
 use quaint::prelude::SqlFamily;
 use sql_connection::SyncSqlConnection;
@@ -42,10 +45,23 @@ impl TestApi {
     }
 
     pub async fn apply_migration(&self, steps: Vec<MigrationStep>, migration_id: &str) -> InferAndApplyOutput {
+        self.apply_migration_with_script(steps, migration_id, None, None).await
+    }
+
+    /// Applies the migration with the script. Migrations with warnings are only applied when they are forced.
+    pub async fn apply_migration_with_script(
+        &self,
+        steps: Vec<MigrationStep>,
+        migration_id: &str,
+        script: Option<MigrationScript>,
+        force: Option<bool>,
+    ) -> InferAndApplyOutput {
         let input = ApplyMigrationInput {
             migration_id: migration_id.to_string(),
             steps,
-            force: None,
+            force,
+            script,
+            script_file: None,
         };
 
         let migration_output = self.api.apply_migration(&input).expect("ApplyMigration failed");
@@ -82,6 +98,53 @@ impl TestApi {
         self.apply_migration(steps, migration_id).await
     }
 
+    /// Like `infer_and_apply`, but applies the migration even when it has warnings.
+    pub async fn infer_and_apply_forcefully(&self, datamodel: &str) -> InferAndApplyOutput {
+        self.infer_and_apply_with_script(datamodel, MigrationScript::default(), Some(true))
+            .await
+    }
+
+    /// Like `infer_and_apply`, with the user-provided script running as part of the migration.
+    pub async fn infer_and_apply_with_script(
+        &self,
+        datamodel: &str,
+        script: MigrationScript,
+        force: Option<bool>,
+    ) -> InferAndApplyOutput {
+        let migration_id = "the-migration-id";
+        let input = InferMigrationStepsInput {
+            migration_id: migration_id.to_string(),
+            datamodel: datamodel.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+
+        let steps = self.run_infer_command(input).await.0.datamodel_steps;
+
+        self.apply_migration_with_script(steps, migration_id, Some(script), force)
+            .await
+    }
+
+    /// Like `infer_and_apply`, but returns the error of a failing migration instead of panicking.
+    pub async fn try_infer_and_apply(&self, datamodel: &str) -> migration_core::Result<MigrationStepsResultOutput> {
+        let migration_id = "the-migration-id";
+        let input = InferMigrationStepsInput {
+            migration_id: migration_id.to_string(),
+            datamodel: datamodel.to_string(),
+            assume_to_be_applied: Vec::new(),
+        };
+
+        let steps = self.run_infer_command(input).await.0.datamodel_steps;
+        let input = ApplyMigrationInput {
+            migration_id: migration_id.to_string(),
+            steps,
+            force: None,
+            script: None,
+            script_file: None,
+        };
+
+        self.api.apply_migration(&input)
+    }
+
     pub async fn run_infer_command(&self, input: InferMigrationStepsInput) -> InferOutput {
         run_infer_command(self.api.as_ref(), input)
     }
@@ -95,6 +158,31 @@ impl TestApi {
         UnapplyOutput { sql_schema, output }
     }
 
+    pub async fn render_migration_script(
+        &self,
+        datamodel: &str,
+        previous_datamodel: Option<&str>,
+    ) -> RenderMigrationScriptResult {
+        let input = RenderMigrationScriptInput {
+            datamodel: datamodel.to_string(),
+            previous_datamodel: previous_datamodel.map(String::from),
+        };
+        let output = self
+            .api
+            .render_migration_script(&input)
+            .expect("RenderMigrationScript failed");
+
+        let sql_schema = self.introspect_database();
+
+        RenderMigrationScriptResult { sql_schema, output }
+    }
+
+    pub fn detect_drift(&self) -> DetectDriftOutput {
+        self.api
+            .detect_drift(&serde_json::Value::Null)
+            .expect("DetectDrift failed")
+    }
+
     pub fn barrel(&self) -> BarrelMigrationExecutor {
         BarrelMigrationExecutor {
             inspector: self.inspector(),
@@ -122,7 +210,7 @@ impl TestApi {
         }
     }
 
-    fn introspect_database(&self) -> SqlSchema {
+    pub fn introspect_database(&self) -> SqlSchema {
         let mut result = self
             .inspector()
             .describe(&SCHEMA_NAME.to_string())
@@ -214,3 +302,9 @@ pub struct UnapplyOutput {
     pub sql_schema: SqlSchema,
     pub output: UnapplyMigrationOutput,
 }
+
+#[derive(Debug)]
+pub struct RenderMigrationScriptResult {
+    pub sql_schema: SqlSchema,
+    pub output: RenderMigrationScriptOutput,
+}
@@ -68,7 +68,7 @@ fn main() {
             .read_to_string(&mut datamodel_string)
             .expect("Unable to read from stdin.");
 
-        if let Err(err) = datamodel::parse_datamodel(&datamodel_string) {
+        if let Err(err) = datamodel::parse_datamodel_with_sources(&datamodel_string, vec![]) {
             let errs: Vec<MiniError> = err
                 .errors
                 .iter()
@@ -0,0 +1,99 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.Prisma2Capability
+import util._
+
+class AggregationFunctionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  override def doNotRunForCapabilities: Set[ConnectorCapability] = Set(Prisma2Capability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id    String @id @default(cuid())
+      |  float Float
+      |  int   Int
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "the sum, avg, min and max queries" should "return null if there are no records" in {
+    val result = server.query(
+      s"""{
+         |  itemsConnection {
+         |    aggregate {
+         |      sum { float int }
+         |      avg { float int }
+         |      min { float int }
+         |      max { float int }
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result should equal(
+      """{"data":{"itemsConnection":{"aggregate":{"sum":{"float":null,"int":null},"avg":{"float":null,"int":null},"min":{"float":null,"int":null},"max":{"float":null,"int":null}}}}}""".parseJson)
+  }
+
+  "the sum query" should "keep the fractional part of float sums" in {
+    createItem(1.25, 5)
+    createItem(2.5, 10)
+
+    val result = server.query(
+      s"""{
+         |  itemsConnection {
+         |    aggregate {
+         |      sum { float int }
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.pathAsDouble("data.itemsConnection.aggregate.sum.float") should be(3.75)
+    result.pathAsLong("data.itemsConnection.aggregate.sum.int") should be(15)
+  }
+
+  "the avg, min and max queries" should "aggregate all matching records" in {
+    createItem(1.5, 1)
+    createItem(2.5, 2)
+    createItem(8.0, 6)
+
+    val result = server.query(
+      s"""{
+         |  itemsConnection(where: { int_lt: 6 }) {
+         |    aggregate {
+         |      avg { float int }
+         |      min { float int }
+         |      max { float int }
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.pathAsDouble("data.itemsConnection.aggregate.avg.float") should be(2.0)
+    result.pathAsDouble("data.itemsConnection.aggregate.avg.int") should be(1.5)
+    result.pathAsDouble("data.itemsConnection.aggregate.min.float") should be(1.5)
+    result.pathAsLong("data.itemsConnection.aggregate.min.int") should be(1)
+    result.pathAsDouble("data.itemsConnection.aggregate.max.float") should be(2.5)
+    result.pathAsLong("data.itemsConnection.aggregate.max.int") should be(2)
+  }
+
+  def createItem(float: Double, int: Int) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { float: $float, int: $int }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,317 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupBySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Item {
+      |  id       String  @id @default(cuid())
+      |  category String
+      |  label    String?
+      |  int      Int
+      |  float    Float
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createItems()
+  }
+
+  "Grouping by a field" should "return one group per value, ordered by the grouped field" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category]) {
+        |    category
+        |    count
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"category":"A","count":2},{"category":"B","count":2},{"category":"C","count":1}]}}""")
+  }
+
+  "Grouping by several fields" should "return one group per combination of values" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category, label], orderBy: label_ASC_NULLS_FIRST) {
+        |    category
+        |    label
+        |    count
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"category":"A","label":null,"count":1},{"category":"B","label":null,"count":1},{"category":"A","label":"x","count":1},{"category":"B","label":"x","count":1},{"category":"C","label":"y","count":1}]}}""")
+  }
+
+  "The aggregations of a group" should "only aggregate the records of the group" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category]) {
+        |    category
+        |    sum { int float }
+        |    avg { int float }
+        |    min { int float }
+        |    max { int float }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.pathAsString("data.groupByItem.[0].category") should be("A")
+    result.pathAsLong("data.groupByItem.[0].sum.int") should be(3)
+    result.pathAsDouble("data.groupByItem.[0].sum.float") should be(4.0)
+    result.pathAsDouble("data.groupByItem.[0].avg.int") should be(1.5)
+    result.pathAsDouble("data.groupByItem.[0].avg.float") should be(2.0)
+    result.pathAsLong("data.groupByItem.[0].min.int") should be(1)
+    result.pathAsDouble("data.groupByItem.[0].min.float") should be(1.5)
+    result.pathAsLong("data.groupByItem.[0].max.int") should be(2)
+    result.pathAsDouble("data.groupByItem.[0].max.float") should be(2.5)
+
+    result.pathAsString("data.groupByItem.[2].category") should be("C")
+    result.pathAsLong("data.groupByItem.[2].sum.int") should be(10)
+    result.pathAsDouble("data.groupByItem.[2].sum.float") should be(0.5)
+    result.pathAsDouble("data.groupByItem.[2].avg.int") should be(10.0)
+    result.pathAsLong("data.groupByItem.[2].min.int") should be(10)
+    result.pathAsLong("data.groupByItem.[2].max.int") should be(10)
+  }
+
+  "Grouping filtered records" should "only group the matching records" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], where: { int_lt: 4 }) {
+        |    category
+        |    count
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"A","count":2},{"category":"B","count":1}]}}""")
+  }
+
+  "Having a count filter" should "only return the matching groups" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], having: { count_gt: 1 }) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"A"},{"category":"B"}]}}""")
+  }
+
+  "Having a filter on an aggregated field" should "only return the matching groups" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], having: { sum: { int_gte: 7 } }) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"B"},{"category":"C"}]}}""")
+  }
+
+  "Having filters combined with AND" should "return the groups matching all filters" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], having: { AND: [{ count: 2 }, { max: { int_gt: 2 } }] }) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"B"}]}}""")
+  }
+
+  "Having filters combined with OR" should "return the groups matching any filter" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], having: { OR: [{ count_lt: 2 }, { avg: { float_lt: 2.5 } }] }) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"A"},{"category":"C"}]}}""")
+  }
+
+  "Having a negated filter" should "return the groups not matching the filter" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], having: { NOT: [{ min: { int_lt: 3 } }] }) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"B"},{"category":"C"}]}}""")
+  }
+
+  "Ordering groups" should "order by the grouped field" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], orderBy: category_DESC) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"C"},{"category":"B"},{"category":"A"}]}}""")
+  }
+
+  "Skipping and limiting groups" should "paginate the ordered groups" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [category], orderBy: category_DESC, skip: 1, first: 1) {
+        |    category
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"category":"B"}]}}""")
+  }
+
+  "Ordering groups with nulls first" should "put the group of nulls before all other groups" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [label], orderBy: label_DESC_NULLS_FIRST) {
+        |    label
+        |    count
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"label":null,"count":2},{"label":"y","count":1},{"label":"x","count":2}]}}""")
+  }
+
+  "Ordering groups with nulls last" should "put the group of nulls after all other groups" in {
+    val result = server.query(
+      """
+        |{
+        |  groupByItem(by: [label], orderBy: label_ASC_NULLS_LAST) {
+        |    label
+        |    count
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"label":"x","count":2},{"label":"y","count":1},{"label":null,"count":2}]}}""")
+  }
+
+  "Grouping by no field" should "fail" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  groupByItem(by: []) {
+        |    count
+        |  }
+        |}
+      """,
+      project,
+      errorCode = 0,
+      errorContains = "At least one field of model 'Item' is required to group by."
+    )
+  }
+
+  "Selecting a field that is not grouped by" should "fail" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  groupByItem(by: [category]) {
+        |    label
+        |  }
+        |}
+      """,
+      project,
+      errorCode = 0,
+      errorContains = "Field 'label' can only be selected if it is part of 'by'."
+    )
+  }
+
+  "Ordering by a field that is not grouped by" should "fail" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  groupByItem(by: [category], orderBy: int_ASC) {
+        |    category
+        |  }
+        |}
+      """,
+      project,
+      errorCode = 0,
+      errorContains = "Groups can only be ordered by fields that are grouped by, but 'int' is not part of 'by'."
+    )
+  }
+
+  def createItems(): Unit = {
+    createItem("A", None, 1, 1.5)
+    createItem("A", Some("x"), 2, 2.5)
+    createItem("B", Some("x"), 3, 3.0)
+    createItem("B", None, 4, 4.0)
+    createItem("C", Some("y"), 10, 0.5)
+  }
+
+  def createItem(category: String, label: Option[String], int: Int, float: Double): Unit = {
+    val labelArg = label.map(l => s"""label: "$l", """).getOrElse("")
+
+    server.query(
+      s"""
+        |mutation {
+        |  createItem(data: { category: "$category", ${labelArg}int: $int, float: $float }) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+  }
+}
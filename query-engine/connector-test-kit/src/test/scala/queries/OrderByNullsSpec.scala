@@ -0,0 +1,159 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class OrderByNullsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Item {
+      |  id    String  @id @default(cuid())
+      |  name  String?
+      |  order Int
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createItems()
+  }
+
+  "Ordering by a list of fields" should "order by the fields in order" in {
+    val result = server.query(
+      """
+        |{
+        |  items(orderBy: [name_ASC_NULLS_FIRST, order_DESC]) {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":4},{"order":2},{"order":3},{"order":1},{"order":5}]}}""")
+  }
+
+  "Ordering with nulls last" should "put nulls after all other values" in {
+    val result = server.query(
+      """
+        |{
+        |  items(orderBy: name_ASC_NULLS_LAST) {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":3},{"order":1},{"order":5},{"order":2},{"order":4}]}}""")
+  }
+
+  "Ordering descending with nulls first" should "put nulls before all other values" in {
+    val result = server.query(
+      """
+        |{
+        |  items(orderBy: name_DESC_NULLS_FIRST) {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":2},{"order":4},{"order":5},{"order":1},{"order":3}]}}""")
+  }
+
+  "After with a null cursor value" should "return the remaining nulls and all other values" in {
+    val after = idOf(4)
+
+    val result = server.query(
+      s"""
+        |{
+        |  items(orderBy: [name_ASC_NULLS_FIRST, order_DESC], after: "$after") {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":2},{"order":3},{"order":1},{"order":5}]}}""")
+  }
+
+  "After the last null" should "return all other values" in {
+    val after = idOf(2)
+
+    val result = server.query(
+      s"""
+        |{
+        |  items(orderBy: [name_ASC_NULLS_FIRST, order_DESC], after: "$after") {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":3},{"order":1},{"order":5}]}}""")
+  }
+
+  "Before a value with nulls first" should "return the nulls" in {
+    val before = idOf(3)
+
+    val result = server.query(
+      s"""
+        |{
+        |  items(orderBy: [name_ASC_NULLS_FIRST, order_DESC], before: "$before") {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":4},{"order":2}]}}""")
+  }
+
+  "After a value with nulls last" should "return the remaining values and the nulls" in {
+    val after = idOf(1)
+
+    val result = server.query(
+      s"""
+        |{
+        |  items(orderBy: name_ASC_NULLS_LAST, after: "$after") {
+        |    order
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"items":[{"order":5},{"order":2},{"order":4}]}}""")
+  }
+
+  private def idOf(order: Int): String = {
+    server
+      .query(s"""{ items(where: {order: $order}) { id } }""", project)
+      .pathAsSeq("data.items")
+      .head
+      .pathAsString("id")
+  }
+
+  private def createItems(): Unit = {
+    server.query(
+      """
+        |mutation {
+        |  a: createItem(data: {name: "b", order: 1}){ id }
+        |  b: createItem(data: {order: 2}){ id }
+        |  c: createItem(data: {name: "a", order: 3}){ id }
+        |  d: createItem(data: {order: 4}){ id }
+        |  e: createItem(data: {name: "c", order: 5}){ id }
+        |}
+      """,
+      project
+    )
+  }
+}
@@ -0,0 +1,102 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class OrderByRelatedFieldSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    String @id @default(cuid())
+      |  name  String
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       String    @id @default(cuid())
+      |  title    String
+      |  author   User?
+      |  category Category?
+      |}
+      |
+      |model Category {
+      |  id   String @id @default(cuid())
+      |  name String
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createData()
+  }
+
+  "Ordering by a related field" should "order the records by the value of the related record" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: category_name_ASC) {
+        |    title
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"title":"p2"},{"title":"p3"},{"title":"p1"}]}}""")
+  }
+
+  "Ordering nested records by a related field" should "select the related value of each nested record" in {
+    val result = server.query(
+      """
+        |{
+        |  users {
+        |    posts(orderBy: category_name_DESC) {
+        |      title
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"users":[{"posts":[{"title":"p1"},{"title":"p3"},{"title":"p2"}]}]}}""")
+  }
+
+  "Paginating nested records ordered by a related field" should "select the related value of each nested record" in {
+    val result = server.query(
+      """
+        |{
+        |  users {
+        |    posts(orderBy: category_name_ASC, first: 2) {
+        |      title
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"users":[{"posts":[{"title":"p2"},{"title":"p3"}]}]}}""")
+  }
+
+  private def createData(): Unit = {
+    server.query(
+      """
+        |mutation {
+        |  createUser(data: {
+        |    name: "A"
+        |    posts: { create: [
+        |      { title: "p1", category: { create: { name: "c" } } }
+        |      { title: "p2", category: { create: { name: "a" } } }
+        |      { title: "p3", category: { create: { name: "b" } } }
+        |    ]}
+        |  }){ id }
+        |}
+      """,
+      project
+    )
+  }
+}
@@ -0,0 +1,67 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model Post {
+      |   id    String   @id @default(cuid())
+      |   title String   @unique
+      |   tags  String[] $scalarListDirective
+      |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createPost("a", Seq("rust", "sql"))
+    createPost("b", Seq("rust"))
+    createPost("c", Seq("scala"))
+    createPost("d", Seq.empty)
+  }
+
+  "The has filter" should "return the records whose list contains the value" in {
+    titles("""{ tags_has: "rust" }""") should be("""[{"title":"a"},{"title":"b"}]""")
+  }
+
+  "The has every filter" should "return the records whose list contains all values" in {
+    titles("""{ tags_has_every: ["rust", "sql"] }""") should be("""[{"title":"a"}]""")
+  }
+
+  "The has some filter" should "return the records whose list contains any value" in {
+    titles("""{ tags_has_some: ["sql", "scala"] }""") should be("""[{"title":"a"},{"title":"c"}]""")
+  }
+
+  "The is empty filter" should "return the records with an empty list" in {
+    titles("""{ tags_is_empty: true }""") should be("""[{"title":"d"}]""")
+    titles("""{ tags_is_empty: false }""") should be("""[{"title":"a"},{"title":"b"},{"title":"c"}]""")
+  }
+
+  "The is empty filter with null" should "not filter" in {
+    titles("""{ tags_is_empty: null }""") should be("""[{"title":"a"},{"title":"b"},{"title":"c"},{"title":"d"}]""")
+  }
+
+  def titles(where: String): String = {
+    server
+      .query(s"""{ posts(where: $where, orderBy: title_ASC) { title } }""", project)
+      .pathAsJsValue("data.posts")
+      .toString
+  }
+
+  def createPost(title: String, tags: Seq[String]): Unit = {
+    val tagList = tags.map(tag => s""""$tag"""").mkString(", ")
+
+    server.query(
+      s"""mutation {
+         |  createPost(data: { title: "$title", tags: { set: [$tagList] } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -83,7 +83,7 @@ case class TestServer() extends PlayJsonExtensions {
     import java.lang.ProcessBuilder.Redirect
 
     // TODO: discuss with Dom whether we want to keep the legacy mode
-    val pb         = new java.lang.ProcessBuilder(EnvVars.prismaBinaryPath, "--legacy")
+    val pb         = new java.lang.ProcessBuilder(EnvVars.prismaBinaryPath, "--legacy", "--enable-reset-data")
     val workingDir = new java.io.File(".")
 
     val fullDataModel = project.dataModelWithDataSourceConfig
@@ -0,0 +1,81 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CreateManySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id     String  @id @default(cuid())
+      |  title  String
+      |  opt    String?
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "The create many Mutation" should "create all given items" in {
+    val result = createManyTodoes(Vector("""{ title: "title1" }""", """{ title: "title2", opt: "opt2" }"""))
+    result.pathAsLong("data.createManyTodoes.count") should equal(2)
+
+    server.query("""{ todoes(orderBy: title_ASC) { title opt } }""", project).toString should equal(
+      """{"data":{"todoes":[{"title":"title1","opt":null},{"title":"title2","opt":"opt2"}]}}""")
+  }
+
+  // Two columns per row (id and title) fit 499 rows into the 999 bind parameters SQLite allows, so
+  // the batch has to be split into several inserts with a partial last chunk.
+  "The create many Mutation" should "create all items of batches exceeding the bind parameter limit" in {
+    val items  = (1 to 1001).map(i => s"""{ title: "title$i" }""")
+    val result = createManyTodoes(items)
+
+    result.pathAsLong("data.createManyTodoes.count") should equal(1001)
+    todoCount should equal(1001)
+    titlesOf(Vector("title1", "title499", "title500", "title998", "title999", "title1001")) should equal(
+      Vector("title1", "title1001", "title499", "title500", "title998", "title999"))
+  }
+
+  "The create many Mutation" should "create all items of interleaved batches setting different fields" in {
+    val items = (1 to 1200).map { i =>
+      if (i % 2 == 0) s"""{ title: "title$i", opt: "opt$i" }""" else s"""{ title: "title$i" }"""
+    }
+    val result = createManyTodoes(items)
+
+    result.pathAsLong("data.createManyTodoes.count") should equal(1200)
+    todoCount should equal(1200)
+
+    server.query("""{ todoesConnection(where: { opt: null }) { aggregate { count } } }""", project)
+      .pathAsLong("data.todoesConnection.aggregate.count") should equal(600)
+    server.query("""{ todoes(where: { title_in: ["title999", "title1000"] }, orderBy: title_ASC) { title opt } }""", project).toString should equal(
+      """{"data":{"todoes":[{"title":"title1000","opt":"opt1000"},{"title":"title999","opt":null}]}}""")
+  }
+
+  def createManyTodoes(items: Seq[String]) = {
+    server.query(
+      s"""mutation {
+        |  createManyTodoes(data: [${items.mkString(", ")}]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+  }
+
+  def todoCount: Long = {
+    server.query("""{ todoesConnection { aggregate { count } } }""", project).pathAsLong("data.todoesConnection.aggregate.count")
+  }
+
+  def titlesOf(titles: Vector[String]): Vector[String] = {
+    val result = server.query(
+      s"""{ todoes(where: { title_in: [${titles.map(t => s""""$t"""").mkString(", ")}] }, orderBy: title_ASC) { title } }""",
+      project
+    )
+    result.pathAsJsArray("data.todoes").value.map(_.pathAsString("title")).toVector
+  }
+}
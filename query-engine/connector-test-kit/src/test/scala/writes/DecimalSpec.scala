@@ -0,0 +1,42 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DecimalSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """|model Invoice {
+       | id     String  @id @default(cuid())
+       | name   String  @unique
+       | amount Decimal
+       |}"""
+  }
+  database.setup(project)
+
+  "Using a decimal with 15 digits" should "work" in {
+
+    server.query(s"""mutation {createInvoice(data: {name: "First", amount: "1234567890.12345"}){name}}""", project)
+    val res = server.query(s"""query {invoice(where:{name: "First"}){name, amount}}""", project)
+    res.toString should be("""{"data":{"invoice":{"name":"First","amount":"1234567890.12345"}}}""")
+  }
+
+  // Postgres numerics are read as floats, and SQLite stores numerics that don't fit into a float as `REAL`.
+  "Using a decimal with more than 15 digits" should "keep all digits" taggedAs (IgnoreSQLite, IgnorePostgres) in {
+
+    server.query(s"""mutation {createInvoice(data: {name: "Second", amount: "12345678901234.5678"}){name}}""", project)
+    val res = server.query(s"""query {invoice(where:{name: "Second"}){name, amount}}""", project)
+    res.toString should be("""{"data":{"invoice":{"name":"Second","amount":"12345678901234.5678"}}}""")
+  }
+
+  "Updating a decimal with more than 15 digits" should "keep all digits" taggedAs (IgnoreSQLite, IgnorePostgres) in {
+
+    server.query(s"""mutation {createInvoice(data: {name: "Third", amount: "1.5"}){name}}""", project)
+    server.query(
+      s"""mutation {updateInvoice(where: {name: "Third"}, data: {amount: "98765432109876.54321"}){name}}""",
+      project
+    )
+    val res = server.query(s"""query {invoice(where:{name: "Third"}){name, amount}}""", project)
+    res.toString should be("""{"data":{"invoice":{"name":"Third","amount":"98765432109876.54321"}}}""")
+  }
+}
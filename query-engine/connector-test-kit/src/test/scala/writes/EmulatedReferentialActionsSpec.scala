@@ -0,0 +1,78 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class EmulatedReferentialActionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    String @id @default(cuid())
+      |  name  String @unique
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       String    @id @default(cuid())
+      |  title    String    @unique
+      |  author   User?     @relation(references: [id], onDelete: Cascade)
+      |  comments Comment[]
+      |  tags     Tag[]
+      |}
+      |
+      |model Comment {
+      |  id   String @id @default(cuid())
+      |  text String @unique
+      |  post Post   @relation(references: [id])
+      |}
+      |
+      |model Tag {
+      |  id   String @id @default(cuid())
+      |  name String @unique
+      |  post Post?  @relation(references: [id])
+      |}
+    """
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Deleting a record" should "delete the records of a relation declared as cascading" in {
+    server.query("""mutation { createUser(data: { name: "a", posts: { create: [{ title: "p1" }] } }){ id } }""", project)
+
+    server.query("""mutation { deleteUser(where: { name: "a" }){ id } }""", project)
+
+    server.query("""{ posts { title } }""", project).toString should be("""{"data":{"posts":[]}}""")
+  }
+
+  "Deleting a record" should "fail if a cascaded deletion violates a required relation" in {
+    server.query(
+      """mutation { createUser(data: { name: "b", posts: { create: [{ title: "p2", comments: { create: [{ text: "c2" }] } }] } }){ id } }""",
+      project
+    )
+
+    server.queryThatMustFail(
+      """mutation { deleteUser(where: { name: "b" }){ id } }""",
+      project,
+      errorCode = 3042
+    )
+
+    server.query("""{ users { name posts { title } } }""", project).toString should be(
+      """{"data":{"users":[{"name":"b","posts":[{"title":"p2"}]}]}}""")
+  }
+
+  "Deleting a record" should "leave undeclared actions of optional relations to the database" in {
+    server.query("""mutation { createPost(data: { title: "p3", tags: { create: [{ name: "t3" }] } }){ id } }""", project)
+
+    server.query("""mutation { deletePost(where: { title: "p3" }){ id } }""", project)
+
+    server.query("""{ tags { name post { title } } }""", project).toString should be(
+      """{"data":{"tags":[{"name":"t3","post":null}]}}""")
+  }
+}
@@ -0,0 +1,96 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ResetDataSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """model Parent {
+      |  id       String  @id @default(cuid())
+      |  name     String  @unique
+      |  tags     String[]
+      |  children Child[]
+      |}
+      |
+      |model Child {
+      |  id     String  @id @default(cuid())
+      |  name   String  @unique
+      |  parent Parent?
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "The resetData mutation" should "remove all records, scalar list entries and relations" in {
+    createParent("p1", "c1")
+    createParent("p2", "c2")
+
+    server.query("mutation { resetData }", project)
+
+    server.query("{ parents { name tags } }", project).toString should be("""{"data":{"parents":[]}}""")
+    server.query("{ children { name parent { name } } }", project).toString should be("""{"data":{"children":[]}}""")
+  }
+
+  "The resetData mutation" should "leave the database usable for new records" in {
+    createParent("p1", "c1")
+
+    server.query("mutation { resetData }", project)
+    server.query("mutation { resetData }", project)
+
+    createParent("p1", "c1")
+
+    server.query("{ parents { name tags children { name } } }", project).toString should be(
+      """{"data":{"parents":[{"name":"p1","tags":["a","b"],"children":[{"name":"c1"}]}]}}""")
+  }
+
+  "The resetData mutation" should "remove records referenced by restricting relations" in {
+    val restrictProject = SchemaDsl.fromStringV11() {
+      """model Author {
+        |  id    String @id @default(cuid())
+        |  name  String @unique
+        |  books Book[]
+        |}
+        |
+        |model Book {
+        |  id     String @id @default(cuid())
+        |  title  String @unique
+        |  author Author @relation(onDelete: Restrict)
+        |}
+      """.stripMargin
+    }
+
+    database.setup(restrictProject)
+
+    server.query(
+      """mutation {
+        |  createAuthor(data: { name: "a1", books: { create: [{ title: "b1" }, { title: "b2" }] } }) {
+        |    id
+        |  }
+        |}""".stripMargin,
+      restrictProject
+    )
+
+    server.query("mutation { resetData }", restrictProject)
+
+    server.query("{ authors { name } }", restrictProject).toString should be("""{"data":{"authors":[]}}""")
+    server.query("{ books { title } }", restrictProject).toString should be("""{"data":{"books":[]}}""")
+  }
+
+  def createParent(name: String, childName: String) = {
+    server.query(
+      s"""mutation {
+         |  createParent(data: { name: "$name", tags: { set: ["a", "b"] }, children: { create: [{ name: "$childName" }] } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,83 @@
+use prisma_models::{PrismaValue, ScalarFieldRef, TypeIdentifier};
+
+/// Selections for aggregating records of a model.
+/// Field based selections are expected to only contain fields that support the aggregation,
+/// see `AggregationSelection::supports_field`.
+#[derive(Debug, Clone)]
+pub enum AggregationSelection {
+    /// Counts the records matching the query arguments.
+    Count,
+
+    /// Sums up the values of the given numeric fields.
+    Sum(Vec<ScalarFieldRef>),
+
+    /// Computes the average of the values of the given numeric fields.
+    Average(Vec<ScalarFieldRef>),
+
+    /// Computes the minimum of the values of the given numeric or date fields.
+    Min(Vec<ScalarFieldRef>),
+
+    /// Computes the maximum of the values of the given numeric or date fields.
+    Max(Vec<ScalarFieldRef>),
+}
+
+impl AggregationSelection {
+    /// Checks if the aggregation can be applied to the given scalar field.
+    pub fn supports_field(&self, field: &ScalarFieldRef) -> bool {
+        if field.is_list {
+            return false;
+        }
+
+        match self {
+            Self::Count => false,
+            Self::Sum(_) | Self::Average(_) => Self::is_numeric(field),
+            Self::Min(_) | Self::Max(_) => Self::is_numeric(field) || field.type_identifier == TypeIdentifier::DateTime,
+        }
+    }
+
+    /// The type identifier of the aggregated value for the given field.
+    /// Averages are floats unless they are computed over decimals, all other aggregations keep the type of the field.
+    pub fn result_type(&self, field: &ScalarFieldRef) -> TypeIdentifier {
+        match self {
+            Self::Count => TypeIdentifier::Int,
+            Self::Average(_) if field.type_identifier == TypeIdentifier::Decimal => TypeIdentifier::Decimal,
+            Self::Average(_) => TypeIdentifier::Float,
+            _ => field.type_identifier,
+        }
+    }
+
+    /// The fields the aggregation is applied to. Empty for counts.
+    pub fn fields(&self) -> &[ScalarFieldRef] {
+        match self {
+            Self::Count => &[],
+            Self::Sum(fields) | Self::Average(fields) | Self::Min(fields) | Self::Max(fields) => fields,
+        }
+    }
+
+    fn is_numeric(field: &ScalarFieldRef) -> bool {
+        match field.type_identifier {
+            TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::Decimal => true,
+            _ => false,
+        }
+    }
+}
+
+/// Result of a single aggregation, for field based aggregations one per field.
+#[derive(Debug, Clone)]
+pub enum AggregationResult {
+    Count(usize),
+    Sum(ScalarFieldRef, PrismaValue),
+    Average(ScalarFieldRef, PrismaValue),
+    Min(ScalarFieldRef, PrismaValue),
+    Max(ScalarFieldRef, PrismaValue),
+}
+
+/// A group of records sharing the same values for the grouped fields.
+#[derive(Debug, Clone)]
+pub struct RecordGroup {
+    /// Values of the grouped fields, in the order of the group by fields.
+    pub key: Vec<PrismaValue>,
+
+    /// Aggregation results of the group, in the order of the selections.
+    pub aggregations: Vec<AggregationResult>,
+}
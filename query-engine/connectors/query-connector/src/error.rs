@@ -114,6 +114,9 @@ pub enum ConnectorError {
 
     #[fail(display = "Authentication failed for user '{}'", user)]
     AuthenticationFailed { user: String },
+
+    #[fail(display = "Unsupported transaction isolation level: {}", _0)]
+    UnsupportedIsolationLevel(String),
 }
 
 impl From<DomainError> for ConnectorError {
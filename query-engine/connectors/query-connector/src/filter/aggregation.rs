@@ -0,0 +1,19 @@
+use super::{Filter, ScalarCondition};
+use prisma_models::ScalarFieldRef;
+
+/// Filters groups of records on the result of an aggregation over the group.
+/// Only valid in the context of grouped queries, where it is applied after grouping.
+#[derive(Debug, Clone)]
+pub enum AggregationFilter {
+    Count(ScalarCondition),
+    Sum(ScalarFieldRef, ScalarCondition),
+    Average(ScalarFieldRef, ScalarCondition),
+    Min(ScalarFieldRef, ScalarCondition),
+    Max(ScalarFieldRef, ScalarCondition),
+}
+
+impl From<AggregationFilter> for Filter {
+    fn from(filter: AggregationFilter) -> Self {
+        Filter::Aggregation(filter)
+    }
+}
@@ -4,11 +4,13 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod aggregation;
 mod list;
 mod record_finder;
 mod relation;
 mod scalar;
 
+pub use aggregation::*;
 pub use list::*;
 pub use record_finder::*;
 pub use relation::*;
@@ -25,6 +27,7 @@ pub enum Filter {
     Relation(RelationFilter),
     NodeSubscription,
     BoolFilter(bool),
+    Aggregation(AggregationFilter),
 }
 
 impl Filter {
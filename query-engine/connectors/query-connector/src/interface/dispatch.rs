@@ -51,11 +51,36 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    // This will eventually become a more generic `aggregate`
-    fn count_by_model<'a>(&'a self, model: &'a ModelRef, query_arguments: QueryArguments) -> crate::IO<'a, usize> {
+    fn aggregate_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        selections: Vec<AggregationSelection>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<AggregationResult>> {
+        match self {
+            Self::Connection(c) => c.aggregate_records(model, selections, query_arguments),
+            Self::Transaction(tx) => tx.aggregate_records(model, selections, query_arguments),
+        }
+    }
+
+    fn group_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        selections: Vec<AggregationSelection>,
+        having: Option<Filter>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<RecordGroup>> {
+        match self {
+            Self::Connection(c) => c.group_records(model, group_by, selections, having, query_arguments),
+            Self::Transaction(tx) => tx.group_records(model, group_by, selections, having, query_arguments),
+        }
+    }
+
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<'a, serde_json::Value> {
         match self {
-            Self::Connection(c) => c.count_by_model(model, query_arguments),
-            Self::Transaction(tx) => tx.count_by_model(model, query_arguments),
+            Self::Connection(c) => c.query_raw(query, parameters),
+            Self::Transaction(tx) => tx.query_raw(query, parameters),
         }
     }
 }
@@ -68,6 +93,13 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn create_records<'a>(&'a self, model: &'a ModelRef, args: Vec<WriteArgs>) -> crate::IO<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args),
+            Self::Transaction(tx) => tx.create_records(model, args),
+        }
+    }
+
     fn update_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> crate::IO<Vec<GraphqlId>> {
         match self {
             Self::Connection(c) => c.update_records(model, where_, args),
@@ -82,6 +114,20 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn truncate_data<'a>(&'a self, internal_data_model: &'a InternalDataModelRef) -> crate::IO<()> {
+        match self {
+            Self::Connection(c) => c.truncate_data(internal_data_model),
+            Self::Transaction(tx) => tx.truncate_data(internal_data_model),
+        }
+    }
+
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<usize> {
+        match self {
+            Self::Connection(c) => c.execute_raw(query, parameters),
+            Self::Transaction(tx) => tx.execute_raw(query, parameters),
+        }
+    }
+
     fn connect<'a>(
         &'a self,
         field: &'a RelationFieldRef,
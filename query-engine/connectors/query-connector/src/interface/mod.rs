@@ -2,7 +2,7 @@ mod dispatch;
 
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, RecordFinder, WriteArgs};
+use crate::{AggregationResult, AggregationSelection, Filter, QueryArguments, RecordFinder, RecordGroup, WriteArgs};
 use prisma_models::*;
 
 pub trait Connector {
@@ -11,6 +11,48 @@ pub trait Connector {
 
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     fn start_transaction<'a>(&'a self) -> crate::IO<Box<dyn Transaction + 'a>>;
+
+    /// Starts a transaction running with the given isolation level instead of the database default.
+    fn start_transaction_with_isolation_level<'a>(
+        &'a self,
+        isolation_level: IsolationLevel,
+    ) -> crate::IO<Box<dyn Transaction + 'a>>;
+}
+
+/// Transaction isolation levels as defined by the SQL standard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl std::str::FromStr for IsolationLevel {
+    type Err = crate::error::ConnectorError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s {
+            "ReadUncommitted" => Ok(Self::ReadUncommitted),
+            "ReadCommitted" => Ok(Self::ReadCommitted),
+            "RepeatableRead" => Ok(Self::RepeatableRead),
+            "Serializable" => Ok(Self::Serializable),
+            _ => Err(crate::error::ConnectorError::UnsupportedIsolationLevel(s.to_owned())),
+        }
+    }
+}
+
+impl std::fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Self::ReadUncommitted => "READ UNCOMMITTED",
+            Self::ReadCommitted => "READ COMMITTED",
+            Self::RepeatableRead => "REPEATABLE READ",
+            Self::Serializable => "SERIALIZABLE",
+        };
+
+        s.fmt(f)
+    }
 }
 
 pub trait Transaction<'a>: ReadOperations + WriteOperations + Send + Sync {
@@ -55,8 +97,31 @@ pub trait ReadOperations {
         record_ids: Vec<GraphqlId>,
     ) -> crate::IO<'a, Vec<ScalarListValues>>;
 
-    // This will eventually become a more generic `aggregate`
-    fn count_by_model<'a>(&'a self, model: &'a ModelRef, query_arguments: QueryArguments) -> crate::IO<'a, usize>;
+    /// Aggregates the records of the model that match the query arguments.
+    /// Returns one result for counts and one result per field for field based aggregations,
+    /// in the order of the given selections.
+    fn aggregate_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        selections: Vec<AggregationSelection>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<AggregationResult>>;
+
+    /// Groups the records of the model that match the query arguments by the given fields
+    /// and aggregates each group. Groups not matching the `having` filter are discarded.
+    /// Pagination and ordering of the query arguments apply to the groups.
+    fn group_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        selections: Vec<AggregationSelection>,
+        having: Option<Filter>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<RecordGroup>>;
+
+    /// Runs a raw query with positional parameters against the database.
+    /// Returns the result rows as a JSON array of objects, keyed by column name.
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<'a, serde_json::Value>;
 }
 
 #[derive(Debug, Clone)]
@@ -68,10 +133,22 @@ pub struct ScalarListValues {
 pub trait WriteOperations {
     fn create_record<'a>(&'a self, model: &'a ModelRef, args: WriteArgs) -> crate::IO<GraphqlId>;
 
+    /// Creates many records at once, without returning their IDs. Only the non-list arguments are written,
+    /// nested writes are not supported. Returns the number of created records.
+    fn create_records<'a>(&'a self, model: &'a ModelRef, args: Vec<WriteArgs>) -> crate::IO<usize>;
+
     fn update_records<'a>(&'a self, model: &'a ModelRef, where_: Filter, args: WriteArgs) -> crate::IO<Vec<GraphqlId>>;
 
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> crate::IO<usize>;
 
+    /// Removes all data of the internal data model, including relation and scalar list data,
+    /// and resets sequences. Intended for resetting test databases, not for production use.
+    fn truncate_data<'a>(&'a self, internal_data_model: &'a InternalDataModelRef) -> crate::IO<()>;
+
+    /// Executes a raw statement with positional parameters against the database.
+    /// Returns the number of affected rows.
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<usize>;
+
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
 
     fn connect<'a>(
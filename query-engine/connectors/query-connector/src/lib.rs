@@ -5,11 +5,13 @@ extern crate failure_derive;
 pub mod error;
 pub mod filter;
 
+mod aggregation;
 mod compare;
 mod interface;
 mod query_arguments;
 mod write_args;
 
+pub use aggregation::*;
 pub use compare::*;
 pub use filter::*;
 pub use interface::*;
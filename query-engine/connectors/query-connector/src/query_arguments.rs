@@ -15,7 +15,8 @@ pub struct QueryArguments {
     pub before: Option<GraphqlId>,
     pub last: Option<i64>,
     pub filter: Option<Filter>,
-    pub order_by: Option<OrderBy>,
+    /// Entries in order of precedence. Records are ordered by id if empty.
+    pub order_by: Vec<OrderBy>,
 }
 
 impl QueryArguments {
@@ -23,6 +24,20 @@ impl QueryArguments {
         self.last.or(self.first).or(self.skip).is_some()
     }
 
+    pub fn is_with_cursor(&self) -> bool {
+        self.before.is_some() || self.after.is_some()
+    }
+
+    /// Records before or after a cursor can only be found if it is known where nulls are ordered,
+    /// hence paginating with a cursor positions the nulls of all ordered values explicitly.
+    pub fn with_explicit_nulls_for_cursor(mut self) -> Self {
+        if self.is_with_cursor() {
+            self.order_by = self.order_by.into_iter().map(OrderBy::with_explicit_nulls).collect();
+        }
+
+        self
+    }
+
     pub fn window_limits(&self) -> (i64, i64) {
         let skip = self.skip.unwrap_or(0) + 1;
 
@@ -1,7 +1,7 @@
+use crate::ordering::Ordering;
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
-use std::sync::Arc;
 
 #[derive(Clone, Copy)]
 enum CursorType {
@@ -9,53 +9,44 @@ enum CursorType {
     After,
 }
 
+/// Builds the condition for records before or after the cursor records, in the order of the query arguments.
+/// Records are compared by their ordered fields in order, with the id as the final tie breaker, e.g. ordering
+/// by `a ASC, b DESC` results in `a > a' OR (a = a' AND b < b') OR (a = a' AND b = b' AND id > id')` for after.
+///
+/// Nullable values must have an explicit null position (see `QueryArguments::with_explicit_nulls_for_cursor`),
+/// as nulls don't compare to anything and are placed by their position instead.
 pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree<'static> {
-    match (
-        query_arguments.before.as_ref(),
-        query_arguments.after.as_ref(),
-        query_arguments.order_by.as_ref(),
-    ) {
-        (None, None, _) => ConditionTree::NoCondition,
-        (before, after, order_by) => {
-            let field = match order_by {
-                Some(order) => Arc::clone(&order.field),
-                None => model.fields().id(),
-            };
-
-            let sort_order: SortOrder = order_by.map(|order| order.sort_order).unwrap_or(SortOrder::Ascending);
+    match (query_arguments.before.as_ref(), query_arguments.after.as_ref()) {
+        (None, None) => ConditionTree::NoCondition,
+        (before, after) => {
+            let order_by = &query_arguments.order_by;
 
             let cursor_for = |cursor_type: CursorType, id: GraphqlId| {
                 let model_id = model.fields().id();
-                let where_condition = model_id.as_column().equals(id.clone());
-
-                let select_query = Select::from_table(model.as_table())
-                    .column(field.as_column())
-                    .so_that(ConditionTree::single(where_condition));
-
-                let compare = match (cursor_type, sort_order) {
-                    (CursorType::Before, SortOrder::Ascending) => field
-                        .as_column()
-                        .equals(select_query.clone())
-                        .and(model_id.as_column().less_than(id))
-                        .or(field.as_column().less_than(select_query)),
-                    (CursorType::Before, SortOrder::Descending) => field
-                        .as_column()
-                        .equals(select_query.clone())
-                        .and(model_id.as_column().less_than(id))
-                        .or(field.as_column().greater_than(select_query)),
-                    (CursorType::After, SortOrder::Ascending) => field
-                        .as_column()
-                        .equals(select_query.clone())
-                        .and(model_id.as_column().greater_than(id))
-                        .or(field.as_column().greater_than(select_query)),
-                    (CursorType::After, SortOrder::Descending) => field
-                        .as_column()
-                        .equals(select_query.clone())
-                        .and(model_id.as_column().greater_than(id))
-                        .or(field.as_column().less_than(select_query)),
+
+                // The ordered values of the cursor record.
+                let cursor_value = |oby: &OrderBy| Ordering::related_value(oby, id.clone());
+
+                let id_compare = match cursor_type {
+                    CursorType::Before => model_id.as_column().less_than(id.clone()),
+                    CursorType::After => model_id.as_column().greater_than(id.clone()),
+                };
+
+                let equal_prefix = |count: usize| -> ConditionTree<'static> {
+                    order_by[..count].iter().fold(ConditionTree::NoCondition, |acc, oby| {
+                        ConditionTree::and(acc, equals(oby, Ordering::value(oby), cursor_value(oby)))
+                    })
                 };
 
-                ConditionTree::single(compare)
+                // All ordered fields equal, the id decides.
+                let tie_breaker = ConditionTree::and(equal_prefix(order_by.len()), ConditionTree::single(id_compare));
+
+                order_by.iter().enumerate().fold(tie_breaker, |acc, (index, oby)| {
+                    let compare = beyond(cursor_type, oby, Ordering::value(oby), cursor_value(oby));
+                    let condition = ConditionTree::and(equal_prefix(index), compare);
+
+                    ConditionTree::or(acc, condition)
+                })
             };
 
             let after_cursor = after
@@ -76,3 +67,43 @@ pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree
         }
     }
 }
+
+/// The value of the record equals the value of the cursor record, nulls included.
+fn equals(order_by: &OrderBy, field: DatabaseValue<'static>, value: DatabaseValue<'static>) -> ConditionTree<'static> {
+    let equals = ConditionTree::single(field.clone().equals(value.clone()));
+
+    match order_by.nulls_order {
+        Some(_) => ConditionTree::or(equals, ConditionTree::and(field.is_null(), value.is_null())),
+        None => equals,
+    }
+}
+
+/// The value of the record comes before or after the value of the cursor record. A null value comes
+/// before or after all other values, depending on the position of nulls.
+fn beyond(
+    cursor_type: CursorType,
+    order_by: &OrderBy,
+    field: DatabaseValue<'static>,
+    value: DatabaseValue<'static>,
+) -> ConditionTree<'static> {
+    let compare = match (cursor_type, order_by.sort_order) {
+        (CursorType::Before, SortOrder::Ascending) => field.clone().less_than(value.clone()),
+        (CursorType::Before, SortOrder::Descending) => field.clone().greater_than(value.clone()),
+        (CursorType::After, SortOrder::Ascending) => field.clone().greater_than(value.clone()),
+        (CursorType::After, SortOrder::Descending) => field.clone().less_than(value.clone()),
+    };
+
+    let nulls = match (cursor_type, order_by.nulls_order) {
+        // The record is null, the cursor record isn't.
+        (CursorType::Before, Some(NullsOrder::First)) | (CursorType::After, Some(NullsOrder::Last)) => {
+            ConditionTree::and(field.is_null(), value.is_not_null())
+        }
+        // The cursor record is null, the record isn't.
+        (CursorType::Before, Some(NullsOrder::Last)) | (CursorType::After, Some(NullsOrder::First)) => {
+            ConditionTree::and(value.is_null(), field.is_not_null())
+        }
+        (_, None) => return ConditionTree::single(compare),
+    };
+
+    ConditionTree::or(compare, nulls)
+}
@@ -2,15 +2,21 @@ use super::transaction::SqlConnectorTransaction;
 use crate::{database::operations::*, query_builder::read::ManyRelatedRecordsQueryBuilder, QueryExt, SqlError};
 use connector_interface::{
     self as connector,
+    error::ConnectorError,
     filter::{Filter, RecordFinder},
-    Connection, QueryArguments, ReadOperations, ScalarListValues, Transaction, WriteArgs, WriteOperations, IO,
+    AggregationResult, AggregationSelection, Connection, IsolationLevel, QueryArguments, ReadOperations, RecordGroup,
+    ScalarListValues, Transaction, WriteArgs, WriteOperations, IO,
 };
 use prisma_models::prelude::*;
-use quaint::connector::TransactionCapable;
+use quaint::{
+    connector::{Queryable, TransactionCapable},
+    prelude::SqlFamily,
+};
 use std::marker::PhantomData;
 
 pub struct SqlConnection<C, T> {
     inner: C,
+    sql_family: SqlFamily,
     _p: PhantomData<T>,
 }
 
@@ -19,8 +25,12 @@ where
     C: QueryExt + Send + Sync + 'static,
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
-    pub fn new(inner: C) -> Self {
-        Self { inner, _p: PhantomData }
+    pub fn new(inner: C, sql_family: SqlFamily) -> Self {
+        Self {
+            inner,
+            sql_family,
+            _p: PhantomData,
+        }
     }
 }
 
@@ -31,10 +41,45 @@ where
 {
     fn start_transaction<'a>(&'a self) -> IO<'a, Box<dyn Transaction<'a> + 'a>> {
         let fut_tx = self.inner.start_transaction();
+        let sql_family = self.sql_family;
 
         IO::new(async move {
             let tx: quaint::connector::Transaction<'a> = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx)) as Box<dyn Transaction<'a> + 'a>)
+            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx, sql_family)) as Box<dyn Transaction<'a> + 'a>)
+        })
+    }
+
+    /// MySQL applies `SET TRANSACTION` to the next transaction of the session, PostgreSQL to the current one.
+    /// SQLite transactions are always serializable.
+    fn start_transaction_with_isolation_level<'a>(
+        &'a self,
+        isolation_level: IsolationLevel,
+    ) -> IO<'a, Box<dyn Transaction<'a> + 'a>> {
+        let sql_family = self.sql_family;
+        let statement = format!("SET TRANSACTION ISOLATION LEVEL {}", isolation_level);
+
+        IO::new(async move {
+            match sql_family {
+                SqlFamily::Sqlite if isolation_level != IsolationLevel::Serializable => {
+                    return Err(ConnectorError::UnsupportedIsolationLevel(format!(
+                        "{} (SQLite only supports SERIALIZABLE)",
+                        isolation_level
+                    )));
+                }
+                SqlFamily::Mysql => {
+                    self.inner.execute_raw(&statement, &[]).await.map_err(SqlError::from)?;
+                }
+                _ => (),
+            };
+
+            let tx: quaint::connector::Transaction<'a> =
+                self.inner.start_transaction().await.map_err(SqlError::from)?;
+
+            if let SqlFamily::Postgres = sql_family {
+                tx.execute_raw(&statement, &[]).await.map_err(SqlError::from)?;
+            }
+
+            Ok(Box::new(SqlConnectorTransaction::<T>::new(tx, sql_family)) as Box<dyn Transaction<'a> + 'a>)
         })
     }
 }
@@ -88,8 +133,29 @@ where
         IO::new(async move { read::get_scalar_list_values(&self.inner, list_field, record_ids).await })
     }
 
-    fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(async move { read::count_by_model(&self.inner, model, query_arguments).await })
+    fn aggregate_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        selections: Vec<AggregationSelection>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<AggregationResult>> {
+        IO::new(async move { read::aggregate_records(&self.inner, model, selections, query_arguments).await })
+    }
+
+    fn group_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        selections: Vec<AggregationSelection>,
+        having: Option<Filter>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<RecordGroup>> {
+        IO::new(
+            async move { read::group_records(&self.inner, model, group_by, selections, having, query_arguments).await },
+        )
+    }
+    fn query_raw<'b>(&'b self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<'b, serde_json::Value> {
+        IO::new(async move { read::query_raw(&self.inner, query, parameters).await })
     }
 }
 
@@ -102,6 +168,10 @@ where
         IO::new(async move { write::create_record(&self.inner, model, args).await })
     }
 
+    fn create_records<'a>(&'a self, model: &'a ModelRef, args: Vec<WriteArgs>) -> connector::IO<usize> {
+        IO::new(async move { write::create_records(&self.inner, self.sql_family, model, args).await })
+    }
+
     fn update_records<'a>(
         &'a self,
         model: &'a ModelRef,
@@ -115,6 +185,14 @@ where
         IO::new(async move { write::delete_records(&self.inner, model, where_).await })
     }
 
+    fn truncate_data<'a>(&'a self, internal_data_model: &'a InternalDataModelRef) -> connector::IO<()> {
+        IO::new(async move { write::truncate_data(&self.inner, self.sql_family, internal_data_model).await })
+    }
+
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<usize> {
+        IO::new(async move { write::execute_raw(&self.inner, query, parameters).await })
+    }
+
     fn connect<'a>(
         &'a self,
         field: &'a RelationFieldRef,
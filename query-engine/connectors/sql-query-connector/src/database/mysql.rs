@@ -20,7 +20,8 @@ impl Connector for Mysql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithUnionAll>::new(conn);
+            let sql_family = self.pool.connection_info().sql_family();
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithUnionAll>::new(conn, sql_family);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -1,6 +1,6 @@
 use crate::{
     query_builder::read::{self, ManyRelatedRecordsBaseQuery, ManyRelatedRecordsQueryBuilder},
-    QueryExt, SqlError,
+    QueryExt, RawQuery, SqlError,
 };
 use connector_interface::{error::ConnectorError, *};
 use itertools::Itertools;
@@ -141,13 +141,118 @@ pub async fn get_scalar_list_values(
     Ok(list_values)
 }
 
-pub async fn count_by_model(
+pub async fn aggregate_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
+    selections: Vec<AggregationSelection>,
     query_arguments: QueryArguments,
-) -> connector_interface::Result<usize> {
-    let query = read::count_by_model(model, query_arguments);
-    let result = conn.find_int(query).await? as usize;
+) -> connector_interface::Result<Vec<AggregationResult>> {
+    let query = read::aggregate(model, &selections, query_arguments);
+    let idents = aggregation_idents(&selections);
+    let mut values = conn.find(query, idents.as_slice()).await?.values.into_iter();
 
-    Ok(result)
+    map_aggregation_results(selections, &mut values)
+}
+
+pub async fn group_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    group_by: Vec<ScalarFieldRef>,
+    selections: Vec<AggregationSelection>,
+    having: Option<Filter>,
+    query_arguments: QueryArguments,
+) -> connector_interface::Result<Vec<RecordGroup>> {
+    let query = read::group_by(model, &group_by, &selections, having, query_arguments);
+    let mut idents: Vec<TypeIdentifier> = group_by.iter().map(|f| f.type_identifier).collect();
+    idents.extend(aggregation_idents(&selections));
+
+    let rows = conn.filter(query.into(), idents.as_slice()).await?;
+
+    rows.into_iter()
+        .map(|row| {
+            let mut values = row.values.into_iter();
+            let key = values.by_ref().take(group_by.len()).collect();
+            let aggregations = map_aggregation_results(selections.clone(), &mut values)?;
+
+            Ok(RecordGroup { key, aggregations })
+        })
+        .collect()
+}
+
+fn aggregation_idents(selections: &[AggregationSelection]) -> Vec<TypeIdentifier> {
+    selections
+        .iter()
+        .flat_map(|selection| match selection {
+            AggregationSelection::Count => vec![TypeIdentifier::Int],
+            _ => selection.fields().iter().map(|f| selection.result_type(f)).collect(),
+        })
+        .collect()
+}
+
+/// Maps the aggregated values of a row to results, consuming one value per count
+/// and one value per field of field based aggregations.
+fn map_aggregation_results(
+    selections: Vec<AggregationSelection>,
+    values: &mut impl Iterator<Item = PrismaValue>,
+) -> connector_interface::Result<Vec<AggregationResult>> {
+    let mut next_value = || values.next().ok_or(SqlError::ColumnDoesNotExist);
+    let mut results = vec![];
+
+    for selection in selections {
+        match selection {
+            AggregationSelection::Count => {
+                let count = i64::try_from(next_value()?)?;
+                results.push(AggregationResult::Count(count as usize));
+            }
+            AggregationSelection::Sum(fields) => {
+                for field in fields {
+                    let value = normalize_sum(&field, next_value()?);
+                    results.push(AggregationResult::Sum(field, value));
+                }
+            }
+            AggregationSelection::Average(fields) => {
+                for field in fields {
+                    results.push(AggregationResult::Average(field, next_value()?));
+                }
+            }
+            AggregationSelection::Min(fields) => {
+                for field in fields {
+                    results.push(AggregationResult::Min(field, next_value()?));
+                }
+            }
+            AggregationSelection::Max(fields) => {
+                for field in fields {
+                    results.push(AggregationResult::Max(field, next_value()?));
+                }
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// MySQL and PostgreSQL return sums of integer columns as decimals,
+/// which are converted back to integers here if they fit. Sums of other fields are left alone.
+fn normalize_sum(field: &ScalarFieldRef, value: PrismaValue) -> PrismaValue {
+    match (field.type_identifier, value) {
+        (TypeIdentifier::Int, PrismaValue::Float(f)) if is_integral(f) => PrismaValue::Int(f as i64),
+        (TypeIdentifier::Int, PrismaValue::String(s)) => match s.parse::<i64>() {
+            Ok(i) => PrismaValue::Int(i),
+            Err(_) => PrismaValue::String(s),
+        },
+        (_, value) => value,
+    }
+}
+
+/// Returns whether the float is a whole number that can be represented as an `i64` without loss.
+fn is_integral(f: f64) -> bool {
+    f.fract() == 0.0 && f >= i64::min_value() as f64 && f < i64::max_value() as f64
+}
+
+pub async fn query_raw(
+    conn: &dyn QueryExt,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> connector_interface::Result<serde_json::Value> {
+    Ok(conn.raw_json(RawQuery::new(query, parameters)).await?)
 }
@@ -1,7 +1,11 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{error::SqlError, query_builder::write, QueryExt, RawQuery};
 use connector_interface::{error::ConnectorError, *};
 use prisma_models::*;
-use quaint::error::Error as QueryError;
+use quaint::{
+    ast::{Comparable, Conjuctive, Select},
+    error::Error as QueryError,
+    prelude::SqlFamily,
+};
 
 pub async fn create_record(
     conn: &dyn QueryExt,
@@ -54,6 +58,39 @@ pub async fn create_record(
     Ok(id)
 }
 
+pub async fn create_records(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+) -> connector_interface::Result<usize> {
+    let count = args.len();
+    let args = args.into_iter().map(|args| args.non_list_args().clone()).collect();
+
+    for insert in write::create_records(sql_family, model, args) {
+        conn.execute(insert.into()).await.map_err(|err| {
+            let field_name = |field_name: String| match field_name.as_str() {
+                "PRIMARY" => format!("{}.{}", model.name, model.fields().id().name),
+                _ => format!("{}.{}", model.name, field_name),
+            };
+
+            match err {
+                QueryError::UniqueConstraintViolation { field_name: name } => {
+                    ConnectorError::UniqueConstraintViolation {
+                        field_name: field_name(name),
+                    }
+                }
+                QueryError::NullConstraintViolation { field_name: name } => ConnectorError::NullConstraintViolation {
+                    field_name: field_name(name),
+                },
+                err => SqlError::from(err).into(),
+            }
+        })?;
+    }
+
+    Ok(count)
+}
+
 pub async fn update_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
@@ -135,3 +172,88 @@ pub async fn disconnect(
     conn.execute(query).await.map_err(SqlError::from)?;
     Ok(())
 }
+
+pub async fn execute_raw(
+    conn: &dyn QueryExt,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> connector_interface::Result<usize> {
+    Ok(conn.raw_count(RawQuery::new(query, parameters)).await?)
+}
+
+/// Removes all data of the internal data model and resets the sequences. Foreign key checks are
+/// suspended while deleting, in the way the database family supports it:
+///
+/// - PostgreSQL truncates all tables in one statement, which cascades across foreign keys.
+/// - MySQL disables foreign key checks for the session, and enables them again whatever happens.
+///   The data is deleted within the transaction, but resetting the counters commits it.
+/// - SQLite defers foreign key checks to the end of the transaction, as the `foreign_keys` pragma
+///   is a no-op inside of transactions. Restricting foreign keys are checked right away even then,
+///   so the tables are deleted from in order, referencing tables first.
+pub async fn truncate_data(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    internal_data_model: &InternalDataModelRef,
+) -> connector_interface::Result<()> {
+    let schema_name = internal_data_model.db_name.as_str();
+    let tables = write::data_tables(internal_data_model);
+
+    if tables.is_empty() {
+        return Ok(());
+    }
+
+    match sql_family {
+        SqlFamily::Postgres => {
+            let truncate = write::truncate_postgres_tables(schema_name, &tables);
+            conn.execute_raw(truncate.as_str(), &[]).await.map_err(SqlError::from)?;
+        }
+        SqlFamily::Mysql => {
+            conn.execute_raw("SET FOREIGN_KEY_CHECKS = 0", &[])
+                .await
+                .map_err(SqlError::from)?;
+
+            let deleted = delete_all(conn, schema_name, &tables).await;
+            let checks_enabled = conn.execute_raw("SET FOREIGN_KEY_CHECKS = 1", &[]).await;
+
+            deleted?;
+            checks_enabled.map_err(SqlError::from)?;
+
+            for statement in write::reset_mysql_auto_increments(schema_name, &tables) {
+                conn.execute_raw(statement.as_str(), &[])
+                    .await
+                    .map_err(SqlError::from)?;
+            }
+        }
+        SqlFamily::Sqlite => {
+            conn.execute_raw("PRAGMA defer_foreign_keys = ON", &[])
+                .await
+                .map_err(SqlError::from)?;
+
+            delete_all(conn, schema_name, &tables).await?;
+
+            // SQLite only keeps sequences for `AUTOINCREMENT` tables, in a table that may not exist yet.
+            let sequences_exist = Select::from_table((schema_name.to_string(), "sqlite_master".to_string()))
+                .column("name")
+                .so_that("type".equals("table").and("name".equals("sqlite_sequence")));
+
+            if !conn
+                .filter(sequences_exist.into(), &[TypeIdentifier::String])
+                .await?
+                .is_empty()
+            {
+                let reset = write::reset_sqlite_sequences(schema_name, &tables);
+                conn.delete(reset).await.map_err(SqlError::from)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn delete_all(conn: &dyn QueryExt, schema_name: &str, tables: &[String]) -> connector_interface::Result<()> {
+    for delete in write::delete_all(schema_name, tables) {
+        conn.delete(delete).await.map_err(SqlError::from)?;
+    }
+
+    Ok(())
+}
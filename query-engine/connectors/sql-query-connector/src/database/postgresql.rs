@@ -20,7 +20,8 @@ impl Connector for PostgreSql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn);
+            let sql_family = self.pool.connection_info().sql_family();
+            let conn = SqlConnection::<_, ManyRelatedRecordsWithRowNumber>::new(conn, sql_family);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -3,20 +3,24 @@ use crate::{query_builder::read::ManyRelatedRecordsQueryBuilder, SqlError};
 use connector_interface::{
     self as connector,
     filter::{Filter, RecordFinder},
-    QueryArguments, ReadOperations, ScalarListValues, Transaction, WriteArgs, WriteOperations, IO,
+    AggregationResult, AggregationSelection, QueryArguments, ReadOperations, RecordGroup, ScalarListValues,
+    Transaction, WriteArgs, WriteOperations, IO,
 };
 use prisma_models::prelude::*;
+use quaint::prelude::SqlFamily;
 use std::marker::PhantomData;
 
 pub struct SqlConnectorTransaction<'a, T> {
     inner: quaint::connector::Transaction<'a>,
+    sql_family: SqlFamily,
     _p: PhantomData<T>,
 }
 
 impl<'a, T> SqlConnectorTransaction<'a, T> {
-    pub fn new(tx: quaint::connector::Transaction<'a>) -> Self {
+    pub fn new(tx: quaint::connector::Transaction<'a>, sql_family: SqlFamily) -> Self {
         Self {
             inner: tx,
+            sql_family,
             _p: PhantomData,
         }
     }
@@ -83,8 +87,29 @@ where
         IO::new(async move { read::get_scalar_list_values(&self.inner, list_field, record_ids).await })
     }
 
-    fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(async move { read::count_by_model(&self.inner, model, query_arguments).await })
+    fn aggregate_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        selections: Vec<AggregationSelection>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<AggregationResult>> {
+        IO::new(async move { read::aggregate_records(&self.inner, model, selections, query_arguments).await })
+    }
+
+    fn group_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        selections: Vec<AggregationSelection>,
+        having: Option<Filter>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<RecordGroup>> {
+        IO::new(
+            async move { read::group_records(&self.inner, model, group_by, selections, having, query_arguments).await },
+        )
+    }
+    fn query_raw<'b>(&'b self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<'b, serde_json::Value> {
+        IO::new(async move { read::query_raw(&self.inner, query, parameters).await })
     }
 }
 
@@ -96,6 +121,10 @@ where
         IO::new(async move { write::create_record(&self.inner, model, args).await })
     }
 
+    fn create_records<'b>(&'b self, model: &'b ModelRef, args: Vec<WriteArgs>) -> connector::IO<usize> {
+        IO::new(async move { write::create_records(&self.inner, self.sql_family, model, args).await })
+    }
+
     fn update_records<'b>(
         &'b self,
         model: &'b ModelRef,
@@ -109,6 +138,14 @@ where
         IO::new(async move { write::delete_records(&self.inner, model, where_).await })
     }
 
+    fn truncate_data<'b>(&'b self, internal_data_model: &'b InternalDataModelRef) -> connector::IO<()> {
+        IO::new(async move { write::truncate_data(&self.inner, self.sql_family, internal_data_model).await })
+    }
+
+    fn execute_raw<'b>(&'b self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<usize> {
+        IO::new(async move { write::execute_raw(&self.inner, query, parameters).await })
+    }
+
     fn connect<'b>(
         &'b self,
         field: &'b RelationFieldRef,
@@ -176,6 +176,12 @@ impl From<serde_json::error::Error> for SqlError {
     }
 }
 
+impl From<rust_decimal::Error> for SqlError {
+    fn from(e: rust_decimal::Error) -> SqlError {
+        SqlError::ConversionError(e.into())
+    }
+}
+
 impl From<url::ParseError> for SqlError {
     fn from(_: url::ParseError) -> SqlError {
         SqlError::DatabaseCreationError("Error parsing database connection string.")
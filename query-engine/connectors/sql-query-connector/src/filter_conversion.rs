@@ -122,6 +122,8 @@ impl AliasedCondition for Filter {
             Filter::Scalar(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Aggregation(filter) => filter.aliased_cond(alias),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -129,7 +131,7 @@ impl AliasedCondition for Filter {
                     ConditionTree::NegativeCondition
                 }
             }
-            _ => unimplemented!(),
+            Filter::NodeSubscription => unimplemented!(),
         }
     }
 }
@@ -142,39 +144,147 @@ impl AliasedCondition for ScalarFilter {
             None => self.field.as_column(),
         };
 
-        let condition = match self.condition {
-            ScalarCondition::Equals(PrismaValue::Null) => column.is_null(),
-            ScalarCondition::NotEquals(PrismaValue::Null) => column.is_not_null(),
-            ScalarCondition::Equals(value) => column.equals(value),
-            ScalarCondition::NotEquals(value) => column.not_equals(value),
-            ScalarCondition::Contains(value) => column.like(format!("{}", value)),
-            ScalarCondition::NotContains(value) => column.not_like(format!("{}", value)),
-            ScalarCondition::StartsWith(value) => column.begins_with(format!("{}", value)),
-            ScalarCondition::NotStartsWith(value) => column.not_begins_with(format!("{}", value)),
-            ScalarCondition::EndsWith(value) => column.ends_into(format!("{}", value)),
-            ScalarCondition::NotEndsWith(value) => column.not_ends_into(format!("{}", value)),
-            ScalarCondition::LessThan(value) => column.less_than(value),
-            ScalarCondition::LessThanOrEquals(value) => column.less_than_or_equals(value),
-            ScalarCondition::GreaterThan(value) => column.greater_than(value),
-            ScalarCondition::GreaterThanOrEquals(value) => column.greater_than_or_equals(value),
-            // We need to preserve the split first semantic for protobuf
-            ScalarCondition::In(Some(values)) => match values.split_first() {
-                Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_null(),
-                _ => column.in_selection(values),
-            },
-            // We need to preserve the split first semantic for protobuf
-            ScalarCondition::NotIn(Some(values)) => match values.split_first() {
-                Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_not_null(),
-                _ => column.not_in_selection(values),
-            },
-            ScalarCondition::In(None) => column.is_null(),
-            ScalarCondition::NotIn(None) => column.is_not_null(),
+        ConditionTree::single(convert_scalar_condition(column, self.condition))
+    }
+}
+
+impl AliasedCondition for AggregationFilter {
+    /// Conversion from an `AggregationFilter` to a query condition tree comparing the aggregated value.
+    /// Only valid in the `HAVING` clause of a grouped query.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let column = |field: &ScalarFieldRef| match alias {
+            Some(ref alias) => field.as_column().table(alias.to_string(None)),
+            None => field.as_column(),
         };
 
-        ConditionTree::single(condition)
+        let (aggregation, condition): (DatabaseValue<'static>, ScalarCondition) = match self {
+            AggregationFilter::Count(condition) => (count(asterisk()).into(), condition),
+            AggregationFilter::Sum(field, condition) => (sum(column(&field)).into(), condition),
+            AggregationFilter::Average(field, condition) => (avg(column(&field)).into(), condition),
+            AggregationFilter::Min(field, condition) => (min(column(&field)).into(), condition),
+            AggregationFilter::Max(field, condition) => (max(column(&field)).into(), condition),
+        };
+
+        ConditionTree::single(convert_scalar_condition(aggregation, condition))
+    }
+}
+
+/// Applies a scalar condition to anything comparable, e.g. a column or an aggregation.
+fn convert_scalar_condition<T>(comparable: T, condition: ScalarCondition) -> Compare<'static>
+where
+    T: Comparable<'static>,
+{
+    match condition {
+        ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
+        ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
+        ScalarCondition::Equals(value) => comparable.equals(value),
+        ScalarCondition::NotEquals(value) => comparable.not_equals(value),
+        ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
+        ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
+        ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
+        ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
+        ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
+        ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
+        ScalarCondition::LessThan(value) => comparable.less_than(value),
+        ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
+        ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
+        ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
+        // We need to preserve the split first semantic for protobuf
+        ScalarCondition::In(Some(values)) => match values.split_first() {
+            Some((PrismaValue::Null, tail)) if tail.is_empty() => comparable.is_null(),
+            _ => comparable.in_selection(values),
+        },
+        // We need to preserve the split first semantic for protobuf
+        ScalarCondition::NotIn(Some(values)) => match values.split_first() {
+            Some((PrismaValue::Null, tail)) if tail.is_empty() => comparable.is_not_null(),
+            _ => comparable.not_in_selection(values),
+        },
+        ScalarCondition::In(None) => comparable.is_null(),
+        ScalarCondition::NotIn(None) => comparable.is_not_null(),
     }
 }
 
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    /// Native (embedded) lists are compared with the array operators, lists stored in a separate
+    /// table are compared by selecting the ids of the records holding matching values.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        match self.field.scalar_list_behaviour_clone() {
+            Some(FieldBehaviour::ScalarList {
+                strategy: ScalarListStrategy::Embedded,
+            }) => {
+                let column = match alias {
+                    Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
+                    None => self.field.as_column(),
+                };
+
+                let condition = match self.condition {
+                    ScalarListCondition::Contains(value) => column.array_contains(array_value(vec![value])),
+                    ScalarListCondition::ContainsEvery(values) => column.array_contains(array_value(values)),
+                    ScalarListCondition::ContainsSome(values) => column.array_overlaps(array_value(values)),
+                    ScalarListCondition::ContainsNone => column.equals(array_value(vec![])),
+                };
+
+                ConditionTree::single(condition)
+            }
+            _ => {
+                let id = self.field.model().fields().id().as_column();
+                let id_column = match alias {
+                    Some(ref alias) => id.table(alias.to_string(None)),
+                    None => id,
+                };
+
+                let list_table = self.field.scalar_list_table();
+                let node_ids = |condition: ConditionTree<'static>| {
+                    Select::from_table(list_table.table())
+                        .column(list_table.node_id_column())
+                        .so_that(condition)
+                };
+
+                match self.condition {
+                    ScalarListCondition::Contains(value) => id_column
+                        .in_selection(node_ids(list_table.value_column().equals(value).into()))
+                        .into(),
+                    ScalarListCondition::ContainsEvery(values) => {
+                        values.into_iter().fold(ConditionTree::NoCondition, |acc, value| {
+                            let condition = id_column
+                                .clone()
+                                .in_selection(node_ids(list_table.value_column().equals(value).into()));
+
+                            match acc {
+                                ConditionTree::NoCondition => condition.into(),
+                                acc => ConditionTree::and(acc, condition),
+                            }
+                        })
+                    }
+                    ScalarListCondition::ContainsSome(ref values) if values.is_empty() => {
+                        ConditionTree::NegativeCondition
+                    }
+                    ScalarListCondition::ContainsSome(values) => id_column
+                        .in_selection(node_ids(list_table.value_column().in_selection(values).into()))
+                        .into(),
+                    ScalarListCondition::ContainsNone => {
+                        id_column.not_in_selection(node_ids(ConditionTree::NoCondition)).into()
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Converts the given values into a single array parameter.
+fn array_value(values: Vec<PrismaValue>) -> DatabaseValue<'static> {
+    let elements = values
+        .into_iter()
+        .map(|value| match DatabaseValue::from(value) {
+            DatabaseValue::Parameterized(value) => value,
+            _ => unreachable!("Scalar list elements are always parameterized values."),
+        })
+        .collect();
+
+    DatabaseValue::Parameterized(ParameterizedValue::Array(elements))
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -7,77 +7,233 @@ pub struct Ordering;
 
 /// Tooling for generating orderings for different query types.
 impl Ordering {
-    pub fn for_model(model: ModelRef, order_by: Option<&OrderBy>, reverse: bool) -> OrderVec<'static> {
+    const TABLE_ALIAS: &'static str = "prismaOrderByTable";
+    const RELATION_TABLE_ALIAS: &'static str = "prismaOrderByRelation";
+    const NULLS_TABLE_ALIAS: &'static str = "prismaOrderByNulls";
+
+    pub fn for_model(model: ModelRef, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static> {
         Self::by_fields(
-            order_by.map(|oby| oby.field.as_column()),
+            order_by.iter().map(|oby| (Self::value(oby), oby)).collect(),
             model.fields().id().as_column(),
-            order_by,
             reverse,
         )
     }
 
-    pub fn internal<C>(second_field: C, order_by: Option<&OrderBy>, reverse: bool) -> OrderVec<'static>
+    /// Orders by the given values, with the second field being the (aliased) id of the ordered records.
+    /// Values reached through relations are selected based on that id, not on the unaliased id column.
+    pub fn internal<C>(second_field: C, order_by: &[OrderBy], reverse: bool) -> OrderVec<'static>
     where
         C: Into<Column<'static>>,
     {
+        let second_column: Column<'static> = second_field.into();
+
         Self::by_fields(
-            order_by.map(|oby| oby.field.as_column()),
-            second_field.into(),
-            order_by,
+            order_by
+                .iter()
+                .map(|oby| {
+                    let value = match oby.scalar_field() {
+                        Some(field) => field.as_column().into(),
+                        None => Self::related_value(oby, second_column.clone()),
+                    };
+
+                    (value, oby)
+                })
+                .collect(),
+            second_column,
             reverse,
         )
     }
 
+    /// Orders by the columns of an aliased subquery, with the secondary column being the id of the ordered records.
+    /// Values reached through relations are selected based on that id.
     pub fn aliased_internal(
         alias: &str,
         secondary_alias: &str,
         secondary_field: &str,
-        order_by: Option<&OrderBy>,
+        order_by: &[OrderBy],
         reverse: bool,
     ) -> OrderVec<'static> {
+        let secondary_column: Column<'static> = (secondary_alias.to_string(), secondary_field.to_string()).into();
+
         Self::by_fields(
-            order_by.map(|oby| (alias.to_string(), oby.field.db_name().to_string()).into()),
-            (secondary_alias.to_string(), secondary_field.to_string()).into(),
-            order_by,
+            order_by
+                .iter()
+                .map(|oby| {
+                    let value = match oby.scalar_field() {
+                        Some(field) => Column::from((alias.to_string(), field.db_name().to_string())).into(),
+                        None => Self::related_value(oby, secondary_column.clone()),
+                    };
+
+                    (value, oby)
+                })
+                .collect(),
+            secondary_column,
             reverse,
         )
     }
 
+    /// Orders groups by the ordered fields first, followed by the remaining grouped fields
+    /// to keep the ordering stable. Groups have no id to fall back to.
+    ///
+    /// Nulls are positioned by the number of non-null values of the group, which is zero exactly
+    /// for the group of nulls, as all records of a group share the grouped value.
+    pub fn for_groups(group_by: &[ScalarFieldRef], order_by: &[OrderBy]) -> OrderVec<'static> {
+        let mut ordering: OrderVec<'static> = vec![];
+
+        for oby in order_by {
+            match (oby.scalar_field(), oby.nulls_order) {
+                (Some(field), Some(NullsOrder::First)) => {
+                    ordering.push((count(field.as_column()).into(), Some(Order::Asc)))
+                }
+                (Some(field), Some(NullsOrder::Last)) => {
+                    ordering.push((count(field.as_column()).into(), Some(Order::Desc)))
+                }
+                _ => (),
+            }
+
+            ordering.push((Self::value(oby), Some(Self::order(oby, false))));
+        }
+
+        let remaining = group_by
+            .iter()
+            .filter(|field| {
+                order_by
+                    .iter()
+                    .all(|oby| oby.scalar_field().map(|f| f.name != field.name).unwrap_or(true))
+            })
+            .map(|field| field.as_column().ascend());
+
+        ordering.extend(remaining);
+        ordering
+    }
+
+    /// The value records are ordered by. Values reached through relations are selected
+    /// with a subquery correlated to the id of the ordered record.
+    pub fn value(order_by: &OrderBy) -> DatabaseValue<'static> {
+        match order_by.scalar_field() {
+            Some(field) => field.as_column().into(),
+            None => Self::related_value(order_by, order_by.model().fields().id().as_column()),
+        }
+    }
+
+    /// Selects the value of the entry for the record with the given id, following the relations of the path.
+    /// Every table is aliased by its depth on the path, to not clash with the tables of the outer query.
+    pub fn related_value<T>(order_by: &OrderBy, id: T) -> DatabaseValue<'static>
+    where
+        T: Into<DatabaseValue<'static>>,
+    {
+        Self::related_value_at(&order_by.path, &order_by.target, id.into(), 0)
+    }
+
+    fn related_value_at(
+        path: &[RelationFieldRef],
+        target: &OrderByTarget,
+        id: DatabaseValue<'static>,
+        depth: usize,
+    ) -> DatabaseValue<'static> {
+        let relation_alias = format!("{}{}", Self::RELATION_TABLE_ALIAS, depth);
+
+        match (path.split_first(), target) {
+            (Some((rf, rest)), _) => {
+                let table_alias = format!("{}{}", Self::TABLE_ALIAS, depth);
+                let related_model = rf.related_model();
+                let related_id = related_model.fields().id().as_column().table(table_alias.clone());
+
+                let value = match (rest.is_empty(), target) {
+                    (true, OrderByTarget::Field(field)) => field.as_column().table(table_alias.clone()).into(),
+                    _ => Self::related_value_at(rest, target, related_id.clone().into(), depth + 1),
+                };
+
+                let join = rf
+                    .relation()
+                    .as_table()
+                    .alias(relation_alias.clone())
+                    .on(related_id.equals(rf.opposite_column().table(relation_alias.clone())));
+
+                Select::from_table(related_model.as_table().alias(table_alias))
+                    .value(value)
+                    .inner_join(join)
+                    .so_that(rf.relation_column().table(relation_alias).equals(id))
+                    .into()
+            }
+
+            (None, OrderByTarget::RelationCount(rf)) => {
+                Select::from_table(rf.relation().as_table().alias(relation_alias.clone()))
+                    .value(count(asterisk()))
+                    .so_that(rf.relation_column().table(relation_alias).equals(id))
+                    .into()
+            }
+
+            (None, OrderByTarget::Field(field)) => {
+                let table_alias = format!("{}{}", Self::TABLE_ALIAS, depth);
+                let model = field.model();
+
+                Select::from_table(model.as_table().alias(table_alias.clone()))
+                    .value(field.as_column().table(table_alias.clone()))
+                    .so_that(model.fields().id().as_column().table(table_alias).equals(id))
+                    .into()
+            }
+        }
+    }
+
+    /// Selects whether the given value is null, as 1 or 0. Ordering by it first positions the nulls, which
+    /// not every database can do on its own (`NULLS FIRST` / `NULLS LAST`). Conditions can't be ordered by
+    /// directly, so the value is tested against a single constant row, without reading any table.
+    fn is_null_value(value: DatabaseValue<'static>) -> DatabaseValue<'static> {
+        let single_row = Table::from(Select::default().value(count(asterisk()))).alias(Self::NULLS_TABLE_ALIAS);
+
+        Select::from_table(single_row)
+            .value(count(asterisk()))
+            .so_that(value.is_null())
+            .into()
+    }
+
+    /// Orders by the given values in order, followed by the second column as a tie breaker, unless it's
+    /// already part of the ordering. Reversing flips every direction, including the position of nulls.
+    /// Values with a null position are preceded by whether they are null.
     fn by_fields(
-        first_column: Option<Column<'static>>,
+        values: Vec<(DatabaseValue<'static>, &OrderBy)>,
         second_column: Column<'static>,
-        order_by: Option<&OrderBy>,
         reverse: bool,
     ) -> OrderVec<'static> {
-        let default_order = order_by
-            .as_ref()
-            .map(|order| order.sort_order)
-            .unwrap_or(SortOrder::Ascending);
-
-        match first_column {
-            Some(first) => {
-                if first != second_column {
-                    match (default_order, reverse) {
-                        (SortOrder::Ascending, true) => vec![first.descend(), second_column.descend()],
-                        (SortOrder::Descending, true) => vec![first.ascend(), second_column.descend()],
-                        (SortOrder::Ascending, false) => vec![first.ascend(), second_column.ascend()],
-                        (SortOrder::Descending, false) => vec![first.descend(), second_column.ascend()],
-                    }
-                } else {
-                    match (default_order, reverse) {
-                        (SortOrder::Ascending, true) => vec![second_column.descend()],
-                        (SortOrder::Descending, true) => vec![second_column.ascend()],
-                        (SortOrder::Ascending, false) => vec![second_column.ascend()],
-                        (SortOrder::Descending, false) => vec![second_column.descend()],
-                    }
-                }
+        let second_value: DatabaseValue<'static> = second_column.clone().into();
+        let contains_second = values.iter().any(|(value, _)| *value == second_value);
+        let mut ordering: OrderVec<'static> = vec![];
+
+        for (value, oby) in values {
+            match Self::nulls_order(oby, reverse) {
+                Some(NullsOrder::First) => ordering.push((Self::is_null_value(value.clone()), Some(Order::Desc))),
+                Some(NullsOrder::Last) => ordering.push((Self::is_null_value(value.clone()), Some(Order::Asc))),
+                None => (),
+            }
+
+            ordering.push((value, Some(Self::order(oby, reverse))));
+        }
+
+        if !contains_second {
+            if reverse {
+                ordering.push(second_column.descend());
+            } else {
+                ordering.push(second_column.ascend());
             }
-            _ => match (default_order, reverse) {
-                (SortOrder::Ascending, true) => vec![second_column.descend()],
-                (SortOrder::Descending, true) => vec![second_column.ascend()],
-                (SortOrder::Ascending, false) => vec![second_column.ascend()],
-                (SortOrder::Descending, false) => vec![second_column.descend()],
-            },
+        }
+
+        ordering
+    }
+
+    fn order(order_by: &OrderBy, reverse: bool) -> Order {
+        match (order_by.sort_order, reverse) {
+            (SortOrder::Ascending, false) | (SortOrder::Descending, true) => Order::Asc,
+            (SortOrder::Descending, false) | (SortOrder::Ascending, true) => Order::Desc,
+        }
+    }
+
+    fn nulls_order(order_by: &OrderBy, reverse: bool) -> Option<NullsOrder> {
+        match (order_by.nulls_order, reverse) {
+            (nulls_order, false) => nulls_order,
+            (Some(NullsOrder::First), true) => Some(NullsOrder::Last),
+            (Some(NullsOrder::Last), true) => Some(NullsOrder::First),
+            (None, true) => None,
         }
     }
 }
@@ -8,7 +8,7 @@ pub struct ManyRelatedRecordsBaseQuery<'a> {
     pub selected_fields: &'a SelectedFields,
     pub from_record_ids: &'a [GraphqlId],
     pub query: Select<'a>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
     pub is_reverse_order: bool,
     pub condition: ConditionTree<'a>,
     pub cursor: ConditionTree<'a>,
@@ -23,6 +23,7 @@ impl<'a> ManyRelatedRecordsBaseQuery<'a> {
         query_arguments: QueryArguments,
         selected_fields: &'a SelectedFields,
     ) -> ManyRelatedRecordsBaseQuery<'a> {
+        let query_arguments = query_arguments.with_explicit_nulls_for_cursor();
         let cursor = cursor_condition::build(&query_arguments, from_field.related_model());
         let window_limits = query_arguments.window_limits();
         let skip_and_limit = query_arguments.skip_and_limit();
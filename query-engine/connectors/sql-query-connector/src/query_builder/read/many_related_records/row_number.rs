@@ -17,11 +17,14 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithRowNumber {
 
         let mut base_query = base.query.so_that(conditions);
 
-        if let Some(order_by) = base.order_by.as_ref() {
-            let column = order_by.field.as_column();
+        let selected_columns = base.selected_fields.columns();
 
-            if !base.selected_fields.columns().contains(&column) {
-                base_query = base_query.column(order_by.field.as_column());
+        // Values reached through relations are selected by the ordering itself, based on the selected id.
+        for field in base.order_by.iter().filter_map(|order_by| order_by.scalar_field()) {
+            let column = field.as_column();
+
+            if !selected_columns.contains(&column) {
+                base_query = base_query.column(column);
             }
         }
 
@@ -29,7 +32,7 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithRowNumber {
             Self::BASE_TABLE_ALIAS,
             Self::BASE_TABLE_ALIAS,
             SelectedFields::RELATED_MODEL_ALIAS,
-            base.order_by.as_ref(),
+            &base.order_by,
             base.is_reverse_order,
         );
 
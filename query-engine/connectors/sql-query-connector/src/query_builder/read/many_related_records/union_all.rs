@@ -15,11 +15,9 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithUnionAll {
             ids
         };
 
-        let order_columns = Ordering::internal(
-            SelectedFields::RELATED_MODEL_ALIAS,
-            base.order_by.as_ref(),
-            base.is_reverse_order,
-        );
+        // Ordered by the id in the relation table, as subqueries for related values can't refer to the selected alias.
+        let opposite_column = base.from_field.opposite_column().table(Relation::TABLE_ALIAS);
+        let order_columns = Ordering::internal(opposite_column, &base.order_by, base.is_reverse_order);
 
         let base_condition = base.condition.and(base.cursor);
         let from_field = base.from_field;
@@ -3,7 +3,7 @@ mod many_related_records;
 use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering::Ordering};
 use connector_interface::{
     filter::{Filter, RecordFinder},
-    QueryArguments,
+    AggregationSelection, QueryArguments,
 };
 use prisma_models::*;
 use quaint::ast::*;
@@ -43,11 +43,15 @@ impl SelectDefinition for Select<'static> {
 
 impl SelectDefinition for QueryArguments {
     fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let cursor: ConditionTree = cursor_condition::build(&self, Arc::clone(&model));
-        let order_by = self.order_by;
-        let ordering = Ordering::for_model(Arc::clone(&model), order_by.as_ref(), self.last.is_some());
-
-        let filter: ConditionTree = self
+        let query_arguments = self.with_explicit_nulls_for_cursor();
+        let cursor: ConditionTree = cursor_condition::build(&query_arguments, Arc::clone(&model));
+        let ordering = Ordering::for_model(
+            Arc::clone(&model),
+            &query_arguments.order_by,
+            query_arguments.last.is_some(),
+        );
+
+        let filter: ConditionTree = query_arguments
             .filter
             .map(|f| f.aliased_cond(None))
             .unwrap_or(ConditionTree::NoCondition);
@@ -58,9 +62,9 @@ impl SelectDefinition for QueryArguments {
             (filter, cursor) => ConditionTree::and(filter, cursor),
         };
 
-        let (skip, limit) = match self.last.or(self.first) {
-            Some(c) => (self.skip.unwrap_or(0), Some(c + 1)), // +1 to see if there's more data
-            None => (self.skip.unwrap_or(0), None),
+        let (skip, limit) = match query_arguments.last.or(query_arguments.first) {
+            Some(c) => (query_arguments.skip.unwrap_or(0), Some(c + 1)), // +1 to see if there's more data
+            None => (query_arguments.skip.unwrap_or(0), None),
         };
 
         let select_ast = Select::from_table(model.as_table())
@@ -101,15 +105,100 @@ pub fn get_scalar_list_values_by_record_ids(
         .so_that(vhere)
 }
 
-pub fn count_by_model(model: &ModelRef, query_arguments: QueryArguments) -> Select<'static> {
+/// Builds a query aggregating the records selected by the query arguments.
+/// The records are selected in a subquery, so that `skip` and `first` limit the
+/// aggregated records instead of the aggregation result.
+pub fn aggregate(
+    model: &ModelRef,
+    selections: &[AggregationSelection],
+    query_arguments: QueryArguments,
+) -> Select<'static> {
     let id_field = model.fields().id();
+    let limit = query_arguments.last.or(query_arguments.first);
 
     let mut selected_fields = SelectedFields::default();
     selected_fields.add_scalar(id_field.clone());
 
+    for field in selections.iter().flat_map(|s| s.fields()) {
+        if selected_fields
+            .scalar
+            .iter()
+            .find(|f| f.field.name == field.name)
+            .is_none()
+        {
+            selected_fields.add_scalar(Arc::clone(field));
+        }
+    }
+
     let base_query = get_records(model, &selected_fields, query_arguments);
+
+    // Paginated record queries fetch one additional record to see if there's more data,
+    // which must not be part of the aggregation.
+    let base_query = match limit {
+        Some(limit) => base_query.limit(limit as usize),
+        None => base_query,
+    };
+
     let table = Table::from(base_query).alias("sub");
-    let column = Column::from(("sub", id_field.db_name().to_string()));
+    let column = |field: &ScalarFieldRef| Column::from(("sub", field.db_name().to_string()));
+
+    selections
+        .iter()
+        .fold(Select::from_table(table), |select, selection| match selection {
+            AggregationSelection::Count => select.value(count(column(&id_field))),
+            AggregationSelection::Sum(fields) => fields.iter().fold(select, |acc, f| acc.value(sum(column(f)))),
+            AggregationSelection::Average(fields) => fields.iter().fold(select, |acc, f| acc.value(avg(column(f)))),
+            AggregationSelection::Min(fields) => fields.iter().fold(select, |acc, f| acc.value(min(column(f)))),
+            AggregationSelection::Max(fields) => fields.iter().fold(select, |acc, f| acc.value(max(column(f)))),
+        })
+}
 
-    Select::from_table(table).value(count(column))
+/// Builds a query grouping the records selected by the query arguments by the given fields.
+/// Selects the grouped columns first, followed by the aggregations in the order of the selections.
+/// Ordering and pagination apply to the groups, the `having` filter is applied after grouping.
+pub fn group_by(
+    model: &ModelRef,
+    group_by: &[ScalarFieldRef],
+    selections: &[AggregationSelection],
+    having: Option<Filter>,
+    query_arguments: QueryArguments,
+) -> Select<'static> {
+    let id_column = model.fields().id().as_column();
+    let ordering = Ordering::for_groups(group_by, &query_arguments.order_by);
+
+    let filter: ConditionTree = query_arguments
+        .filter
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let having: ConditionTree = having
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let select = group_by
+        .iter()
+        .fold(Select::from_table(model.as_table()).so_that(filter), |acc, field| {
+            acc.column(field.as_column())
+        });
+
+    let select = selections.iter().fold(select, |select, selection| match selection {
+        AggregationSelection::Count => select.value(count(id_column.clone())),
+        AggregationSelection::Sum(fields) => fields.iter().fold(select, |acc, f| acc.value(sum(f.as_column()))),
+        AggregationSelection::Average(fields) => fields.iter().fold(select, |acc, f| acc.value(avg(f.as_column()))),
+        AggregationSelection::Min(fields) => fields.iter().fold(select, |acc, f| acc.value(min(f.as_column()))),
+        AggregationSelection::Max(fields) => fields.iter().fold(select, |acc, f| acc.value(max(f.as_column()))),
+    });
+
+    let select = group_by
+        .iter()
+        .fold(select, |acc, field| acc.group_by(field.as_column()))
+        .having(having)
+        .offset(query_arguments.skip.unwrap_or(0) as usize);
+
+    let select = ordering.into_iter().fold(select, |acc, ord| acc.order_by(ord));
+
+    match query_arguments.first {
+        Some(limit) => select.limit(limit as usize),
+        None => select,
+    }
 }
@@ -1,6 +1,6 @@
 use crate::error::SqlError;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 use std::convert::TryFrom;
 
 const PARAMETER_LIMIT: usize = 10000;
@@ -40,6 +40,66 @@ pub fn create_record(model: &ModelRef, mut args: PrismaArgs) -> (Insert<'static>
     (Insert::from(insert).returning(vec![id_field.as_column()]), return_id)
 }
 
+/// Multi-row inserts for the given records. Records setting the same fields are inserted together,
+/// in chunks small enough to stay below the bind parameter limit of the database.
+pub fn create_records(sql_family: SqlFamily, model: &ModelRef, args: Vec<PrismaArgs>) -> Vec<Insert<'static>> {
+    let id_field = model.fields().id();
+    let mut batches: Vec<(Vec<&Field>, Vec<Row<'static>>)> = Vec::new();
+
+    for mut args in args {
+        match args.get_field_value(&id_field.name) {
+            _ if id_field.is_auto_generated => (),
+            Some(PrismaValue::Null) | None => args.insert(id_field.name.as_str(), model.generate_id()),
+            Some(_) => (),
+        };
+
+        let fields: Vec<&Field> = model
+            .fields()
+            .all
+            .iter()
+            .filter(|field| args.has_arg_for(&field.name()))
+            .collect();
+
+        let row = fields.iter().fold(Row::new(), |row, field| {
+            row.push(args.take_field_value(field.name()).unwrap())
+        });
+
+        match batches.iter_mut().find(|(batch_fields, _)| batch_fields == &fields) {
+            Some((_, rows)) => rows.push(row),
+            None => batches.push((fields, vec![row])),
+        }
+    }
+
+    let parameter_limit = parameter_limit(sql_family);
+
+    batches
+        .into_iter()
+        .flat_map(|(fields, rows)| {
+            let columns: Vec<String> = fields.iter().map(|field| field.db_name().into_owned()).collect();
+            let rows_per_insert = std::cmp::max(1, parameter_limit / std::cmp::max(1, columns.len()));
+
+            rows.chunks(rows_per_insert)
+                .map(|chunk| {
+                    let insert = Insert::multi_into(model.as_table(), columns.clone());
+                    let insert: MultiRowInsert = chunk.iter().fold(insert, |acc, row| acc.values(row.clone()));
+
+                    Insert::from(insert)
+                })
+                .collect::<Vec<Insert<'static>>>()
+        })
+        .collect()
+}
+
+/// The maximum number of bind parameters a single statement may have.
+fn parameter_limit(sql_family: SqlFamily) -> usize {
+    match sql_family {
+        SqlFamily::Postgres => 32767,
+        SqlFamily::Mysql => 65535,
+        // The default of SQLITE_MAX_VARIABLE_NUMBER for SQLite versions before 3.32.
+        SqlFamily::Sqlite => 999,
+    }
+}
+
 pub fn create_relation_table_records(
     field: &RelationFieldRef,
     parent_id: &GraphqlId,
@@ -208,3 +268,99 @@ where
         .map(|chunk| Delete::from_table(table.clone()).so_that(conditions(chunk)))
         .collect()
 }
+
+/// Names of all tables holding data of the internal data model: model tables,
+/// separate relation tables and scalar list tables. Tables referencing other tables come first,
+/// so deleting in this order doesn't trip restricting foreign keys. Model tables referencing
+/// each other in a cycle are kept in name order.
+pub fn data_tables(internal_data_model: &InternalDataModel) -> Vec<String> {
+    let mut link_tables: Vec<String> = vec![];
+    let mut model_tables: Vec<String> = vec![];
+    let mut references: Vec<(String, String)> = vec![];
+
+    for model in internal_data_model.models().iter().filter(|m| !m.is_embedded) {
+        model_tables.push(model.db_name().to_string());
+
+        for field in model.fields().scalar_list() {
+            link_tables.push(ScalarListTable::new(&field).table_name().to_string());
+        }
+    }
+
+    for relation in internal_data_model.relations() {
+        match relation.inline_manifestation() {
+            // Inline relations are stored in the model tables.
+            Some(inline) => {
+                let referenced = if relation.model_a().name == inline.in_table_of_model_name {
+                    relation.model_b()
+                } else {
+                    relation.model_a()
+                };
+
+                references.push((relation.table_name(), referenced.db_name().to_string()));
+            }
+            None => link_tables.push(relation.table_name()),
+        }
+    }
+
+    link_tables.sort();
+    link_tables.dedup();
+    model_tables.sort();
+    model_tables.dedup();
+
+    let is_referenced = |table: &String, remaining: &[String]| {
+        references
+            .iter()
+            .any(|(from, to)| to == table && from != table && remaining.contains(from))
+    };
+
+    let mut tables = link_tables;
+
+    while !model_tables.is_empty() {
+        let next = model_tables
+            .iter()
+            .position(|table| !is_referenced(table, &model_tables))
+            .unwrap_or(0);
+
+        tables.push(model_tables.remove(next));
+    }
+
+    tables
+}
+
+/// Removes all data from the given PostgreSQL tables and resets their sequences.
+/// Truncating is transactional on PostgreSQL and cascades across foreign keys.
+pub fn truncate_postgres_tables(schema_name: &str, tables: &[String]) -> String {
+    let tables: Vec<String> = tables
+        .iter()
+        .map(|table| format!("\"{}\".\"{}\"", schema_name, table))
+        .collect();
+
+    format!("TRUNCATE TABLE {} RESTART IDENTITY CASCADE", tables.join(", "))
+}
+
+/// Deletes all rows of the given tables. Used on MySQL and SQLite, where the foreign key checks
+/// are suspended while deleting. Unlike `TRUNCATE` on MySQL, deleting doesn't commit implicitly.
+pub fn delete_all(schema_name: &str, tables: &[String]) -> Vec<Delete<'static>> {
+    tables
+        .iter()
+        .map(|table| Delete::from_table((schema_name.to_string(), table.clone())))
+        .collect()
+}
+
+/// Resets the `AUTO_INCREMENT` counters of the given MySQL tables to the lowest possible value.
+/// Altering a table commits implicitly, so these have to run after all data was deleted.
+pub fn reset_mysql_auto_increments(schema_name: &str, tables: &[String]) -> Vec<String> {
+    tables
+        .iter()
+        .map(|table| format!("ALTER TABLE `{}`.`{}` AUTO_INCREMENT = 1", schema_name, table))
+        .collect()
+}
+
+/// Resets the `AUTOINCREMENT` counters of the given tables. Only valid if the
+/// `sqlite_sequence` table exists, which SQLite creates with the first `AUTOINCREMENT` table.
+pub fn reset_sqlite_sequences(schema_name: &str, tables: &[String]) -> Delete<'static> {
+    let table = Table::from((schema_name.to_string(), "sqlite_sequence".to_string()));
+    let names: Vec<String> = tables.to_vec();
+
+    Delete::from_table(table).so_that("name".in_selection(names))
+}
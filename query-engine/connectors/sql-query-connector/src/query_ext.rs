@@ -10,7 +10,7 @@ use quaint::{
     connector::{self, Queryable},
     pool::PooledConnection,
 };
-use serde_json::{Map, Number, Value};
+use serde_json::{Map, Value};
 use std::convert::TryFrom;
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
@@ -31,28 +31,32 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
+    /// Runs a raw query, returning the rows as JSON objects keyed by column name.
+    /// The values are typed from the result set.
     async fn raw_json(&self, q: RawQuery) -> crate::Result<Value> {
-        if q.is_select() {
-            let result_set = self.query_raw(q.0.as_str(), &[]).await?;
-            let columns: Vec<String> = result_set.columns().map(ToString::to_string).collect();
-            let mut result = Vec::new();
-
-            for row in result_set.into_iter() {
-                let mut object = Map::new();
+        let result_set = self.query_raw(q.query.as_str(), q.parameters.as_slice()).await?;
+        let columns: Vec<String> = result_set.columns().map(ToString::to_string).collect();
+        let mut result = Vec::new();
 
-                for (idx, p_value) in row.into_iter().enumerate() {
-                    let column_name: String = columns[idx].clone();
-                    object.insert(column_name, Value::from(p_value));
-                }
+        for row in result_set.into_iter() {
+            let mut object = Map::new();
 
-                result.push(Value::Object(object));
+            for (idx, p_value) in row.into_iter().enumerate() {
+                let column_name: String = columns[idx].clone();
+                object.insert(column_name, Value::from(p_value));
             }
 
-            Ok(Value::Array(result))
-        } else {
-            let changes = self.execute_raw(q.0.as_str(), &[]).await?;
-            Ok(Value::Number(Number::from(changes)))
+            result.push(Value::Object(object));
         }
+
+        Ok(Value::Array(result))
+    }
+
+    /// Executes a raw statement, returning the number of affected rows.
+    async fn raw_count(&self, q: RawQuery) -> crate::Result<usize> {
+        let changes = self.execute_raw(q.query.as_str(), q.parameters.as_slice()).await?;
+
+        Ok(changes as usize)
     }
 
     /// Find one full record selecting all scalar fields.
@@ -1,13 +1,31 @@
-pub struct RawQuery(pub String);
+use prisma_models::PrismaValue;
+use quaint::ast::{DatabaseValue, ParameterizedValue};
+
+/// A raw SQL string with positional parameters, as passed in by the user.
+pub struct RawQuery {
+    pub query: String,
+    pub parameters: Vec<ParameterizedValue<'static>>,
+}
 
-#[allow(dead_code)]
 impl RawQuery {
-    pub fn is_select(&self) -> bool {
-        let splitted: Vec<&str> = self.0.split(" ").collect();
-        splitted
-            .first()
-            .map(|t| t.to_uppercase().trim() == "SELECT")
-            .unwrap_or(false)
+    pub fn new(query: String, parameters: Vec<PrismaValue>) -> Self {
+        let parameters = parameters.into_iter().map(Self::parameter).collect();
+
+        RawQuery { query, parameters }
+    }
+
+    fn parameter(value: PrismaValue) -> ParameterizedValue<'static> {
+        match value {
+            PrismaValue::Json(json) => ParameterizedValue::Json(json),
+            PrismaValue::List(Some(values)) => {
+                ParameterizedValue::Array(values.into_iter().map(Self::parameter).collect())
+            }
+            PrismaValue::List(None) => ParameterizedValue::Null,
+            value => match DatabaseValue::from(value) {
+                DatabaseValue::Parameterized(p) => p,
+                _ => unreachable!("Scalar values always convert into parameters."),
+            },
+        }
     }
 }
 
@@ -16,6 +34,9 @@ where
     T: Into<String>,
 {
     fn from(s: T) -> Self {
-        RawQuery(s.into())
+        RawQuery {
+            query: s.into(),
+            parameters: Vec::new(),
+        }
     }
 }
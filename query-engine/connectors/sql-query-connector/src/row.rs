@@ -5,7 +5,8 @@ use quaint::{
     ast::{DatabaseValue, ParameterizedValue},
     connector::ResultRow,
 };
-use std::{borrow::Borrow, io};
+use rust_decimal::Decimal;
+use std::{borrow::Borrow, io, str::FromStr};
 use uuid::Uuid;
 
 /// An allocated representation of a `Row` returned from the database.
@@ -125,6 +126,21 @@ impl ToSqlRow for ResultRow {
                         return Err(SqlError::ConversionError(error.into()));
                     }
                 },
+                TypeIdentifier::Decimal => match p_value {
+                    ParameterizedValue::Null => PrismaValue::Null,
+                    // Exact numerics come back as text, e.g. on MySQL, and are parsed without a detour through floats.
+                    ParameterizedValue::Text(s) => PrismaValue::Decimal(parse_decimal(s.borrow())?),
+                    // Postgres numerics and SQLite reals come back as floats.
+                    ParameterizedValue::Real(f) => PrismaValue::Decimal(parse_decimal(&f.to_string())?),
+                    ParameterizedValue::Integer(i) => PrismaValue::Decimal(Decimal::from(i)),
+                    _ => {
+                        let error = io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "Decimal value not stored as float, int or text",
+                        );
+                        return Err(SqlError::ConversionError(error.into()));
+                    }
+                },
                 _ => PrismaValue::from(p_value),
             };
 
@@ -135,6 +151,19 @@ impl ToSqlRow for ResultRow {
     }
 }
 
+/// Parse a stored decimal. Trailing zeros of the fraction are dropped first, since databases pad decimals to the scale
+/// of their column, which can be larger than the scale a `Decimal` holds.
+fn parse_decimal(s: &str) -> Result<Decimal, SqlError> {
+    let s = s.trim();
+    let s = if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        s
+    };
+
+    Ok(Decimal::from_str(s)?)
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SqlId {
     String(String),
@@ -32,6 +32,9 @@ pub enum CoreError {
 
     #[fail(display = "{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[fail(display = "Transaction error: {}", _0)]
+    TransactionError(String),
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
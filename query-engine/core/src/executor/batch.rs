@@ -0,0 +1,290 @@
+//! Compaction of query document batches.
+//!
+//! Batches sent by DataLoader-style clients often contain many `findOne` lookups that only differ
+//! in the looked up value, e.g. `findOneUser(where: { id: 1 })`, `findOneUser(where: { id: 2 })`.
+//! Adjacent lookups of that kind are compacted into a single `findMany` document with an `_in` filter,
+//! and the result of the `findMany` is split back into one response per lookup.
+use crate::{
+    query_document::{Operation, QueryDocument, QueryValue, Selection},
+    response_ir::{Item, Map, Response},
+    schema::{QuerySchemaRef, QueryTag, SchemaQueryBuilder},
+    CoreResult,
+};
+use prisma_models::{GraphqlId, ModelRef, PrismaValue, TypeIdentifier};
+use std::collections::BTreeMap;
+
+/// A step of a compacted batch. Steps are executed in order, and cover the documents of the batch in order.
+pub enum BatchStep {
+    /// The document at the given position of the batch, executed as is.
+    Single(usize, QueryDocument),
+
+    /// A `findMany` document standing in for several adjacent `findOne` lookups.
+    Compacted(QueryDocument, CompactedLookups),
+}
+
+pub struct CompactedLookups {
+    /// The unique field the records are looked up by.
+    key: String,
+
+    /// Whether the key field has been added to the selection in order to match records to lookups.
+    key_added: bool,
+
+    /// Position in the batch, response key, looked up value and original document of each lookup.
+    lookups: Vec<(usize, String, QueryValue, QueryDocument)>,
+}
+
+/// The result of a single lookup of a compacted step.
+pub enum LookupOutcome {
+    /// The response of the lookup, taken from the result of the `findMany`.
+    Resolved(Response),
+
+    /// No record of the `findMany` result has exactly the looked up value, which doesn't mean there is
+    /// no such record, e.g. with case insensitive collations. The original document has to be executed.
+    Unmatched(QueryDocument),
+}
+
+/// A `findOne` lookup by a single unique field.
+struct FindOneLookup {
+    find_many_name: String,
+    key: String,
+    value: QueryValue,
+    response_key: String,
+    nested_selections: Vec<Selection>,
+}
+
+/// Compacts the given batch into the steps to execute, in order. Only runs of adjacent `findOne` lookups
+/// are compacted, so that reads are never moved across writes (or any other document) of the batch.
+pub fn compact(batch: Vec<QueryDocument>, query_schema: &QuerySchemaRef) -> Vec<BatchStep> {
+    let mut steps = vec![];
+    let mut run: Vec<(usize, FindOneLookup, QueryDocument)> = vec![];
+
+    for (index, document) in batch.into_iter().enumerate() {
+        match find_one_lookup(&document, query_schema) {
+            Some(lookup) => {
+                let continues_run = run.first().map_or(true, |(_, other, _)| {
+                    other.find_many_name == lookup.find_many_name
+                        && other.key == lookup.key
+                        && other.nested_selections == lookup.nested_selections
+                });
+
+                if !continues_run {
+                    flush_run(&mut run, &mut steps);
+                }
+
+                run.push((index, lookup, document));
+            }
+            None => {
+                flush_run(&mut run, &mut steps);
+                steps.push(BatchStep::Single(index, document));
+            }
+        }
+    }
+
+    flush_run(&mut run, &mut steps);
+    steps
+}
+
+/// Turns the current run of lookups into a step, compacted if it holds more than one lookup.
+fn flush_run(run: &mut Vec<(usize, FindOneLookup, QueryDocument)>, steps: &mut Vec<BatchStep>) {
+    let mut lookups = std::mem::replace(run, vec![]);
+
+    match lookups.len() {
+        0 => (),
+        1 => {
+            let (index, _, document) = lookups.pop().unwrap();
+            steps.push(BatchStep::Single(index, document));
+        }
+        _ => {
+            let (document, lookups) = compact_group(lookups);
+            steps.push(BatchStep::Compacted(document, lookups));
+        }
+    }
+}
+
+impl CompactedLookups {
+    /// Splits the result of the `findMany` into one outcome per lookup.
+    pub fn split(self, result: CoreResult<Vec<Response>>) -> Vec<(usize, LookupOutcome)> {
+        let records = match result.map(|mut responses| responses.pop()) {
+            Ok(Some(Response::Data(_, item))) => match unwrap_ref(item) {
+                Item::List(records) => records.into_iter().filter_map(|record| into_map(record)).collect(),
+                _ => vec![],
+            },
+            Ok(Some(Response::Error(err))) => return self.fail(err),
+            Ok(None) => vec![],
+            Err(err) => return self.fail(format!("{}", err)),
+        };
+
+        let key = self.key;
+        let key_added = self.key_added;
+        let matches = |value: &QueryValue, record: &Map| match record.get(&key) {
+            Some(Item::Value(pv)) => value_matches(value, pv),
+            _ => false,
+        };
+
+        // Records no lookup matches exactly mean the database compared values differently than we do.
+        let all_records_matched = records
+            .iter()
+            .all(|record| self.lookups.iter().any(|(_, _, value, _)| matches(value, record)));
+
+        self.lookups
+            .into_iter()
+            .map(|(index, response_key, value, document)| {
+                let outcome = match records.iter().find(|record| matches(&value, record)) {
+                    Some(record) => {
+                        let mut record = record.clone();
+
+                        if key_added {
+                            record.shift_remove(&key);
+                        }
+
+                        LookupOutcome::Resolved(Response::Data(response_key, Item::Map(record)))
+                    }
+                    None if all_records_matched => {
+                        LookupOutcome::Resolved(Response::Data(response_key, Item::Value(PrismaValue::Null)))
+                    }
+                    None => LookupOutcome::Unmatched(document),
+                };
+
+                (index, outcome)
+            })
+            .collect()
+    }
+
+    fn fail(self, err: String) -> Vec<(usize, LookupOutcome)> {
+        self.lookups
+            .into_iter()
+            .map(|(index, _, _, _)| (index, LookupOutcome::Resolved(Response::Error(err.clone()))))
+            .collect()
+    }
+}
+
+/// Returns the lookup if the document consists of exactly one `findOne` by a single
+/// unique field with an identifier-like type (String, Int, ID or UUID).
+fn find_one_lookup(document: &QueryDocument, query_schema: &QuerySchemaRef) -> Option<FindOneLookup> {
+    let selection = match document.operations.as_slice() {
+        [Operation::Read(selection)] => selection,
+        _ => return None,
+    };
+
+    let model = match query_schema.find_query_field(selection.name.as_str())?.query_builder {
+        Some(SchemaQueryBuilder::ModelQueryBuilder(ref builder)) if builder.tag == QueryTag::FindOne => {
+            builder.model.clone()
+        }
+        _ => return None,
+    };
+
+    let (key, value) = match selection.arguments.as_slice() {
+        [(name, QueryValue::Object(map))] if name == "where" && map.len() == 1 => map.iter().next().unwrap(),
+        _ => return None,
+    };
+
+    let field = model.fields().find_from_scalar(key).ok()?;
+
+    match (field.type_identifier, value) {
+        (TypeIdentifier::String, QueryValue::String(_)) => (),
+        (TypeIdentifier::GraphQLID, QueryValue::String(_)) => (),
+        (TypeIdentifier::GraphQLID, QueryValue::Int(_)) => (),
+        (TypeIdentifier::UUID, QueryValue::String(_)) => (),
+        (TypeIdentifier::Int, QueryValue::Int(_)) => (),
+        _ => return None,
+    };
+
+    Some(FindOneLookup {
+        find_many_name: find_many_name(&model, query_schema)?,
+        key: key.clone(),
+        value: value.clone(),
+        response_key: selection.alias.clone().unwrap_or_else(|| selection.name.clone()),
+        nested_selections: selection.nested_selections.clone(),
+    })
+}
+
+fn find_many_name(model: &ModelRef, query_schema: &QuerySchemaRef) -> Option<String> {
+    query_schema
+        .query()
+        .get_fields()
+        .iter()
+        .find(|field| match field.query_builder {
+            Some(SchemaQueryBuilder::ModelQueryBuilder(ref builder)) => {
+                builder.tag == QueryTag::FindMany && builder.model.name == model.name
+            }
+            _ => false,
+        })
+        .map(|field| field.name.clone())
+}
+
+/// Builds the `findMany` document for a group of lookups (e.g. `findManyUser(where: { id_in: [1, 2] })`).
+/// The key field is added to the selection if it isn't selected already, to match records to lookups.
+fn compact_group(group: Vec<(usize, FindOneLookup, QueryDocument)>) -> (QueryDocument, CompactedLookups) {
+    let find_many_name = group[0].1.find_many_name.clone();
+    let key = group[0].1.key.clone();
+    let mut nested_selections = group[0].1.nested_selections.clone();
+
+    let key_added = !nested_selections
+        .iter()
+        .any(|selection| selection.name == key && selection.alias.is_none());
+
+    if key_added {
+        nested_selections.push(Selection {
+            name: key.clone(),
+            alias: None,
+            arguments: vec![],
+            nested_selections: vec![],
+        });
+    }
+
+    let lookups: Vec<(usize, String, QueryValue, QueryDocument)> = group
+        .into_iter()
+        .map(|(index, lookup, document)| (index, lookup.response_key, lookup.value, document))
+        .collect();
+
+    let values = lookups.iter().map(|(_, _, value, _)| value.clone()).collect();
+    let mut filter = BTreeMap::new();
+    filter.insert(format!("{}_in", key), QueryValue::List(values));
+
+    let document = QueryDocument {
+        operations: vec![Operation::Read(Selection {
+            name: find_many_name,
+            alias: None,
+            arguments: vec![("where".to_owned(), QueryValue::Object(filter))],
+            nested_selections,
+        })],
+    };
+
+    (
+        document,
+        CompactedLookups {
+            key,
+            key_added,
+            lookups,
+        },
+    )
+}
+
+fn value_matches(value: &QueryValue, pv: &PrismaValue) -> bool {
+    match (value, pv) {
+        (QueryValue::String(s), PrismaValue::String(p)) => s == p,
+        (QueryValue::String(s), PrismaValue::Uuid(u)) => &u.to_hyphenated().to_string() == s,
+        (QueryValue::String(s), PrismaValue::GraphqlId(GraphqlId::String(p))) => s == p,
+        (QueryValue::String(s), PrismaValue::GraphqlId(GraphqlId::UUID(u))) => &u.to_hyphenated().to_string() == s,
+        (QueryValue::Int(i), PrismaValue::GraphqlId(GraphqlId::Int(p))) => *i as usize == *p,
+        (QueryValue::Int(i), PrismaValue::Int(p)) => i == p,
+        _ => false,
+    }
+}
+
+fn unwrap_ref(item: Item) -> Item {
+    match item {
+        Item::Ref(r) => (*r).clone(),
+        item => item,
+    }
+}
+
+fn into_map(item: Item) -> Option<Map> {
+    match unwrap_ref(item) {
+        Item::Map(map) => Some(map),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
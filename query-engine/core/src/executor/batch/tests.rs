@@ -0,0 +1,196 @@
+use super::*;
+use crate::{BuildMode, QuerySchemaBuilder, SupportedCapabilities};
+use prisma_models::DatamodelConverter;
+use std::sync::Arc;
+
+const DATAMODEL: &str = r#"
+    model User {
+        id   String  @id @default(cuid())
+        name String?
+    }
+"#;
+
+#[test]
+fn adjacent_lookups_must_be_compacted() {
+    let steps = compact(vec![find_one("a", &["id"]), find_one("b", &["id"])], &query_schema());
+
+    assert_eq!(describe(&steps), vec!["compacted [0, 1]"]);
+
+    match &steps[0] {
+        BatchStep::Compacted(document, lookups) => {
+            let selection = match document.operations.as_slice() {
+                [Operation::Read(selection)] => selection,
+                _ => panic!("Expected a single read operation."),
+            };
+
+            assert_eq!(selection.name, "findManyUser");
+            assert_eq!(
+                selection.arguments,
+                vec![(
+                    "where".to_owned(),
+                    object(vec![("id_in", QueryValue::List(vec![string("a"), string("b")]))])
+                )]
+            );
+            assert!(!lookups.key_added);
+        }
+        _ => panic!("Expected a compacted step."),
+    }
+}
+
+#[test]
+fn lookups_must_not_be_compacted_across_writes() {
+    let batch = vec![find_one("a", &["id"]), create_one("a"), find_one("b", &["id"])];
+    let steps = compact(batch, &query_schema());
+
+    assert_eq!(describe(&steps), vec!["single 0", "single 1", "single 2"]);
+}
+
+#[test]
+fn compaction_must_preserve_the_order_of_the_batch() {
+    let batch = vec![
+        find_one("a", &["id"]),
+        find_one("b", &["id"]),
+        create_one("c"),
+        find_one("c", &["id"]),
+        find_one("d", &["id"]),
+        find_one("e", &["id", "name"]),
+    ];
+
+    let steps = compact(batch, &query_schema());
+
+    assert_eq!(
+        describe(&steps),
+        vec!["compacted [0, 1]", "single 2", "compacted [3, 4]", "single 5"]
+    );
+}
+
+#[test]
+fn split_must_match_records_to_lookups() {
+    let steps = compact(
+        vec![find_one("a", &["name"]), find_one("b", &["name"])],
+        &query_schema(),
+    );
+
+    let outcomes = match steps.into_iter().next().unwrap() {
+        BatchStep::Compacted(_, lookups) => {
+            assert!(lookups.key_added);
+            lookups.split(Ok(vec![records(vec![("a", "Alice")])]))
+        }
+        _ => panic!("Expected a compacted step."),
+    };
+
+    assert_eq!(outcomes.len(), 2);
+
+    match &outcomes[0] {
+        (0, LookupOutcome::Resolved(Response::Data(key, Item::Map(record)))) => {
+            assert_eq!(key, "findOneUser");
+            assert_eq!(record.keys().collect::<Vec<_>>(), vec!["name"]);
+        }
+        _ => panic!("Expected the record of the first lookup."),
+    }
+
+    match &outcomes[1] {
+        (1, LookupOutcome::Resolved(Response::Data(_, Item::Value(PrismaValue::Null)))) => (),
+        _ => panic!("Expected no record for the second lookup."),
+    }
+}
+
+#[test]
+fn split_must_leave_lookups_without_an_exact_match_unresolved() {
+    let steps = compact(vec![find_one("a", &["id"]), find_one("b", &["id"])], &query_schema());
+
+    // E.g. a case insensitive collation returning `A` for the lookup of `a`.
+    let outcomes = match steps.into_iter().next().unwrap() {
+        BatchStep::Compacted(_, lookups) => lookups.split(Ok(vec![records(vec![("A", "Alice"), ("b", "Bob")])])),
+        _ => panic!("Expected a compacted step."),
+    };
+
+    match &outcomes[0] {
+        (0, LookupOutcome::Unmatched(document)) => assert_eq!(document.operations.len(), 1),
+        _ => panic!("Expected the first lookup to be unmatched."),
+    }
+
+    match &outcomes[1] {
+        (1, LookupOutcome::Resolved(Response::Data(_, Item::Map(_)))) => (),
+        _ => panic!("Expected the record of the second lookup."),
+    }
+}
+
+fn query_schema() -> QuerySchemaRef {
+    let internal_data_model = DatamodelConverter::convert_string(DATAMODEL.to_owned()).build("db".to_owned());
+    let capabilities = SupportedCapabilities::empty();
+
+    Arc::new(QuerySchemaBuilder::new(&internal_data_model, &capabilities, BuildMode::Modern, false).build())
+}
+
+fn find_one(id: &str, fields: &[&str]) -> QueryDocument {
+    QueryDocument {
+        operations: vec![Operation::Read(Selection {
+            name: "findOneUser".to_owned(),
+            alias: None,
+            arguments: vec![("where".to_owned(), object(vec![("id", string(id))]))],
+            nested_selections: fields.iter().map(|field| leaf(field)).collect(),
+        })],
+    }
+}
+
+fn create_one(name: &str) -> QueryDocument {
+    QueryDocument {
+        operations: vec![Operation::Write(Selection {
+            name: "createOneUser".to_owned(),
+            alias: None,
+            arguments: vec![("data".to_owned(), object(vec![("name", string(name))]))],
+            nested_selections: vec![leaf("id")],
+        })],
+    }
+}
+
+/// The result of the compacted `findMany`, holding the given id and name pairs.
+fn records(records: Vec<(&str, &str)>) -> Response {
+    let records = records
+        .into_iter()
+        .map(|(id, name)| {
+            let mut record = Map::new();
+            record.insert("name".to_owned(), Item::Value(PrismaValue::String(name.to_owned())));
+            record.insert("id".to_owned(), Item::Value(PrismaValue::String(id.to_owned())));
+
+            Item::Map(record)
+        })
+        .collect();
+
+    Response::Data("findManyUser".to_owned(), Item::List(records))
+}
+
+fn describe(steps: &[BatchStep]) -> Vec<String> {
+    steps
+        .iter()
+        .map(|step| match step {
+            BatchStep::Single(index, _) => format!("single {}", index),
+            BatchStep::Compacted(_, lookups) => format!(
+                "compacted {:?}",
+                lookups
+                    .lookups
+                    .iter()
+                    .map(|(index, _, _, _)| *index)
+                    .collect::<Vec<_>>()
+            ),
+        })
+        .collect()
+}
+
+fn leaf(name: &str) -> Selection {
+    Selection {
+        name: name.to_owned(),
+        alias: None,
+        arguments: vec![],
+        nested_selections: vec![],
+    }
+}
+
+fn string(s: &str) -> QueryValue {
+    QueryValue::String(s.to_owned())
+}
+
+fn object(fields: Vec<(&str, QueryValue)>) -> QueryValue {
+    QueryValue::Object(fields.into_iter().map(|(k, v)| (k.to_owned(), v)).collect())
+}
@@ -0,0 +1,214 @@
+use super::pipeline::QueryPipeline;
+use crate::{CoreError, CoreResult, QueryDocument, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response};
+use connector::{ConnectionLike, Connector, IsolationLevel};
+use futures::{
+    channel::{mpsc, oneshot},
+    StreamExt,
+};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::timer::Timeout;
+use uuid::Uuid;
+
+enum TransactionMessage {
+    Execute {
+        query_doc: QueryDocument,
+        query_schema: QuerySchemaRef,
+        reply: oneshot::Sender<CoreResult<Vec<Response>>>,
+    },
+    Commit(oneshot::Sender<CoreResult<()>>),
+    Rollback(oneshot::Sender<CoreResult<()>>),
+}
+
+/// Handle to an interactive transaction, which stays open across several executions
+/// until it is committed or rolled back.
+///
+/// The transaction itself is owned by a background task, which executes the queries sent through
+/// the handle. The task rolls the transaction back if the handle is dropped or the timeout elapses,
+/// including while a query is executing.
+pub struct InteractiveTransaction {
+    id: String,
+    sender: mpsc::UnboundedSender<TransactionMessage>,
+}
+
+impl InteractiveTransaction {
+    pub(super) async fn start<C>(
+        connector: Arc<C>,
+        isolation_level: Option<IsolationLevel>,
+        timeout: Duration,
+    ) -> CoreResult<Self>
+    where
+        C: Connector + Send + Sync + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        let (started_sender, started) = oneshot::channel();
+
+        tokio::spawn(run(connector, isolation_level, timeout, receiver, started_sender));
+
+        match started.await {
+            Ok(Ok(())) => Ok(Self {
+                id: Uuid::new_v4().to_string(),
+                sender,
+            }),
+            Ok(Err(err)) => Err(err),
+            Err(_) => Err(closed()),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// False once the transaction has been committed, rolled back or timed out.
+    pub fn is_open(&self) -> bool {
+        !self.sender.is_closed()
+    }
+
+    pub async fn execute(&self, query_doc: QueryDocument, query_schema: QuerySchemaRef) -> CoreResult<Vec<Response>> {
+        let (reply, response) = oneshot::channel();
+
+        self.send(TransactionMessage::Execute {
+            query_doc,
+            query_schema,
+            reply,
+        })?;
+
+        response.await.map_err(|_| closed())?
+    }
+
+    pub async fn commit(&self) -> CoreResult<()> {
+        let (reply, response) = oneshot::channel();
+
+        self.send(TransactionMessage::Commit(reply))?;
+        response.await.map_err(|_| closed())?
+    }
+
+    pub async fn rollback(&self) -> CoreResult<()> {
+        let (reply, response) = oneshot::channel();
+
+        self.send(TransactionMessage::Rollback(reply))?;
+        response.await.map_err(|_| closed())?
+    }
+
+    fn send(&self, message: TransactionMessage) -> CoreResult<()> {
+        self.sender.unbounded_send(message).map_err(|_| closed())
+    }
+}
+
+fn closed() -> CoreError {
+    CoreError::TransactionError("The transaction has already been closed or timed out.".into())
+}
+
+fn timed_out() -> CoreError {
+    CoreError::TransactionError("The transaction timed out while executing the query and has been rolled back.".into())
+}
+
+/// Owns connection and transaction, executing incoming messages until the transaction is finished.
+async fn run<C>(
+    connector: Arc<C>,
+    isolation_level: Option<IsolationLevel>,
+    timeout: Duration,
+    mut receiver: mpsc::UnboundedReceiver<TransactionMessage>,
+    started: oneshot::Sender<CoreResult<()>>,
+) where
+    C: Connector + Send + Sync + 'static,
+{
+    let conn = match connector.get_connection().await {
+        Ok(conn) => conn,
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    let tx = match isolation_level {
+        Some(level) => conn.start_transaction_with_isolation_level(level).await,
+        None => conn.start_transaction().await,
+    };
+
+    let tx = match tx {
+        Ok(tx) => tx,
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    if started.send(Ok(())).is_err() {
+        let _ = tx.rollback().await;
+        return;
+    }
+
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        let message = match Timeout::new(receiver.next(), remaining).await {
+            Ok(Some(message)) => message,
+            Ok(None) => {
+                debug!("Interactive transaction handle dropped, rolling back.");
+                let _ = tx.rollback().await;
+                return;
+            }
+            Err(_) => {
+                debug!(
+                    "Interactive transaction timed out after {}ms, rolling back.",
+                    timeout.as_millis()
+                );
+                let _ = tx.rollback().await;
+                return;
+            }
+        };
+
+        match message {
+            TransactionMessage::Execute {
+                query_doc,
+                query_schema,
+                reply,
+            } => {
+                let execution = async {
+                    let queries = QueryGraphBuilder::new(query_schema).build(query_doc)?;
+                    let mut results = Vec::with_capacity(queries.len());
+
+                    for (query_graph, info) in queries {
+                        let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+                        results.push(QueryPipeline::new(query_graph, interpreter, info).execute().await?);
+                    }
+
+                    Ok::<_, CoreError>(results)
+                };
+
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                match Timeout::new(execution, remaining).await {
+                    Ok(result) => {
+                        let _ = reply.send(result);
+                    }
+                    Err(_) => {
+                        debug!(
+                            "Interactive transaction timed out after {}ms, rolling back.",
+                            timeout.as_millis()
+                        );
+                        let _ = reply.send(Err(timed_out()));
+                        let _ = tx.rollback().await;
+                        return;
+                    }
+                }
+            }
+            TransactionMessage::Commit(reply) => {
+                let _ = reply.send(tx.commit().await.map_err(CoreError::from));
+                return;
+            }
+            TransactionMessage::Rollback(reply) => {
+                let _ = reply.send(tx.rollback().await.map_err(CoreError::from));
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,282 @@
+use super::*;
+use crate::{
+    query_document::{Operation, QueryValue, Selection},
+    BuildMode, ConnectorCapability, QuerySchemaBuilder, SupportedCapabilities,
+};
+use connector::{
+    AggregationResult, AggregationSelection, Connection, Filter, QueryArguments, ReadOperations, RecordFinder,
+    RecordGroup, ScalarListValues, Transaction, WriteArgs, WriteOperations, IO,
+};
+use prisma_models::*;
+use std::sync::Mutex;
+
+type Log = Arc<Mutex<Vec<&'static str>>>;
+
+#[tokio::test]
+async fn committing_must_commit_the_executed_queries() {
+    let (connector, log) = connector(Duration::from_millis(0));
+    let tx = InteractiveTransaction::start(connector, None, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    tx.execute(execute_raw(), query_schema()).await.unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(*log.lock().unwrap(), vec!["execute", "commit"]);
+    assert!(tx.execute(execute_raw(), query_schema()).await.is_err());
+}
+
+#[tokio::test]
+async fn dropping_the_handle_must_roll_back() {
+    let (connector, log) = connector(Duration::from_millis(0));
+    let tx = InteractiveTransaction::start(connector, None, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    tx.execute(execute_raw(), query_schema()).await.unwrap();
+    drop(tx);
+
+    wait_for_rollback(&log).await;
+    assert_eq!(*log.lock().unwrap(), vec!["execute", "rollback"]);
+}
+
+#[tokio::test]
+async fn idle_transactions_must_be_rolled_back_after_the_timeout() {
+    let (connector, log) = connector(Duration::from_millis(0));
+    let tx = InteractiveTransaction::start(connector, None, Duration::from_millis(50))
+        .await
+        .unwrap();
+
+    wait_for_rollback(&log).await;
+
+    assert!(!tx.is_open());
+    assert!(tx.execute(execute_raw(), query_schema()).await.is_err());
+    assert_eq!(*log.lock().unwrap(), vec!["rollback"]);
+}
+
+#[tokio::test]
+async fn the_timeout_must_interrupt_running_queries() {
+    let (connector, log) = connector(Duration::from_secs(10));
+    let tx = InteractiveTransaction::start(connector, None, Duration::from_millis(100))
+        .await
+        .unwrap();
+
+    let started = Instant::now();
+    let error = tx.execute(execute_raw(), query_schema()).await.unwrap_err();
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(error.to_string().contains("timed out while executing the query"));
+
+    wait_for_rollback(&log).await;
+    assert_eq!(*log.lock().unwrap(), vec!["execute", "rollback"]);
+}
+
+async fn wait_for_rollback(log: &Log) {
+    for _ in 0..100 {
+        if log.lock().unwrap().contains(&"rollback") {
+            return;
+        }
+
+        tokio::timer::delay_for(Duration::from_millis(10)).await;
+    }
+
+    panic!("The transaction has not been rolled back.");
+}
+
+fn query_schema() -> QuerySchemaRef {
+    let internal_data_model = DatamodelConverter::convert_string(
+        r#"
+        model User {
+            id String @id @default(cuid())
+        }
+        "#
+        .to_owned(),
+    )
+    .build("db".to_owned());
+
+    let capabilities = SupportedCapabilities {
+        capabilities: vec![ConnectorCapability::RawAccess],
+    };
+
+    Arc::new(QuerySchemaBuilder::new(&internal_data_model, &capabilities, BuildMode::Modern, false).build())
+}
+
+fn execute_raw() -> QueryDocument {
+    QueryDocument {
+        operations: vec![Operation::Write(Selection {
+            name: "executeRaw".to_owned(),
+            alias: None,
+            arguments: vec![("query".to_owned(), QueryValue::String("UPDATE users".to_owned()))],
+            nested_selections: vec![],
+        })],
+    }
+}
+
+fn connector(execution_time: Duration) -> (Arc<TestConnector>, Log) {
+    let log = Log::default();
+    let connector = TestConnector {
+        log: Arc::clone(&log),
+        execution_time,
+    };
+
+    (Arc::new(connector), log)
+}
+
+/// Connector recording the raw statements, commits and rollbacks of its transactions.
+/// Raw statements take the given execution time, all other operations are unsupported.
+#[derive(Clone)]
+struct TestConnector {
+    log: Log,
+    execution_time: Duration,
+}
+
+impl TestConnector {
+    fn record(&self, entry: &'static str) {
+        self.log.lock().unwrap().push(entry);
+    }
+}
+
+impl Connector for TestConnector {
+    fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
+        IO::new(async move { Ok(Box::new(self.clone()) as Box<dyn Connection>) })
+    }
+}
+
+impl Connection for TestConnector {
+    fn start_transaction<'a>(&'a self) -> IO<Box<dyn Transaction + 'a>> {
+        IO::new(async move { Ok(Box::new(self.clone()) as Box<dyn Transaction>) })
+    }
+
+    fn start_transaction_with_isolation_level<'a>(
+        &'a self,
+        _isolation_level: IsolationLevel,
+    ) -> IO<Box<dyn Transaction + 'a>> {
+        self.start_transaction()
+    }
+}
+
+impl<'t> Transaction<'t> for TestConnector {
+    fn commit<'b>(&'b self) -> IO<'b, ()> {
+        IO::new(async move {
+            self.record("commit");
+            Ok(())
+        })
+    }
+
+    fn rollback<'b>(&'b self) -> IO<'b, ()> {
+        IO::new(async move {
+            self.record("rollback");
+            Ok(())
+        })
+    }
+}
+
+impl ReadOperations for TestConnector {
+    fn get_single_record<'a>(
+        &'a self,
+        _record_finder: &'a RecordFinder,
+        _selected_fields: &'a SelectedFields,
+    ) -> IO<'a, Option<SingleRecord>> {
+        unimplemented!()
+    }
+
+    fn get_many_records<'a>(
+        &'a self,
+        _model: &'a ModelRef,
+        _query_arguments: QueryArguments,
+        _selected_fields: &'a SelectedFields,
+    ) -> IO<'a, ManyRecords> {
+        unimplemented!()
+    }
+
+    fn get_related_records<'a>(
+        &'a self,
+        _from_field: &'a RelationFieldRef,
+        _from_record_ids: &'a [GraphqlId],
+        _query_arguments: QueryArguments,
+        _selected_fields: &'a SelectedFields,
+    ) -> IO<'a, ManyRecords> {
+        unimplemented!()
+    }
+
+    fn get_scalar_list_values<'a>(
+        &'a self,
+        _list_field: &'a ScalarFieldRef,
+        _record_ids: Vec<GraphqlId>,
+    ) -> IO<'a, Vec<ScalarListValues>> {
+        unimplemented!()
+    }
+
+    fn aggregate_records<'a>(
+        &'a self,
+        _model: &'a ModelRef,
+        _selections: Vec<AggregationSelection>,
+        _query_arguments: QueryArguments,
+    ) -> IO<'a, Vec<AggregationResult>> {
+        unimplemented!()
+    }
+
+    fn group_records<'a>(
+        &'a self,
+        _model: &'a ModelRef,
+        _group_by: Vec<ScalarFieldRef>,
+        _selections: Vec<AggregationSelection>,
+        _having: Option<Filter>,
+        _query_arguments: QueryArguments,
+    ) -> IO<'a, Vec<RecordGroup>> {
+        unimplemented!()
+    }
+
+    fn query_raw<'a>(&'a self, _query: String, _parameters: Vec<PrismaValue>) -> IO<'a, serde_json::Value> {
+        unimplemented!()
+    }
+}
+
+impl WriteOperations for TestConnector {
+    fn create_record<'a>(&'a self, _model: &'a ModelRef, _args: WriteArgs) -> IO<GraphqlId> {
+        unimplemented!()
+    }
+
+    fn create_records<'a>(&'a self, _model: &'a ModelRef, _args: Vec<WriteArgs>) -> IO<usize> {
+        unimplemented!()
+    }
+
+    fn update_records<'a>(&'a self, _model: &'a ModelRef, _where_: Filter, _args: WriteArgs) -> IO<Vec<GraphqlId>> {
+        unimplemented!()
+    }
+
+    fn delete_records<'a>(&'a self, _model: &'a ModelRef, _where_: Filter) -> IO<usize> {
+        unimplemented!()
+    }
+
+    fn truncate_data<'a>(&'a self, _internal_data_model: &'a InternalDataModelRef) -> IO<()> {
+        unimplemented!()
+    }
+
+    fn execute_raw<'a>(&'a self, _query: String, _parameters: Vec<PrismaValue>) -> IO<usize> {
+        IO::new(async move {
+            self.record("execute");
+            tokio::timer::delay_for(self.execution_time).await;
+
+            Ok(1)
+        })
+    }
+
+    fn connect<'a>(
+        &'a self,
+        _field: &'a RelationFieldRef,
+        _parent_id: &'a GraphqlId,
+        _child_ids: &'a [GraphqlId],
+    ) -> IO<()> {
+        unimplemented!()
+    }
+
+    fn disconnect<'a>(
+        &'a self,
+        _field: &'a RelationFieldRef,
+        _parent_id: &'a GraphqlId,
+        _child_ids: &'a [GraphqlId],
+    ) -> IO<()> {
+        unimplemented!()
+    }
+}
@@ -1,13 +1,19 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
+use super::{
+    batch::{self, BatchStep, LookupOutcome},
+    pipeline::QueryPipeline,
+    InteractiveTransaction, QueryExecutor,
+};
 use crate::{
-    CoreResult, IrSerializer, QueryDocument, QueryGraph, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response,
+    CoreResult, IrSerializer, QueryDocument, QueryGraph, QueryGraphBuilder, QueryInterpreter,
+    QuerySchemaRef, Response,
 };
-use connector::{Connector, ConnectionLike};
+use connector::{ConnectionLike, Connector, IsolationLevel, Transaction};
 use async_trait::async_trait;
+use std::{sync::Arc, time::Duration};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
-    connector: C,
+    connector: Arc<C>,
     primary_connector: &'static str,
 }
 
@@ -15,20 +21,92 @@ pub struct InterpretingExecutor<C> {
 // - Partial execution semantics?
 impl<C> InterpretingExecutor<C>
 where
-    C: Connector + Send + Sync,
+    C: Connector + Send + Sync + 'static,
 {
     pub fn new(connector: C, primary_connector: &'static str) -> Self {
         InterpretingExecutor {
-            connector,
+            connector: Arc::new(connector),
             primary_connector,
         }
     }
+
+    /// Executes the steps of a compacted batch in order, within the given transaction if any.
+    /// In a transaction, the first failing document fails the entire batch.
+    async fn execute_batch_steps<'a, 'tx>(
+        &'a self,
+        steps: Vec<BatchStep>,
+        size: usize,
+        query_schema: &'a QuerySchemaRef,
+        tx: Option<&'a (dyn Transaction<'tx> + 'tx)>,
+    ) -> CoreResult<Vec<CoreResult<Vec<Response>>>> {
+        let mut results: Vec<Option<CoreResult<Vec<Response>>>> = (0..size).map(|_| None).collect();
+
+        for step in steps {
+            match step {
+                BatchStep::Single(index, document) => {
+                    let result = self.execute_batch_document(document, query_schema, tx).await;
+                    results[index] = Some(fail_in_transaction(result, tx.is_some())?);
+                }
+                BatchStep::Compacted(document, lookups) => {
+                    let result = self.execute_batch_document(document, query_schema, tx).await;
+                    let result = fail_in_transaction(result, tx.is_some())?;
+
+                    for (index, outcome) in lookups.split(result) {
+                        let result = match outcome {
+                            LookupOutcome::Resolved(response) => Ok(vec![response]),
+                            LookupOutcome::Unmatched(document) => {
+                                let result = self.execute_batch_document(document, query_schema, tx).await;
+                                fail_in_transaction(result, tx.is_some())?
+                            }
+                        };
+
+                        results[index] = Some(result);
+                    }
+                }
+            }
+        }
+
+        Ok(results.into_iter().map(|result| result.unwrap()).collect())
+    }
+
+    async fn execute_batch_document<'a, 'tx>(
+        &'a self,
+        document: QueryDocument,
+        query_schema: &'a QuerySchemaRef,
+        tx: Option<&'a (dyn Transaction<'tx> + 'tx)>,
+    ) -> CoreResult<Vec<Response>> {
+        match tx {
+            Some(tx) => {
+                let queries = QueryGraphBuilder::new(Arc::clone(query_schema)).build(document)?;
+                let mut responses = Vec::with_capacity(queries.len());
+
+                for (query_graph, info) in queries {
+                    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx));
+                    responses.push(QueryPipeline::new(query_graph, interpreter, info).execute().await?);
+                }
+
+                Ok(responses)
+            }
+            None => self.execute(document, Arc::clone(query_schema)).await,
+        }
+    }
+}
+
+/// Inside a transaction, a failing document fails the entire batch. Outside, it only fails its own result.
+fn fail_in_transaction(
+    result: CoreResult<Vec<Response>>,
+    transactional: bool,
+) -> CoreResult<CoreResult<Vec<Response>>> {
+    match result {
+        Err(err) if transactional => Err(err),
+        result => Ok(result),
+    }
 }
 
 #[async_trait]
 impl<C> QueryExecutor for InterpretingExecutor<C>
 where
-    C: Connector + Send + Sync,
+    C: Connector + Send + Sync + 'static,
 {
     async fn execute(
         &self,
@@ -68,6 +146,42 @@ where
         Ok(results)
     }
 
+    async fn execute_batch(
+        &self,
+        batch: Vec<QueryDocument>,
+        transactional: bool,
+        query_schema: QuerySchemaRef,
+    ) -> CoreResult<Vec<CoreResult<Vec<Response>>>> {
+        let size = batch.len();
+        let steps = batch::compact(batch, &query_schema);
+
+        if transactional {
+            let conn = self.connector.get_connection().await?;
+            let tx = conn.start_transaction().await?;
+            let result = self
+                .execute_batch_steps(steps, size, &query_schema, Some(tx.as_ref()))
+                .await;
+
+            if result.is_ok() {
+                tx.commit().await?;
+            } else {
+                tx.rollback().await?;
+            }
+
+            result
+        } else {
+            self.execute_batch_steps(steps, size, &query_schema, None).await
+        }
+    }
+
+    async fn start_transaction(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        timeout: Duration,
+    ) -> CoreResult<InteractiveTransaction> {
+        InteractiveTransaction::start(Arc::clone(&self.connector), isolation_level, timeout).await
+    }
+
     fn primary_connector(&self) -> &'static str {
         self.primary_connector
     }
@@ -5,13 +5,18 @@
 //!
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
+mod batch;
+mod interactive_transaction;
 mod interpreting_executor;
 mod pipeline;
 
+pub use interactive_transaction::*;
 pub use interpreting_executor::*;
 
 use crate::{query_document::QueryDocument, response_ir::Response, schema::QuerySchemaRef, CoreResult};
 use async_trait::async_trait;
+use connector::IsolationLevel;
+use std::time::Duration;
 
 #[async_trait]
 pub trait QueryExecutor {
@@ -21,5 +26,25 @@ pub trait QueryExecutor {
         query_schema: QuerySchemaRef,
     ) -> CoreResult<Vec<Response>>;
 
+    /// Executes a batch of query documents, returning one result per document, in order.
+    /// Adjacent `findOne` lookups of the same model and selection are compacted into a single query.
+    ///
+    /// If `transactional` is set, all documents are executed in one transaction, which is rolled back
+    /// as a whole if any of the documents fails, in which case the error is returned for the entire batch.
+    async fn execute_batch(
+        &self,
+        batch: Vec<QueryDocument>,
+        transactional: bool,
+        query_schema: QuerySchemaRef,
+    ) -> CoreResult<Vec<CoreResult<Vec<Response>>>>;
+
+    /// Starts an interactive transaction, running with the given isolation level or the database default.
+    /// The transaction is rolled back if it isn't committed or rolled back within the timeout.
+    async fn start_transaction(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        timeout: Duration,
+    ) -> CoreResult<InteractiveTransaction>;
+
     fn primary_connector(&self) -> &'static str;
 }
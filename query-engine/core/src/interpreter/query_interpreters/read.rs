@@ -1,5 +1,5 @@
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, QueryArguments, ScalarListValues, ReadOperations, ConnectionLike};
+use connector::{self, ConnectionLike, ReadOperations, ScalarListValues};
 use futures::future::{BoxFuture, FutureExt};
 use prisma_models::{GraphqlId, ScalarField, SelectedFields};
 use std::sync::Arc;
@@ -15,6 +15,8 @@ pub fn execute<'a, 'b>(
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_ids).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::GroupByRecordsQuery(q) => group_by(tx, q).await,
+            ReadQuery::QueryRaw(q) => query_raw(tx, q).await,
         }
     };
 
@@ -110,12 +112,7 @@ fn read_related<'a, 'b>(
         };
 
         let scalars = tx
-            .get_related_records(
-                &query.parent_field,
-                parent_ids,
-                query.args.clone(),
-                &selected_fields,
-            )
+            .get_related_records(&query.parent_field, parent_ids, query.args.clone(), &selected_fields)
             .await?;
 
         let model = query.parent_field.related_model();
@@ -143,8 +140,28 @@ async fn aggregate<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: AggregateRecordsQuery,
 ) -> InterpretationResult<QueryResult> {
-    let result = tx.count_by_model(&query.model, QueryArguments::default()).await?;
-    Ok(QueryResult::Count(result))
+    let results = tx.aggregate_records(&query.model, query.selectors, query.args).await?;
+
+    Ok(QueryResult::RecordAggregation(RecordAggregation {
+        selection_order: query.selection_order,
+        results,
+    }))
+}
+
+async fn group_by<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: GroupByRecordsQuery,
+) -> InterpretationResult<QueryResult> {
+    let group_by_fields = query.group_by.iter().map(|f| f.name.clone()).collect();
+    let groups = tx
+        .group_records(&query.model, query.group_by, query.selectors, query.having, query.args)
+        .await?;
+
+    Ok(QueryResult::RecordGroups(RecordGroups {
+        group_by: group_by_fields,
+        selection_order: query.selection_order,
+        groups,
+    }))
 }
 
 /// Resolves scalar lists for a list field for a set of parent IDs.
@@ -205,3 +222,10 @@ fn process_nested<'a, 'b>(
 
     fut.boxed()
 }
+
+/// Runs a raw query, returning the result rows as JSON.
+async fn query_raw<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, query: QueryRaw) -> InterpretationResult<QueryResult> {
+    let result = tx.query_raw(query.query, query.parameters).await?;
+
+    Ok(QueryResult::Json(result))
+}
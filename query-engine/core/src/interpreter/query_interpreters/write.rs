@@ -11,6 +11,7 @@ pub async fn execute<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     match write_query {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
+        WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
@@ -19,6 +20,7 @@ pub async fn execute<'a, 'b>(
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
         // WriteQuery::SetRecords(q) => set(tx, q).await,
         WriteQuery::ResetData(q) => reset(tx, q).await,
+        WriteQuery::ExecuteRaw(q) => execute_raw(tx, q).await,
     }
 }
 
@@ -30,6 +32,16 @@ async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) ->
     Ok(QueryResult::Id(res))
 }
 
+async fn create_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let args = q.args.into_iter().map(|args| WriteArgs::new(args, vec![])).collect();
+    let res = tx.create_records(&q.model, args).await?;
+
+    Ok(QueryResult::Count(res))
+}
+
 async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
     let mut res = tx
         .update_records(
@@ -98,6 +110,14 @@ async fn disconnect<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DisconnectRecords
     Ok(QueryResult::Unit)
 }
 
-async fn reset<'a, 'b>(_tx: &'a ConnectionLike<'a, 'b>, _q: ResetData) -> InterpretationResult<QueryResult> {
-    unimplemented!()
+async fn reset<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: ResetData) -> InterpretationResult<QueryResult> {
+    tx.truncate_data(&q.internal_data_model).await?;
+
+    Ok(QueryResult::Unit)
+}
+
+async fn execute_raw<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: ExecuteRaw) -> InterpretationResult<QueryResult> {
+    let res = tx.execute_raw(q.query, q.parameters).await?;
+
+    Ok(QueryResult::Json(serde_json::Value::from(res)))
 }
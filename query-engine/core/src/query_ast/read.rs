@@ -1,6 +1,9 @@
 //! Prisma read query AST
 use super::RecordFinderInjector;
-use connector::{filter::RecordFinder, QueryArguments};
+use connector::{
+    filter::{Filter, RecordFinder},
+    AggregationSelection, QueryArguments,
+};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -10,6 +13,8 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    GroupByRecordsQuery(GroupByRecordsQuery),
+    QueryRaw(QueryRaw),
 }
 
 impl ReadQuery {
@@ -19,6 +24,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::GroupByRecordsQuery(x) => &x.name,
+            ReadQuery::QueryRaw(x) => &x.name,
         }
     }
 }
@@ -55,6 +62,18 @@ impl Display for ReadQuery {
                 q.parent_field.name
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::GroupByRecordsQuery(q) => write!(
+                f,
+                "GroupByRecordsQuery(name: '{}', model: {}, by: {})",
+                q.name,
+                q.model.name,
+                q.group_by
+                    .iter()
+                    .map(|f| f.name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ),
+            Self::QueryRaw(q) => write!(f, "QueryRaw(query: '{}', parameters: {:?})", q.query, q.parameters),
         }
     }
 }
@@ -97,4 +116,45 @@ pub struct AggregateRecordsQuery {
     pub name: String,
     pub alias: Option<String>,
     pub model: ModelRef,
+    pub args: QueryArguments,
+    pub selectors: Vec<AggregationSelection>,
+
+    /// Response keys of the selected aggregations, in order. Field based aggregations
+    /// additionally carry the response keys of their selected fields, in order.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupByRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub args: QueryArguments,
+
+    /// Fields the records are grouped by, in order.
+    pub group_by: Vec<ScalarFieldRef>,
+
+    /// Filter on the aggregations of the groups.
+    pub having: Option<Filter>,
+    pub selectors: Vec<AggregationSelection>,
+    pub selection_order: Vec<GroupBySelection>,
+}
+
+/// A selection on the output of a grouped query, by response key.
+#[derive(Debug, Clone)]
+pub enum GroupBySelection {
+    /// A field the records are grouped by, with the name of the field.
+    Field(String, String),
+
+    /// An aggregation, carrying the response keys of the selected fields for field based aggregations.
+    Aggregation(String, Option<Vec<String>>),
+}
+
+/// A raw query as given by the user, with positional parameters.
+#[derive(Debug, Clone)]
+pub struct QueryRaw {
+    pub name: String,
+    pub alias: Option<String>,
+    pub query: String,
+    pub parameters: Vec<PrismaValue>,
 }
@@ -6,6 +6,7 @@ use prisma_models::prelude::*;
 #[derive(Debug, Clone)]
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(UpdateRecord),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
@@ -13,6 +14,7 @@ pub enum WriteQuery {
     ConnectRecords(ConnectRecords),
     DisconnectRecords(DisconnectRecords),
     ResetData(ResetData),
+    ExecuteRaw(ExecuteRaw),
 }
 
 impl WriteQuery {
@@ -54,6 +56,12 @@ impl std::fmt::Display for WriteQuery {
                 "CreateRecord(model: {}, non-list-args: {:?}, list_args: {:?})",
                 q.model.name, q.non_list_args, q.list_args,
             ),
+            Self::CreateManyRecords(q) => write!(
+                f,
+                "CreateManyRecords(model: {}, records: {})",
+                q.model.name,
+                q.args.len()
+            ),
             Self::UpdateRecord(q) => write!(
                 f,
                 "UpdateRecord(model: {}, finder: {:?}, non-list-args: {:?}, list_args: {:?})",
@@ -86,6 +94,7 @@ impl std::fmt::Display for WriteQuery {
             Self::ConnectRecords(_) => write!(f, "ConnectRecords"),
             Self::DisconnectRecords(_) => write!(f, "DisconnectRecords"),
             Self::ResetData(_) => write!(f, "ResetData"),
+            Self::ExecuteRaw(q) => write!(f, "ExecuteRaw(query: '{}', parameters: {:?})", q.query, q.parameters),
         }
     }
 }
@@ -97,6 +106,12 @@ pub struct CreateRecord {
     pub list_args: Vec<(String, PrismaListValue)>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<PrismaArgs>,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateRecord {
     pub model: ModelRef,
@@ -143,3 +158,10 @@ pub struct DisconnectRecords {
 pub struct ResetData {
     pub internal_data_model: InternalDataModelRef,
 }
+
+/// A raw statement as given by the user, with positional parameters.
+#[derive(Debug, Clone)]
+pub struct ExecuteRaw {
+    pub query: String,
+    pub parameters: Vec<PrismaValue>,
+}
@@ -2,8 +2,10 @@ use super::*;
 use crate::schema::*;
 use chrono::prelude::*;
 use prisma_models::{GraphqlId, PrismaValue};
+use rust_decimal::Decimal;
 use std::{
     collections::{BTreeMap, HashSet},
+    str::FromStr,
     sync::Arc,
 };
 use uuid::Uuid;
@@ -180,6 +182,9 @@ impl QueryDocumentParser {
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(f)),
             (QueryValue::Float(f), ScalarType::Int)       => Ok(PrismaValue::Int(f as i64)),
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(Decimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Self::parse_decimal(&f.to_string()).map(PrismaValue::Decimal),
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
             (QueryValue::Enum(e), ScalarType::Enum(et))   => match et.value_for(e.as_str()) {
                                                                 Some(val) => Ok(PrismaValue::Enum(val.clone())),
@@ -207,6 +212,10 @@ impl QueryDocumentParser {
             })
     }
 
+    pub fn parse_decimal(s: &str) -> QueryParserResult<Decimal> {
+        Decimal::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid decimal: {}", err)))
+    }
+
     pub fn parse_json(s: &str) -> QueryParserResult<serde_json::Value> {
         serde_json::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err)))
     }
@@ -26,10 +26,9 @@ pub struct QueryDocument {
 pub enum Operation {
     Read(Selection),
     Write(Selection),
-    // "Batch" for grouping operations into one transaction?
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Selection {
     pub name: String,
     pub alias: Option<String>,
@@ -37,7 +36,7 @@ pub struct Selection {
     pub nested_selections: Vec<Selection>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum QueryValue {
     Int(i64),
     Float(f64),
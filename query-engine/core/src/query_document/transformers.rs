@@ -128,6 +128,26 @@ impl TryInto<Option<OrderBy>> for ParsedInputValue {
     }
 }
 
+impl TryInto<Vec<OrderBy>> for ParsedInputValue {
+    type Error = QueryParserError;
+
+    fn try_into(self) -> QueryParserResult<Vec<OrderBy>> {
+        match self {
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .filter_map(|value| {
+                    let order_by: QueryParserResult<Option<OrderBy>> = value.try_into();
+                    order_by.transpose()
+                })
+                .collect(),
+            value => {
+                let order_by: Option<OrderBy> = value.try_into()?;
+                Ok(order_by.into_iter().collect())
+            }
+        }
+    }
+}
+
 impl TryInto<Option<f64>> for ParsedInputValue {
     type Error = QueryParserError;
 
@@ -6,6 +6,7 @@ mod error;
 mod read;
 mod utils;
 
+pub mod raw;
 pub mod write;
 
 pub use builder::*;
@@ -0,0 +1,67 @@
+//! Builders for raw database access, bypassing the data model.
+use super::*;
+use crate::{
+    query_document::{ArgumentListLookup, ParsedField},
+    ExecuteRaw, Query, QueryGraph, QueryRaw, ReadQuery, WriteQuery,
+};
+use prisma_models::PrismaValue;
+use serde_json::Value;
+use std::convert::TryInto;
+
+/// Builds a graph running a raw query, e.g. for reporting queries that can't be expressed with filters.
+pub fn query_raw(mut field: ParsedField) -> QueryGraphBuilderResult<QueryGraph> {
+    let (query, parameters) = extract_raw_arguments(&mut field)?;
+    let mut graph = QueryGraph::new();
+
+    graph.create_node(Query::Read(ReadQuery::QueryRaw(QueryRaw {
+        name: field.name,
+        alias: field.alias,
+        query,
+        parameters,
+    })));
+
+    Ok(graph)
+}
+
+/// Builds a graph executing a raw statement.
+pub fn execute_raw(mut field: ParsedField) -> QueryGraphBuilderResult<QueryGraph> {
+    let (query, parameters) = extract_raw_arguments(&mut field)?;
+    let mut graph = QueryGraph::new();
+
+    graph.create_node(Query::Write(WriteQuery::ExecuteRaw(ExecuteRaw { query, parameters })));
+    Ok(graph)
+}
+
+/// Extracts the query string and the positional parameters, which are given as a JSON array.
+fn extract_raw_arguments(field: &mut ParsedField) -> QueryGraphBuilderResult<(String, Vec<PrismaValue>)> {
+    let query: Option<String> = field.arguments.lookup("query").unwrap().value.try_into()?;
+    let parameters: Option<Value> = match field.arguments.lookup("parameters") {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    let parameters = match parameters {
+        Some(Value::Array(values)) => values.into_iter().map(parameter_value).collect(),
+        Some(Value::Null) | None => vec![],
+        Some(_) => {
+            return Err(QueryGraphBuilderError::InputError(
+                "Raw query parameters must be given as a JSON array.".into(),
+            ))
+        }
+    };
+
+    Ok((query.unwrap(), parameters))
+}
+
+fn parameter_value(value: Value) -> PrismaValue {
+    match value {
+        Value::Null => PrismaValue::Null,
+        Value::Bool(b) => PrismaValue::Boolean(b),
+        Value::String(s) => PrismaValue::String(s),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => PrismaValue::Int(i),
+            None => PrismaValue::Float(n.as_f64().unwrap()),
+        },
+        value => PrismaValue::Json(value),
+    }
+}
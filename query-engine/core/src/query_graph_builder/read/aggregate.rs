@@ -1,6 +1,10 @@
 use super::*;
-use crate::{query_document::ParsedField, AggregateRecordsQuery, ReadQuery};
-use prisma_models::ModelRef;
+use crate::{
+    query_document::{ParsedField, ParsedObject},
+    AggregateRecordsQuery, ReadQuery,
+};
+use connector::AggregationSelection;
+use prisma_models::{ModelRef, ScalarFieldRef};
 
 pub struct AggregateRecordsBuilder {
     field: ParsedField,
@@ -15,14 +19,73 @@ impl AggregateRecordsBuilder {
 
 impl Builder<ReadQuery> for AggregateRecordsBuilder {
     fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let args = utils::extract_query_args(self.field.arguments, &self.model)?;
         let name = self.field.name;
         let alias = self.field.alias;
         let model = self.model;
+        let nested_fields = aggregation_fields(self.field.nested_fields, &name)?;
+
+        let mut selectors = vec![];
+        let mut selection_order = vec![];
+
+        for selected in nested_fields {
+            let key = selected.alias.clone().unwrap_or_else(|| selected.name.clone());
+            let (selector, field_keys) = extract_aggregation_selector(selected, &model)?;
+
+            selectors.push(selector);
+            selection_order.push((key, field_keys));
+        }
 
         Ok(ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
             name,
             alias,
             model,
+            args,
+            selectors,
+            selection_order,
         }))
     }
 }
+
+/// Extracts the aggregation selector from a selected aggregation field (e.g. "count" or "sum").
+/// Returns the response keys of the selected fields for field based aggregations.
+pub fn extract_aggregation_selector(
+    selected: ParsedField,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<(AggregationSelection, Option<Vec<String>>)> {
+    if selected.name == "count" {
+        return Ok((AggregationSelection::Count, None));
+    }
+
+    let fields = aggregation_fields(selected.nested_fields, &selected.name)?;
+    let field_keys = collect_selection_order(&fields);
+    let scalar_fields = fields
+        .iter()
+        .map(|f| model.fields().find_from_scalar(&f.name))
+        .collect::<Result<Vec<ScalarFieldRef>, _>>()?;
+
+    let selector = match selected.name.as_str() {
+        "sum" => AggregationSelection::Sum(scalar_fields),
+        "avg" => AggregationSelection::Average(scalar_fields),
+        "min" => AggregationSelection::Min(scalar_fields),
+        "max" => AggregationSelection::Max(scalar_fields),
+        name => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Unknown aggregation '{}' on model '{}'.",
+                name, model.name
+            )))
+        }
+    };
+
+    Ok((selector, Some(field_keys)))
+}
+
+/// The fields selected on an aggregation field, which has to have a selection set.
+pub fn aggregation_fields(
+    nested_fields: Option<ParsedObject>,
+    name: &str,
+) -> QueryGraphBuilderResult<Vec<ParsedField>> {
+    nested_fields
+        .map(|object| object.fields)
+        .ok_or_else(|| QueryGraphBuilderError::InputError(format!("Expected a selection of fields for '{}'.", name)))
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::{
+    query_document::{ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue},
+    GroupByRecordsQuery, GroupBySelection, ReadQuery,
+};
+use prisma_models::{EnumValue, ModelRef, ScalarFieldRef};
+use std::convert::TryInto;
+
+pub struct GroupByRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl GroupByRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+}
+
+impl Builder<ReadQuery> for GroupByRecordsBuilder {
+    fn build(mut self) -> QueryGraphBuilderResult<ReadQuery> {
+        let group_by = match self.field.arguments.lookup("by") {
+            Some(arg) => extract_group_by_fields(arg.value, &self.model)?,
+            None => vec![],
+        };
+
+        let having = match self.field.arguments.lookup("having") {
+            Some(arg) => {
+                let value: Option<ParsedInputMap> = arg.value.try_into()?;
+                match value {
+                    Some(map) => Some(utils::extract_having_filter(map, &self.model)?),
+                    None => None,
+                }
+            }
+            None => None,
+        };
+
+        let args = utils::extract_query_args(self.field.arguments, &self.model)?;
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+        let nested_fields = aggregation_fields(self.field.nested_fields, &name)?;
+
+        if group_by.is_empty() {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "At least one field of model '{}' is required to group by.",
+                model.name
+            )));
+        }
+
+        for order_by in args.order_by.iter() {
+            match order_by.scalar_field() {
+                Some(field) if is_grouped(&group_by, field) => (),
+                Some(field) => {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "Groups can only be ordered by fields that are grouped by, but '{}' is not part of 'by'.",
+                        field.name
+                    )))
+                }
+                None => {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "Groups can only be ordered by fields that are grouped by, not by related values ('{}').",
+                        order_by.name()
+                    )))
+                }
+            }
+        }
+
+        let mut selectors = vec![];
+        let mut selection_order = vec![];
+
+        for selected in nested_fields {
+            let key = selected.alias.clone().unwrap_or_else(|| selected.name.clone());
+
+            match selected.name.as_str() {
+                "count" | "sum" | "avg" | "min" | "max" => {
+                    let (selector, field_keys) = extract_aggregation_selector(selected, &model)?;
+
+                    selectors.push(selector);
+                    selection_order.push(GroupBySelection::Aggregation(key, field_keys));
+                }
+                field_name => {
+                    let field = model.fields().find_from_scalar(field_name)?;
+
+                    if !is_grouped(&group_by, &field) {
+                        return Err(QueryGraphBuilderError::InputError(format!(
+                            "Field '{}' can only be selected if it is part of 'by'.",
+                            field.name
+                        )));
+                    }
+
+                    selection_order.push(GroupBySelection::Field(key, field.name.clone()));
+                }
+            }
+        }
+
+        Ok(ReadQuery::GroupByRecordsQuery(GroupByRecordsQuery {
+            name,
+            alias,
+            model,
+            args,
+            group_by,
+            having,
+            selectors,
+            selection_order,
+        }))
+    }
+}
+
+/// Extracts the fields to group by from the list of scalar field enum values.
+fn extract_group_by_fields(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+    let values: Vec<ParsedInputValue> = value.try_into()?;
+    let mut fields: Vec<ScalarFieldRef> = vec![];
+
+    for value in values {
+        let enum_value: Option<EnumValue> = value.try_into()?;
+        let field_name = enum_value.map(|v| v.as_string()).unwrap_or_default();
+        let field = model.fields().find_from_scalar(&field_name)?;
+
+        if !is_grouped(&fields, &field) {
+            fields.push(field);
+        }
+    }
+
+    Ok(fields)
+}
+
+fn is_grouped(group_by: &[ScalarFieldRef], field: &ScalarFieldRef) -> bool {
+    group_by.iter().any(|f| f.name == field.name)
+}
@@ -1,9 +1,11 @@
 mod aggregate;
+mod group_by;
 mod many;
 mod one;
 mod related;
 
 pub use aggregate::*;
+pub use group_by::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
@@ -20,6 +22,7 @@ pub enum ReadQueryBuilder {
     ReadManyRecordsBuilder(ReadManyRecordsBuilder),
     ReadRelatedRecordsBuilder(ReadRelatedRecordsBuilder),
     AggregateRecordsBuilder(AggregateRecordsBuilder),
+    GroupByRecordsBuilder(GroupByRecordsBuilder),
 }
 
 impl Builder<ReadQuery> for ReadQueryBuilder {
@@ -29,6 +32,7 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
             ReadQueryBuilder::ReadManyRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::ReadRelatedRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::AggregateRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::GroupByRecordsBuilder(b) => b.build(),
         }
     }
 }
@@ -1,12 +1,19 @@
 use super::*;
 use crate::query_document::ParsedInputValue;
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
+use connector::{
+    filter::{AggregationFilter, Filter, ScalarCondition},
+    RelationCompare, ScalarCompare, ScalarListCompare,
+};
 use prisma_models::{Field, ModelRef, PrismaListValue, PrismaValue};
 use std::{collections::BTreeMap, convert::TryFrom, convert::TryInto};
 
 lazy_static! {
     /// Filter operations in descending order of how they should be checked.
     static ref FILTER_OPERATIONS: Vec<FilterOp> = vec![
+        FilterOp::HasEvery,
+        FilterOp::HasSome,
+        FilterOp::Has,
+        FilterOp::IsEmpty,
         FilterOp::NotIn,
         FilterOp::NotContains,
         FilterOp::NotStartsWith,
@@ -48,6 +55,10 @@ enum FilterOp {
     Some,
     None,
     Every,
+    Has,
+    HasEvery,
+    HasSome,
+    IsEmpty,
     NestedAnd,
     NestedOr,
     NestedNot,
@@ -83,6 +94,10 @@ impl FilterOp {
             FilterOp::Some => "_some",
             FilterOp::None => "_none",
             FilterOp::Every => "_every",
+            FilterOp::Has => "_has",
+            FilterOp::HasEvery => "_has_every",
+            FilterOp::HasSome => "_has_some",
+            FilterOp::IsEmpty => "_is_empty",
             FilterOp::NestedAnd => "AND",
             FilterOp::NestedOr => "OR",
             FilterOp::NestedNot => "NOT",
@@ -125,6 +140,25 @@ pub fn extract_filter(
                     let field = model.fields().find_from_all(&field_name).unwrap();
 
                     match field {
+                        Field::Scalar(s) if s.is_list => {
+                            let value: PrismaValue = value.try_into()?;
+                            Ok(match op {
+                                FilterOp::Has => s.contains_element(value),
+                                FilterOp::HasEvery => {
+                                    s.contains_every_element(PrismaListValue::try_from(value)?.unwrap_or_default())
+                                }
+                                FilterOp::HasSome => {
+                                    s.contains_some_element(PrismaListValue::try_from(value)?.unwrap_or_default())
+                                }
+                                FilterOp::IsEmpty => match value {
+                                    PrismaValue::Boolean(true) => s.contains_none(),
+                                    PrismaValue::Boolean(false) => Filter::not(vec![s.contains_none()]),
+                                    // A null filter value doesn't filter.
+                                    _ => Filter::empty(),
+                                },
+                                _ => unreachable!(),
+                            })
+                        }
                         Field::Scalar(s) => {
                             let value: PrismaValue = value.try_into()?;
                             Ok(match op {
@@ -173,3 +207,79 @@ pub fn extract_filter(
 
     Ok(Filter::and(filters))
 }
+
+/// Extracts a filter on the aggregations of grouped records, e.g. `{ count_gt: 1, sum: { age_lt: 100 } }`.
+/// Counts are filtered directly, field based aggregations carry the field filters of the aggregated fields.
+pub fn extract_having_filter(
+    value_map: BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Filter> {
+    let mut filters = vec![];
+
+    for (key, value) in value_map {
+        let op = FilterOp::find_op(key.as_str()).unwrap();
+
+        match op {
+            op if (op == FilterOp::NestedAnd || op == FilterOp::NestedOr || op == FilterOp::NestedNot) => {
+                let nested: Vec<Filter> = match value {
+                    ParsedInputValue::List(values) => values
+                        .into_iter()
+                        .map(|val| extract_having_filter(val.try_into()?, model))
+                        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?,
+
+                    ParsedInputValue::Map(map) => vec![extract_having_filter(map, model)?],
+                    _ => unreachable!(),
+                };
+
+                filters.push(match op {
+                    FilterOp::NestedAnd => Filter::and(nested),
+                    FilterOp::NestedOr => Filter::or(nested),
+                    FilterOp::NestedNot => Filter::not(nested),
+                    _ => unreachable!(),
+                });
+            }
+            op if key.trim_end_matches(op.suffix()) == "count" => {
+                let condition = scalar_condition(op, value.try_into()?)?;
+                filters.push(AggregationFilter::Count(condition).into());
+            }
+            _ => {
+                let field_filters: BTreeMap<String, ParsedInputValue> = value.try_into()?;
+
+                for (field_key, value) in field_filters {
+                    let op = FilterOp::find_op(field_key.as_str()).unwrap();
+                    let field = model
+                        .fields()
+                        .find_from_scalar(field_key.trim_end_matches(op.suffix()))?;
+                    let condition = scalar_condition(op, value.try_into()?)?;
+
+                    let filter = match key.as_str() {
+                        "sum" => AggregationFilter::Sum(field, condition),
+                        "avg" => AggregationFilter::Average(field, condition),
+                        "min" => AggregationFilter::Min(field, condition),
+                        "max" => AggregationFilter::Max(field, condition),
+                        _ => unreachable!(),
+                    };
+
+                    filters.push(filter.into());
+                }
+            }
+        }
+    }
+
+    Ok(Filter::and(filters))
+}
+
+/// Maps a comparison filter operation on numbers or dates to a scalar condition.
+fn scalar_condition(op: FilterOp, value: PrismaValue) -> QueryGraphBuilderResult<ScalarCondition> {
+    Ok(match op {
+        FilterOp::In => ScalarCondition::In(PrismaListValue::try_from(value)?),
+        FilterOp::NotIn => ScalarCondition::NotIn(PrismaListValue::try_from(value)?),
+        FilterOp::Not => ScalarCondition::NotEquals(value),
+        FilterOp::Lt => ScalarCondition::LessThan(value),
+        FilterOp::Lte => ScalarCondition::LessThanOrEquals(value),
+        FilterOp::Gt => ScalarCondition::GreaterThan(value),
+        FilterOp::Gte => ScalarCondition::GreaterThanOrEquals(value),
+        FilterOp::Field => ScalarCondition::Equals(value),
+        _ => unreachable!(),
+    })
+}
@@ -48,6 +48,31 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     Ok(())
 }
 
+/// Creates a create many records query and adds it to the query graph.
+/// Nested writes are not supported, so each record maps to plain (non-list) write arguments.
+pub fn create_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    let data_argument = field.arguments.lookup("data").unwrap();
+    let args = utils::coerce_vec(data_argument.value)
+        .into_iter()
+        .map(|data_value| {
+            let data_map: ParsedInputMap = data_value.try_into()?;
+            let mut non_list_args = WriteArguments::from(&model, data_map)?.non_list;
+
+            non_list_args.add_datetimes(Arc::clone(&model));
+            Ok(non_list_args)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    let create_many = WriteQuery::CreateManyRecords(CreateManyRecords { model, args });
+
+    graph.create_node(Query::Write(create_many));
+    Ok(())
+}
+
 pub fn create_record_node(
     graph: &mut QueryGraph,
     model: ModelRef,
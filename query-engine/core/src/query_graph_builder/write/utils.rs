@@ -3,9 +3,9 @@ use crate::{
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
     ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::RecordFinder, Filter, QueryArguments};
+use connector::{filter::RecordFinder, Filter, QueryArguments, ScalarCompare};
 use itertools::Itertools;
-use prisma_models::{ModelRef, PrismaArgs, PrismaValue, RelationFieldRef, SelectedFields};
+use prisma_models::{ModelRef, PrismaArgs, PrismaValue, ReferentialAction, RelationFieldRef, SelectedFields};
 use std::{convert::TryInto, sync::Arc};
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -233,10 +233,16 @@ pub fn insert_existing_1to1_related_model_checks(
     Ok(())
 }
 
-/// Inserts checks into the graph that check all required, non-list relations pointing to
-/// the given `model`. Those checks fail at runtime (edges to the `Empty` node) if one or more
+/// Inserts checks into the graph that check all non-list relations pointing to the given `model`
+/// with a restricting referential action (`Restrict` or `NoAction`, the default for required relations).
+/// Those checks fail at runtime (edges to the `Empty` node) if one or more
 /// records are found. Checks are inserted between `parent_node` and `child_node`.
 ///
+/// Relations that explicitly declare `Cascade` or `SetNull` are emulated the same way: instead of failing,
+/// the referencing records are deleted or their foreign key is nulled out before the deletion
+/// (see `insert_emulated_referential_action`). Undeclared actions of optional relations and
+/// `SetDefault` are left to the database.
+///
 /// This function is usually part of a delete (`deleteOne` or `deleteMany`).
 /// Expects `parent_node` to return one or more IDs (for records of `model`) to be checked.
 ///
@@ -278,31 +284,58 @@ pub fn insert_deletion_checks(
     model: &ModelRef,
     parent_node: &NodeRef,
     child_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    insert_deletion_checks_along(graph, model, parent_node, child_node, &[])
+}
+
+/// `cascaded` holds the names of the relations the deletion already cascaded through, to not cascade in cycles.
+fn insert_deletion_checks_along(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+    cascaded: &[String],
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
-    let relation_fields = internal_model.fields_requiring_model(model);
+    let relation_fields = internal_model.fields_referencing_model(model);
     let mut check_nodes = vec![];
 
     if relation_fields.len() > 0 {
         let noop_node = graph.create_node(Node::Empty);
 
-        // We know that the relation can't be a list and must be required on the related model for `model` (see fields_requiring_model).
-        // For all requiring models (RM), we use the field on `model` to query for existing RM records and error out if at least one exists.
+        // We know that the relation can't be a list on the related model for `model` (see fields_referencing_model).
+        // For all referencing models (RM), we use the field on `model` to query for existing RM records and either
+        // error out if at least one exists, or apply the referential action of the relation to them.
         for rf in relation_fields {
+            let on_delete = rf.on_delete();
+            let emulate = match rf.declared_on_delete() {
+                Some(ReferentialAction::Cascade) => !cascaded.contains(&rf.relation().name),
+                Some(ReferentialAction::SetNull) => true,
+                _ => false,
+            } && rf.relation_is_inlined_in_parent();
+
+            if !on_delete.is_restrict() && !emulate {
+                continue;
+            }
+
             let relation_field = rf.related_field();
             let read_node = insert_find_children_by_parent_node(graph, parent_node, &relation_field, None)?;
 
-            graph.create_edge(
-                &read_node,
-                &noop_node,
-                QueryGraphDependency::ParentIds(Box::new(move |node, parent_ids| {
-                    if !parent_ids.is_empty() {
-                        return Err(QueryGraphBuilderError::RelationViolation((relation_field).into()));
-                    }
-
-                    Ok(node)
-                })),
-            )?;
+            if emulate {
+                insert_emulated_referential_action(graph, &rf, &read_node, &noop_node, cascaded)?;
+            } else {
+                graph.create_edge(
+                    &read_node,
+                    &noop_node,
+                    QueryGraphDependency::ParentIds(Box::new(move |node, parent_ids| {
+                        if !parent_ids.is_empty() {
+                            return Err(QueryGraphBuilderError::RelationViolation((relation_field).into()));
+                        }
+
+                        Ok(node)
+                    })),
+                )?;
+            }
 
             check_nodes.push(read_node);
         }
@@ -322,3 +355,74 @@ pub fn insert_deletion_checks(
 
     Ok(())
 }
+
+/// Emulates the `Cascade` or `SetNull` action of `referencing_field`, so that it also applies where the
+/// database doesn't enforce foreign keys. `read_node` returns the IDs of the referencing records, which are
+/// then deleted or disconnected before `noop_node` runs. Deleting the referencing records is checked the
+/// same way as any other deletion, so their own relations restrict or cascade further.
+///
+/// ```text
+/// ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ┐
+///   Find Connected Model
+/// └ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ┘
+///              │
+///              ▼
+/// ┌────────────────────────┐
+/// │ Delete / Null out Many │
+/// └────────────────────────┘
+///              │
+///              ▼
+/// ┌ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ┐
+///          Empty
+/// └ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ─ ┘
+/// ```
+fn insert_emulated_referential_action(
+    graph: &mut QueryGraph,
+    referencing_field: &RelationFieldRef,
+    read_node: &NodeRef,
+    noop_node: &NodeRef,
+    cascaded: &[String],
+) -> QueryGraphBuilderResult<()> {
+    let referencing_model = referencing_field.model();
+    let id_field = referencing_model.fields().id();
+    let relation_field_name = referencing_field.name.clone();
+
+    let write_node = match referencing_field.on_delete() {
+        ReferentialAction::Cascade => {
+            let delete_node = graph.create_node(Query::Write(WriteQuery::DeleteManyRecords(DeleteManyRecords {
+                model: Arc::clone(&referencing_model),
+                filter: Filter::empty(),
+            })));
+
+            let mut cascaded = cascaded.to_vec();
+            cascaded.push(referencing_field.relation().name.clone());
+
+            insert_deletion_checks_along(graph, &referencing_model, read_node, &delete_node, &cascaded)?;
+            delete_node
+        }
+        _ => update_records_node_placeholder(graph, Filter::empty(), referencing_model),
+    };
+
+    graph.create_edge(
+        read_node,
+        &write_node,
+        QueryGraphDependency::ParentIds(Box::new(move |mut node, parent_ids| {
+            match node {
+                Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dm))) => {
+                    dm.filter = id_field.is_in(Some(parent_ids));
+                }
+                Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut ur))) => {
+                    ur.filter = id_field.is_in(Some(parent_ids));
+                    ur.non_list_args.insert(relation_field_name, PrismaValue::Null);
+                }
+                _ => unreachable!(),
+            };
+
+            Ok(node)
+        })),
+    )?;
+
+    graph.create_edge(&write_node, noop_node, QueryGraphDependency::ExecutionOrder)?;
+
+    Ok(())
+}
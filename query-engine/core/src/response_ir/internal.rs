@@ -1,11 +1,12 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, CoreResult, QueryResult, RecordSelection,
+    CoreError, CoreResult, GroupBySelection, QueryResult, RecordAggregation, RecordGroups, RecordSelection,
 };
-use connector::ScalarListValues;
+use connector::{AggregationResult, ScalarListValues};
 use indexmap::IndexMap;
 use prisma_models::{GraphqlId, PrismaValue};
+use rust_decimal::Decimal;
 use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
 
 /// A grouping of items to their parent record.
@@ -52,8 +53,120 @@ pub fn serialize_internal(
             Ok(result)
         }
 
+        QueryResult::RecordAggregation(ra) => serialize_aggregation(ra),
+        QueryResult::RecordGroups(rg) => serialize_groups(rg),
+
+        QueryResult::Json(json) => {
+            let mut result = CheckedItemsWithParents::new();
+            result.insert(None, Item::Value(PrismaValue::Json(json)));
+
+            Ok(result)
+        }
+
+        // Operations without a result (e.g. resetData) signal successful completion.
+        QueryResult::Unit => {
+            let mut result = CheckedItemsWithParents::new();
+            result.insert(None, Item::Value(PrismaValue::Boolean(true)));
+
+            Ok(result)
+        }
+
         QueryResult::Id(_) => unimplemented!(),
-        QueryResult::Unit => unimplemented!(),
+    }
+}
+
+fn serialize_aggregation(record_aggregation: RecordAggregation) -> CoreResult<CheckedItemsWithParents> {
+    let mut results = record_aggregation.results.into_iter();
+    let mut map: IndexMap<String, Item> = IndexMap::new();
+
+    for (key, field_keys) in record_aggregation.selection_order {
+        let item = serialize_aggregation_result(&key, field_keys, &mut results)?;
+        map.insert(key, item);
+    }
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, Item::Map(map));
+
+    Ok(result)
+}
+
+fn serialize_groups(record_groups: RecordGroups) -> CoreResult<CheckedItemsWithParents> {
+    let group_by = record_groups.group_by;
+    let selection_order = record_groups.selection_order;
+    let mut items = vec![];
+
+    for group in record_groups.groups {
+        let mut results = group.aggregations.into_iter();
+        let mut map: IndexMap<String, Item> = IndexMap::new();
+
+        for selection in selection_order.iter() {
+            match selection {
+                GroupBySelection::Field(key, field_name) => {
+                    let value = group_by
+                        .iter()
+                        .position(|name| name == field_name)
+                        .and_then(|pos| group.key.get(pos).cloned())
+                        .ok_or_else(|| {
+                            CoreError::SerializationError(format!("Expected a group value for '{}'", key))
+                        })?;
+
+                    map.insert(key.clone(), Item::Value(value));
+                }
+
+                GroupBySelection::Aggregation(key, field_keys) => {
+                    let item = serialize_aggregation_result(key, field_keys.clone(), &mut results)?;
+                    map.insert(key.clone(), item);
+                }
+            }
+        }
+
+        items.push(Item::Map(map));
+    }
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, Item::List(items));
+
+    Ok(result)
+}
+
+/// Serializes the next aggregation results for the selected aggregation with the given response key.
+/// Consumes one result for counts and one result per selected field for field based aggregations.
+fn serialize_aggregation_result(
+    key: &str,
+    field_keys: Option<Vec<String>>,
+    results: &mut impl Iterator<Item = AggregationResult>,
+) -> CoreResult<Item> {
+    match field_keys {
+        None => match results.next() {
+            Some(AggregationResult::Count(c)) => Ok(Item::Value(PrismaValue::Int(c as i64))),
+            _ => Err(CoreError::SerializationError(format!(
+                "Expected a count result for '{}'",
+                key
+            ))),
+        },
+
+        Some(field_keys) => {
+            let mut nested: IndexMap<String, Item> = IndexMap::new();
+
+            for field_key in field_keys {
+                let value = match results.next() {
+                    Some(AggregationResult::Sum(_, value))
+                    | Some(AggregationResult::Average(_, value))
+                    | Some(AggregationResult::Min(_, value))
+                    | Some(AggregationResult::Max(_, value)) => value,
+                    _ => {
+                        return Err(CoreError::SerializationError(format!(
+                            "Expected an aggregation result for '{}.{}'",
+                            key, field_key
+                        )))
+                    }
+                };
+
+                nested.insert(field_key, Item::Value(value));
+            }
+
+            Ok(Item::Map(nested))
+        }
     }
 }
 
@@ -343,6 +456,9 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> CoreResult<Item>
                 (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
                 (ScalarType::Float, PrismaValue::Int(i)) => PrismaValue::Float(i as f64),
 
+                (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+                (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(Decimal::from(i)),
+
                 (ScalarType::Enum(ref et), PrismaValue::Enum(ref ev)) => match et.value_for(&ev.name) {
                     Some(_) => PrismaValue::Enum(ev.clone()),
                     None => {
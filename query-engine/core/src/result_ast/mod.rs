@@ -1,4 +1,5 @@
-use connector::{QueryArguments, ScalarListValues};
+use crate::GroupBySelection;
+use connector::{AggregationResult, QueryArguments, RecordGroup, ScalarListValues};
 use prisma_models::{GraphqlId, ManyRecords};
 
 #[derive(Debug, Clone)]
@@ -6,6 +7,9 @@ pub enum QueryResult {
     Id(GraphqlId),
     Count(usize),
     RecordSelection(RecordSelection),
+    RecordAggregation(RecordAggregation),
+    RecordGroups(RecordGroups),
+    Json(serde_json::Value),
     Unit,
 }
 
@@ -34,3 +38,25 @@ pub struct RecordSelection {
     /// Name of the id field of the contained records.
     pub id_field: String,
 }
+
+#[derive(Debug, Clone)]
+pub struct RecordAggregation {
+    /// Ordered list of response keys of the selected aggregations,
+    /// with the ordered response keys of the selected fields for field based aggregations.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+
+    /// Aggregation results, one per count and one per selected field, in selection order.
+    pub results: Vec<AggregationResult>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordGroups {
+    /// Names of the fields the records are grouped by, in the order of the group keys.
+    pub group_by: Vec<String>,
+
+    /// Ordered list of selections on each group.
+    pub selection_order: Vec<GroupBySelection>,
+
+    /// The groups, with their aggregation results in selection order.
+    pub groups: Vec<RecordGroup>,
+}
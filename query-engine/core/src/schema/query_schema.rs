@@ -86,6 +86,39 @@ impl QuerySchema {
         self.query().get_fields().into_iter().find(|f| f.name == name).cloned()
     }
 
+    /// Finds an input object type by name.
+    pub fn find_input_object_type(&self, name: &str) -> Option<InputObjectTypeStrongRef> {
+        self.input_object_types.iter().find(|t| t.name == name).cloned()
+    }
+
+    /// Finds an output object type by name.
+    pub fn find_output_object_type(&self, name: &str) -> Option<ObjectTypeStrongRef> {
+        self.output_object_types.iter().find(|t| t.name == name).cloned()
+    }
+
+    /// Finds an enum type that is used as input somewhere in the schema, either as
+    /// (part of) a field argument type or as (part of) an input object field type.
+    pub fn find_input_enum_type(&self, name: &str) -> Option<EnumTypeRef> {
+        let argument_types = self
+            .query()
+            .get_fields()
+            .iter()
+            .chain(self.mutation().get_fields().iter())
+            .flat_map(|field| field.arguments.iter().map(|arg| arg.argument_type.clone()))
+            .collect::<Vec<InputType>>();
+
+        let field_types = self
+            .input_object_types
+            .iter()
+            .flat_map(|t| t.get_fields().iter().map(|f| f.field_type.clone()))
+            .collect::<Vec<InputType>>();
+
+        argument_types
+            .iter()
+            .chain(field_types.iter())
+            .find_map(|t| t.find_enum(name))
+    }
+
     pub fn mutation(&self) -> ObjectTypeStrongRef {
         match self.mutation.borrow() {
             OutputType::Object(ref o) => o.into_arc(),
@@ -160,7 +193,7 @@ impl SchemaQueryBuilder {
     pub fn build(&self, parsed_field: ParsedField) -> QueryGraphBuilderResult<QueryGraph> {
         match self {
             Self::ModelQueryBuilder(m) => m.build(parsed_field),
-            _ => unimplemented!(),
+            Self::GenericQueryBuilder(g) => g.build(parsed_field),
         }
     }
 }
@@ -196,12 +229,14 @@ pub enum QueryTag {
     FindOne,
     FindMany,
     CreateOne,
+    CreateMany,
     UpdateOne,
     UpdateMany,
     DeleteOne,
     DeleteMany,
     UpsertOne,
     Aggregate,
+    GroupBy,
 }
 
 impl fmt::Display for QueryTag {
@@ -210,21 +245,37 @@ impl fmt::Display for QueryTag {
             QueryTag::FindOne => "findOne",
             QueryTag::FindMany => "findMany",
             QueryTag::CreateOne => "createOne",
+            QueryTag::CreateMany => "createMany",
             QueryTag::UpdateOne => "updateOne",
             QueryTag::UpdateMany => "updateMany",
             QueryTag::DeleteOne => "deleteOne",
             QueryTag::DeleteMany => "deleteMany",
             QueryTag::UpsertOne => "upsertOne",
             QueryTag::Aggregate => "aggregate",
+            QueryTag::GroupBy => "groupBy",
         };
 
         s.fmt(f)
     }
 }
 
-#[derive(Debug, Clone)]
+pub type GenericQueryBuilderFn = dyn (Fn(ParsedField) -> QueryGraphBuilderResult<QueryGraph>) + Send + Sync;
+
+/// Designates a top-level operation that is not associated to any particular model.
+#[derive(DebugStub)]
 pub struct GenericQueryBuilder {
-    // WIP
+    #[debug_stub = "#BuilderFn#"]
+    pub builder_fn: Box<GenericQueryBuilderFn>,
+}
+
+impl GenericQueryBuilder {
+    pub fn new(builder_fn: Box<GenericQueryBuilderFn>) -> Self {
+        Self { builder_fn }
+    }
+
+    pub fn build(&self, parsed_field: ParsedField) -> QueryGraphBuilderResult<QueryGraph> {
+        (self.builder_fn)(parsed_field)
+    }
 }
 
 #[derive(Debug)]
@@ -308,6 +359,10 @@ impl InputType {
         InputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> InputType {
         InputType::Scalar(ScalarType::Boolean)
     }
@@ -327,6 +382,18 @@ impl InputType {
     pub fn id() -> InputType {
         InputType::Scalar(ScalarType::ID)
     }
+
+    /// Recurses through list and optional wrappers and returns the enum type with the given name, if any.
+    /// Does not descend into input objects.
+    pub fn find_enum(&self, name: &str) -> Option<EnumTypeRef> {
+        match self {
+            InputType::Enum(e) if e.name == name => Some(Arc::clone(e)),
+            InputType::Scalar(ScalarType::Enum(e)) if e.name == name => Some(Arc::clone(e)),
+            InputType::List(inner) => inner.find_enum(name),
+            InputType::Opt(inner) => inner.find_enum(name),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -363,6 +430,10 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> OutputType {
         OutputType::Scalar(ScalarType::Boolean)
     }
@@ -417,6 +488,7 @@ pub enum ScalarType {
     String,
     Int,
     Float,
+    Decimal,
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
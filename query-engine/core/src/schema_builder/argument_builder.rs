@@ -39,6 +39,29 @@ impl<'a> ArgumentBuilder<'a> {
         }
     }
 
+    /// Builds "data" argument intended for the create many field, holding a list of create inputs without nested writes.
+    /// Models with required relations can't be created without nested writes, hence they don't get arguments.
+    pub fn create_many_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
+        let has_required_relations = model
+            .fields()
+            .relation()
+            .iter()
+            .any(|rf| rf.is_required && !rf.is_hidden);
+
+        if has_required_relations {
+            return None;
+        }
+
+        let input_object_type = self.input_type_builder.into_arc().create_many_input_type(model);
+
+        if input_object_type.into_arc().is_empty() {
+            None
+        } else {
+            let input_type = InputType::list(InputType::object(input_object_type));
+            Some(vec![argument("data", input_type, None)])
+        }
+    }
+
     /// Builds "where" (unique) argument intended for the delete field.
     pub fn delete_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
         self.where_unique_argument(model).map(|arg| vec![arg])
@@ -13,6 +13,7 @@ struct StaticFilterArguments {
     pub string: Vec<FilterArgument>,
     pub multi_relation: Vec<FilterArgument>,
     pub one_relation: Vec<FilterArgument>,
+    pub scalar_list: Vec<FilterArgument>,
 }
 
 lazy_static! {
@@ -50,6 +51,13 @@ lazy_static! {
         ],
 
         one_relation: vec![FilterArgument { suffix: "", is_list: false }],
+
+        scalar_list: vec![
+            FilterArgument { suffix: "_has", is_list: false }, // All lists containing the given value.
+            FilterArgument { suffix: "_has_every", is_list: true }, // All lists containing every one of the given values.
+            FilterArgument { suffix: "_has_some", is_list: true }, // All lists containing at least one of the given values.
+            FilterArgument { suffix: "_is_empty", is_list: false } // All lists that are (or are not) empty.
+        ],
     };
 }
 
@@ -59,7 +67,7 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
     if field.is_list() {
         match field.type_identifier() {
             TypeIdentifier::Relation => args.multi_relation.iter().collect(),
-            _ => vec![],
+            _ => args.scalar_list.iter().collect(),
         }
     } else {
         let filters = match field.type_identifier() {
@@ -68,6 +76,7 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::Decimal => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
@@ -82,3 +91,14 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             .collect()
     }
 }
+
+/// Filter arguments on aggregated values, which are always numbers or dates.
+pub fn get_aggregation_filters<'a>() -> Vec<&'a FilterArgument> {
+    let args = &FILTER_ARGUMENTS;
+
+    vec![&args.base, &args.inclusion, &args.alphanumeric]
+        .into_iter()
+        .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
+        .flatten()
+        .collect()
+}
@@ -120,11 +120,127 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         weak_ref
     }
 
+    /// Builds the filter object on the aggregations of grouped records (e.g. "UserGroupByHavingInput").
+    /// Counts are filtered directly, field based aggregations carry filters for each aggregated field.
+    pub fn having_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}GroupByHavingInput", model.name);
+        return_cached!(self.input_object_cache, &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let weak_ref = Arc::downgrade(&input_object);
+        let mut fields = vec![
+            input_field(
+                "AND",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "OR",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "NOT",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+        ];
+
+        fields.extend(Self::map_aggregation_filter_fields("count", TypeIdentifier::Int));
+
+        append_opt(
+            &mut fields,
+            self.aggregation_filter_field("sum", &model, AggregationSelection::Sum(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_filter_field("avg", &model, AggregationSelection::Average(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_filter_field("min", &model, AggregationSelection::Min(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_filter_field("max", &model, AggregationSelection::Max(vec![])),
+        );
+
+        input_object.set_fields(fields);
+        weak_ref
+    }
+
+    /// Builds the filter field for a field based aggregation (e.g. "sum"), filtering on the aggregated
+    /// values of all fields supporting the aggregation. Returns None if the model has no such fields.
+    fn aggregation_filter_field(
+        &self,
+        name: &str,
+        model: &ModelRef,
+        selection: AggregationSelection,
+    ) -> Option<InputField> {
+        let scalar_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden && selection.supports_field(f))
+            .collect();
+
+        if scalar_fields.is_empty() {
+            return None;
+        }
+
+        let type_name = format!("{}{}HavingInput", capitalize(&model.name), capitalize(name));
+        let input_object = match self.get_cache().get(&type_name) {
+            Some(existing) => existing,
+            None => {
+                let fields = scalar_fields
+                    .iter()
+                    .flat_map(|sf| Self::map_aggregation_filter_fields(&sf.name, selection.result_type(sf)))
+                    .collect();
+
+                let input_object = Arc::new(input_object_type(type_name.clone(), fields));
+                self.cache(type_name, Arc::clone(&input_object));
+
+                Arc::downgrade(&input_object)
+            }
+        };
+
+        Some(input_field(name, InputType::opt(InputType::object(input_object)), None))
+    }
+
+    fn map_aggregation_filter_fields(name: &str, type_identifier: TypeIdentifier) -> Vec<InputField> {
+        let mapped = match type_identifier {
+            TypeIdentifier::Int => InputType::int(),
+            TypeIdentifier::Float => InputType::float(),
+            TypeIdentifier::Decimal => InputType::decimal(),
+            TypeIdentifier::DateTime => InputType::date_time(),
+            _ => unreachable!("Aggregations are only supported on numeric and date fields."),
+        };
+
+        get_aggregation_filters()
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", name, arg.suffix);
+
+                if arg.is_list {
+                    input_field(field_name, InputType::opt(InputType::list(mapped.clone())), None)
+                } else {
+                    input_field(field_name, InputType::opt(mapped.clone()), None)
+                }
+            })
+            .collect()
+    }
+
     fn build_mongo_filter_object(&self, _model: ModelRef) -> InputObjectTypeRef {
         unimplemented!()
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        if field.is_list {
+            return self.map_scalar_list_filter_input_field(field);
+        }
+
         get_field_filters(&ModelField::Scalar(Arc::clone(&field))) // wip: take a look at required signatures
             .into_iter()
             .map(|arg| {
@@ -140,6 +256,30 @@ impl<'a> FilterObjectTypeBuilder<'a> {
             .collect()
     }
 
+    /// Maps scalar lists to (filter) input fields. The filters operate on the list elements,
+    /// except for `_is_empty`, which takes a boolean.
+    fn map_scalar_list_filter_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        let element_type = match self.map_required_input_type(Arc::clone(&field)) {
+            InputType::List(inner) => *inner,
+            typ => typ,
+        };
+
+        get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", field.name, arg.suffix);
+
+                if arg.suffix == "_is_empty" {
+                    input_field(field_name, InputType::opt(InputType::boolean()), None)
+                } else if arg.is_list {
+                    input_field(field_name, InputType::opt(InputType::list(element_type.clone())), None)
+                } else {
+                    input_field(field_name, InputType::opt(element_type.clone()), None)
+                }
+            })
+            .collect()
+    }
+
     /// Maps relations to (filter) input fields.
     fn map_relation_filter_input_field(&self, field: RelationFieldRef) -> Vec<InputField> {
         let related_model = field.related_model();
@@ -28,29 +28,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             model.name.clone(),
             "Create",
             scalar_fields,
-            |f: ScalarFieldRef| {
-                let required_and_none = f.is_required && f.default_value.is_none();
-
-                if required_and_none && f.is_id() {
-                    match (f.behaviour.as_ref(), f.type_identifier) {
-                        (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::UUID)      => self.map_optional_input_type(f),
-                        (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::GraphQLID) => self.map_optional_input_type(f),
-                        (None, TypeIdentifier::UUID)                                                             => self.map_optional_input_type(f),
-                        (None, TypeIdentifier::GraphQLID)                                                        => self.map_optional_input_type(f),
-
-                        (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::GraphQLID) => self.map_required_input_type(f),
-                        (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::UUID)      => self.map_required_input_type(f),
-
-                        _ => unreachable!(),
-                    }
-                } else if required_and_none && (f.is_created_at() || f.is_updated_at()) {
-                    self.map_optional_input_type(f)
-                } else if required_and_none {
-                    self.map_required_input_type(f)
-                } else {
-                    self.map_optional_input_type(f)
-                }
-            },
+            |f: ScalarFieldRef| self.map_create_input_field_type(f),
             true
         );
 
@@ -62,6 +40,60 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
         Arc::downgrade(&input_object)
     }
 
+    /// Builds "<x>CreateManyInput" input object type. Only holds scalar non-list fields, as
+    /// creating many records at once does not support nested writes.
+    fn create_many_input_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}CreateManyInput", model.name);
+        return_cached!(self.get_cache(), &name);
+
+        let scalar_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden && Self::do_filter(&f))
+            .collect();
+
+        let fields = self.scalar_input_fields(
+            model.name.clone(),
+            "CreateMany",
+            scalar_fields,
+            |f: ScalarFieldRef| self.map_create_input_field_type(f),
+            true,
+        );
+
+        let input_object = Arc::new(input_object_type(name.clone(), fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
+    /// Maps a scalar field to its input type for create input types. Fields that are generated
+    /// or defaulted by the database are optional.
+    #[rustfmt::skip]
+    fn map_create_input_field_type(&self, f: ScalarFieldRef) -> InputType {
+        let required_and_none = f.is_required && f.default_value.is_none();
+
+        if required_and_none && f.is_id() {
+            match (f.behaviour.as_ref(), f.type_identifier) {
+                (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::UUID)      => self.map_optional_input_type(f),
+                (Some(FieldBehaviour::Id { strategy: IdStrategy::Auto, .. }), TypeIdentifier::GraphQLID) => self.map_optional_input_type(f),
+                (None, TypeIdentifier::UUID)                                                             => self.map_optional_input_type(f),
+                (None, TypeIdentifier::GraphQLID)                                                        => self.map_optional_input_type(f),
+
+                (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::GraphQLID) => self.map_required_input_type(f),
+                (Some(FieldBehaviour::Id { strategy: IdStrategy::None, .. }), TypeIdentifier::UUID)      => self.map_required_input_type(f),
+
+                _ => unreachable!(),
+            }
+        } else if required_and_none && (f.is_created_at() || f.is_updated_at()) {
+            self.map_optional_input_type(f)
+        } else if required_and_none {
+            self.map_required_input_type(f)
+        } else {
+            self.map_optional_input_type(f)
+        }
+    }
+
     /// For create input types only. Compute input fields for relational fields.
     /// This recurses into create_input_type (via nested_create_input_field).
     fn relation_input_fields_create(
@@ -18,9 +18,10 @@ use utils::*;
 
 /// Common module imports shared accross submodules.
 use crate::schema::*;
+use connector::AggregationSelection;
 use prisma_models::{
     EnumType, EnumValue, Field as ModelField, FieldBehaviour, IdStrategy, InternalDataModelRef, ModelRef,
-    RelationFieldRef, ScalarField, ScalarFieldRef, SortOrder, TypeIdentifier,
+    NullsOrder, OrderBy, OrderByTarget, RelationFieldRef, ScalarField, ScalarFieldRef, SortOrder, TypeIdentifier,
 };
 use std::sync::{Arc, Weak};
 
@@ -106,6 +106,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             ModelField::Scalar(sf) => match sf.type_identifier {
                 TypeIdentifier::String => OutputType::string(),
                 TypeIdentifier::Float => OutputType::float(),
+                TypeIdentifier::Decimal => OutputType::decimal(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum => Self::map_enum_field(sf).into(),
                 TypeIdentifier::Json => OutputType::json(),
@@ -155,6 +156,39 @@ impl<'a> ObjectTypeBuilder<'a> {
         ]
     }
 
+    /// Builds the arguments of grouped queries. Groups can only be paginated by skipping and limiting.
+    pub fn group_by_arguments(&self, model: &ModelRef) -> Vec<Argument> {
+        let having_object = self
+            .filter_object_type_builder
+            .into_arc()
+            .having_object_type(Arc::clone(model));
+
+        vec![
+            self.by_argument(&model),
+            self.where_argument(&model),
+            self.order_by_argument(&model),
+            argument("having", InputType::opt(InputType::object(having_object)), None),
+            argument("skip", InputType::opt(InputType::int()), None),
+            argument("first", InputType::opt(InputType::int()), None),
+        ]
+    }
+
+    /// Builds the "by" argument, listing the scalar fields to group by.
+    fn by_argument(&self, model: &ModelRef) -> Argument {
+        let enum_values: Vec<EnumValue> = model
+            .fields()
+            .scalar_non_list()
+            .iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| EnumValue::string(f.name.clone(), f.name.clone()))
+            .collect();
+
+        let enum_name = format!("{}ScalarFieldEnum", model.name);
+        let enum_type = enum_type(enum_name, enum_values);
+
+        argument("by", InputType::list(enum_type.into()), None)
+    }
+
     /// Builds "where" argument.
     pub fn where_argument(&self, model: &ModelRef) -> Argument {
         let where_object = self
@@ -166,32 +200,66 @@ impl<'a> ObjectTypeBuilder<'a> {
     }
 
     // Builds "orderBy" argument.
+    // Records can be ordered by a list of entries, a single value is coerced into a list.
+    // Besides own fields, records can be ordered by fields of to-one related records and by the number of
+    // to-many related records. Null ordering can only be specified for nullable values.
     pub fn order_by_argument(&self, model: &ModelRef) -> Argument {
-        let enum_values: Vec<EnumValue> = model
+        let mut targets: Vec<(Vec<RelationFieldRef>, OrderByTarget)> = model
             .fields()
             .scalar_non_list()
-            .iter()
-            .map(|f| {
-                vec![
-                    EnumValue::order_by(
-                        format!("{}_{}", f.name, SortOrder::Ascending.abbreviated()),
-                        Arc::clone(f),
-                        SortOrder::Ascending,
-                    ),
-                    EnumValue::order_by(
-                        format!("{}_{}", f.name, SortOrder::Descending.abbreviated()),
-                        Arc::clone(f),
-                        SortOrder::Descending,
-                    ),
-                ]
-            })
-            .flatten()
+            .into_iter()
+            .map(|f| (vec![], OrderByTarget::Field(f)))
             .collect();
 
+        for rf in model.fields().relation().into_iter().filter(|rf| !rf.is_hidden) {
+            if rf.is_list {
+                targets.push((vec![], OrderByTarget::RelationCount(rf)));
+            } else {
+                let related_fields = rf.related_model().fields().scalar_non_list();
+
+                targets.extend(
+                    related_fields
+                        .into_iter()
+                        .map(|f| (vec![Arc::clone(&rf)], OrderByTarget::Field(f))),
+                );
+            }
+        }
+
+        let mut enum_values: Vec<EnumValue> = vec![];
+
+        for (path, target) in targets {
+            for sort_order in &[SortOrder::Ascending, SortOrder::Descending] {
+                let order_by = OrderBy {
+                    path: path.clone(),
+                    target: target.clone(),
+                    sort_order: *sort_order,
+                    nulls_order: None,
+                };
+
+                let nulls_orders = if order_by.is_nullable() {
+                    vec![None, Some(NullsOrder::First), Some(NullsOrder::Last)]
+                } else {
+                    vec![None]
+                };
+
+                for nulls_order in nulls_orders {
+                    let value = EnumValue::order_by(OrderBy {
+                        nulls_order,
+                        ..order_by.clone()
+                    });
+
+                    // Names of related values may clash with own fields, which take precedence.
+                    if enum_values.iter().all(|existing| existing.name != value.name) {
+                        enum_values.push(value);
+                    }
+                }
+            }
+        }
+
         let enum_name = format!("{}OrderByInput", model.name);
         let enum_type = enum_type(enum_name, enum_values);
 
-        argument("orderBy", InputType::opt(enum_type.into()), None)
+        argument("orderBy", InputType::opt(InputType::list(enum_type.into())), None)
     }
 
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
@@ -226,11 +294,118 @@ impl<'a> ObjectTypeBuilder<'a> {
         return_cached!(self.get_cache(), &name);
 
         let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
-        let fields = vec![field("count", vec![], OutputType::int(), None)];
+        let mut fields = vec![field("count", vec![], OutputType::int(), None)];
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("sum", model, AggregationSelection::Sum(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_field("avg", model, AggregationSelection::Average(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_field("min", model, AggregationSelection::Min(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_field("max", model, AggregationSelection::Max(vec![])),
+        );
 
         object.set_fields(fields);
         self.cache(name, ObjectTypeStrongRef::clone(&object));
 
         ObjectTypeStrongRef::downgrade(&object)
     }
+
+    /// Builds the object type of a single group of a grouped query for given model (e.g. UserGroupByOutputType).
+    /// Contains all scalar fields that can be grouped by, as well as the aggregations of the group.
+    pub fn group_by_output_object_type(&self, model: &ModelRef) -> ObjectTypeRef {
+        let name = format!("{}GroupByOutputType", capitalize(&model.name));
+        return_cached!(self.get_cache(), &name);
+
+        let object = ObjectTypeStrongRef::new(init_object_type(&name, Some(ModelRef::clone(model))));
+        let mut fields: Vec<Field> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| !f.is_hidden)
+            .map(|f| {
+                let model_field = ModelField::Scalar(Arc::clone(&f));
+                field(f.name.clone(), vec![], self.map_output_type(&model_field), None)
+            })
+            .collect();
+
+        fields.push(field("count", vec![], OutputType::int(), None));
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("sum", model, AggregationSelection::Sum(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_field("avg", model, AggregationSelection::Average(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_field("min", model, AggregationSelection::Min(vec![])),
+        );
+        append_opt(
+            &mut fields,
+            self.aggregation_field("max", model, AggregationSelection::Max(vec![])),
+        );
+
+        object.set_fields(fields);
+        self.cache(name, ObjectTypeStrongRef::clone(&object));
+
+        ObjectTypeStrongRef::downgrade(&object)
+    }
+
+    /// Builds a field of the aggregation object type (e.g. "sum"), which selects
+    /// all scalar fields of the model that support the given aggregation (e.g. UserSumAggregateOutputType).
+    /// Returns None if the model has no such fields.
+    fn aggregation_field(&self, name: &str, model: &ModelRef, selection: AggregationSelection) -> Option<Field> {
+        let scalar_fields: Vec<ScalarFieldRef> = model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|f| selection.supports_field(f))
+            .collect();
+
+        if scalar_fields.is_empty() {
+            return None;
+        }
+
+        let type_name = format!("{}{}AggregateOutputType", capitalize(&model.name), capitalize(name));
+        let object = match self.get_cache().get(&type_name) {
+            Some(existing) => existing,
+            None => {
+                let fields = scalar_fields
+                    .iter()
+                    .map(|sf| {
+                        let output_type = Self::map_aggregation_output_type(selection.result_type(sf));
+                        field(sf.name.clone(), vec![], OutputType::opt(output_type), None)
+                    })
+                    .collect();
+
+                let object = Arc::new(object_type(type_name.clone(), fields, None));
+                self.cache(type_name, Arc::clone(&object));
+
+                Arc::downgrade(&object)
+            }
+        };
+
+        Some(field(name, vec![], OutputType::opt(OutputType::object(object)), None))
+    }
+
+    fn map_aggregation_output_type(type_identifier: TypeIdentifier) -> OutputType {
+        match type_identifier {
+            TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Decimal => OutputType::decimal(),
+            TypeIdentifier::DateTime => OutputType::date_time(),
+            _ => unreachable!("Aggregations are only supported on numeric and date fields."),
+        }
+    }
 }
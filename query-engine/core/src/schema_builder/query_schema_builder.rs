@@ -1,5 +1,5 @@
 use super::*;
-use crate::{query_graph_builder::*, Query, QueryGraph};
+use crate::{query_graph_builder::*, Query, QueryGraph, ResetData, WriteQuery};
 
 /// Build mode for schema generation.
 #[derive(Debug, Copy, Clone)]
@@ -45,8 +45,9 @@ pub enum BuildMode {
 /// object types, which are then moved to the query schema to keep weak references alive (see TypeRefCache for additional infos).
 pub struct QuerySchemaBuilder<'a> {
     mode: BuildMode,
+    enable_reset_data: bool,
     internal_data_model: InternalDataModelRef,
-    _capabilities: &'a SupportedCapabilities,
+    capabilities: &'a SupportedCapabilities,
     object_type_builder: Arc<ObjectTypeBuilder<'a>>,
     input_type_builder: Arc<InputTypeBuilder<'a>>,
     argument_builder: ArgumentBuilder<'a>,
@@ -58,6 +59,7 @@ impl<'a> QuerySchemaBuilder<'a> {
         internal_data_model: &InternalDataModelRef,
         capabilities: &'a SupportedCapabilities,
         mode: BuildMode,
+        enable_reset_data: bool,
     ) -> Self {
         let filter_object_type_builder = Arc::new(FilterObjectTypeBuilder::new(capabilities));
         let input_type_builder = Arc::new(InputTypeBuilder::new(
@@ -79,8 +81,9 @@ impl<'a> QuerySchemaBuilder<'a> {
 
         QuerySchemaBuilder {
             mode,
+            enable_reset_data,
             internal_data_model: Arc::clone(internal_data_model),
-            _capabilities: capabilities,
+            capabilities,
             object_type_builder,
             input_type_builder,
             argument_builder,
@@ -129,12 +132,13 @@ impl<'a> QuerySchemaBuilder<'a> {
     /// Builds the root query type.
     fn build_query_type(&self) -> (OutputType, ObjectTypeStrongRef) {
         let non_embedded_models = self.non_embedded_models();
-        let fields = non_embedded_models
+        let mut fields: Vec<Field> = non_embedded_models
             .into_iter()
             .map(|m| {
                 let mut vec = vec![
                     self.all_items_field(Arc::clone(&m)),
                     self.aggregation_field(Arc::clone(&m)),
+                    self.group_by_field(Arc::clone(&m)),
                 ];
 
                 append_opt(&mut vec, self.single_item_field(Arc::clone(&m)));
@@ -143,6 +147,10 @@ impl<'a> QuerySchemaBuilder<'a> {
             .flatten()
             .collect();
 
+        if self.capabilities.has(ConnectorCapability::RawAccess) {
+            fields.push(self.query_raw_field());
+        }
+
         let strong_ref = Arc::new(object_type("Query", fields, None));
 
         (OutputType::Object(Arc::downgrade(&strong_ref)), strong_ref)
@@ -151,11 +159,13 @@ impl<'a> QuerySchemaBuilder<'a> {
     /// Builds the root mutation type.
     fn build_mutation_type(&self) -> (OutputType, ObjectTypeStrongRef) {
         let non_embedded_models = self.non_embedded_models();
-        let fields = non_embedded_models
+        let mut fields: Vec<Field> = non_embedded_models
             .into_iter()
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
+                append_opt(&mut vec, self.create_many_field(Arc::clone(&model)));
+
                 append_opt(&mut vec, self.delete_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.update_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.upsert_item_field(Arc::clone(&model)));
@@ -168,11 +178,74 @@ impl<'a> QuerySchemaBuilder<'a> {
             .flatten()
             .collect();
 
+        if self.capabilities.has(ConnectorCapability::RawAccess) {
+            fields.push(self.execute_raw_field());
+        }
+
+        if self.enable_reset_data {
+            fields.push(self.reset_data_field());
+        }
+
         let strong_ref = Arc::new(object_type("Mutation", fields, None));
 
         (OutputType::Object(Arc::downgrade(&strong_ref)), strong_ref)
     }
 
+    /// Builds the "queryRaw" query field, which runs a raw query and returns the result rows as JSON.
+    fn query_raw_field(&self) -> Field {
+        field(
+            "queryRaw",
+            self.raw_arguments(),
+            OutputType::json(),
+            Some(SchemaQueryBuilder::GenericQueryBuilder(GenericQueryBuilder::new(
+                Box::new(raw::query_raw),
+            ))),
+        )
+    }
+
+    /// Builds the "executeRaw" mutation field, which executes a raw statement and returns the number of affected rows.
+    fn execute_raw_field(&self) -> Field {
+        field(
+            "executeRaw",
+            self.raw_arguments(),
+            OutputType::json(),
+            Some(SchemaQueryBuilder::GenericQueryBuilder(GenericQueryBuilder::new(
+                Box::new(raw::execute_raw),
+            ))),
+        )
+    }
+
+    /// Arguments of the raw fields: the SQL string and its positional parameters as a JSON array.
+    fn raw_arguments(&self) -> Vec<Argument> {
+        vec![
+            argument("query", InputType::string(), None),
+            argument("parameters", InputType::opt(InputType::json()), None),
+        ]
+    }
+
+    /// Builds the "resetData" mutation field, which removes all data from the database.
+    /// Only available if explicitly enabled, as it is meant for resetting test databases.
+    fn reset_data_field(&self) -> Field {
+        let internal_data_model = Arc::clone(&self.internal_data_model);
+
+        field(
+            "resetData",
+            vec![],
+            OutputType::opt(OutputType::boolean()),
+            Some(SchemaQueryBuilder::GenericQueryBuilder(GenericQueryBuilder::new(
+                Box::new(move |_| {
+                    let mut graph = QueryGraph::new();
+                    let reset = WriteQuery::ResetData(ResetData {
+                        internal_data_model: Arc::clone(&internal_data_model),
+                    });
+
+                    graph.create_node(Query::Write(reset));
+                    Ok(graph)
+                }),
+            ))),
+        )
+    }
+
     /// Helper function to get all non-embedded models from the internal data model.
     fn non_embedded_models(&self) -> Vec<ModelRef> {
         self.internal_data_model
@@ -244,6 +317,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds an "aggregate" query field (e.g. "aggregateUser") for given model.
     fn aggregation_field(&self, model: ModelRef) -> Field {
+        let args = self.object_type_builder.many_records_arguments(&model);
         let field_name = self.pluralize_internal(
             format!("aggregate{}", model.name.clone()), // Has no legacy counterpart.
             format!("aggregate{}", model.name.clone()),
@@ -251,7 +325,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
         field(
             field_name,
-            vec![],
+            args,
             OutputType::object(self.object_type_builder.aggregation_object_type(&model)),
             Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
                 Arc::clone(&model),
@@ -267,6 +341,34 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a "groupBy" query field (e.g. "groupByUser") for given model.
+    fn group_by_field(&self, model: ModelRef) -> Field {
+        let args = self.object_type_builder.group_by_arguments(&model);
+        let field_name = self.pluralize_internal(
+            format!("groupBy{}", model.name.clone()), // Has no legacy counterpart.
+            format!("groupBy{}", model.name.clone()),
+        );
+
+        field(
+            field_name,
+            args,
+            OutputType::list(OutputType::object(
+                self.object_type_builder.group_by_output_object_type(&model),
+            )),
+            Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+                Arc::clone(&model),
+                QueryTag::GroupBy,
+                Box::new(|model, parsed_field| {
+                    let mut graph = QueryGraph::new();
+                    let query = GroupByRecordsBuilder::new(parsed_field, model).build()?;
+
+                    graph.create_node(Query::Read(query));
+                    Ok(graph)
+                }),
+            ))),
+        )
+    }
+
     /// Builds a create mutation field (e.g. createUser) for given model.
     fn create_item_field(&self, model: ModelRef) -> Field {
         let args = self
@@ -296,6 +398,34 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a create many mutation field (e.g. createManyUsers) for given model.
+    fn create_many_field(&self, model: ModelRef) -> Option<Field> {
+        self.argument_builder
+            .create_many_arguments(Arc::clone(&model))
+            .map(|args| {
+                let field_name = self.pluralize_internal(
+                    format!("createMany{}", pluralize(model.name.clone())),
+                    format!("createMany{}", model.name.clone()),
+                );
+
+                field(
+                    field_name,
+                    args,
+                    OutputType::object(self.object_type_builder.batch_payload_object_type()),
+                    Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+                        Arc::clone(&model),
+                        QueryTag::CreateMany,
+                        Box::new(|model, parsed_field| {
+                            let mut graph = QueryGraph::new();
+
+                            write::create_many_records(&mut graph, model, parsed_field)?;
+                            Ok(graph)
+                        }),
+                    ))),
+                )
+            })
+    }
+
     /// Builds a delete mutation field (e.g. deleteUser) for given model.
     fn delete_item_field(&self, model: ModelRef) -> Option<Field> {
         self.argument_builder.delete_arguments(Arc::clone(&model)).map(|args| {
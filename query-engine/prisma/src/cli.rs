@@ -1,6 +1,6 @@
 use crate::{
     data_model_loader::{load_configuration, load_data_model_components},
-    dmmf, PrismaResult,
+    dmmf, exec_loader, PrismaResult,
 };
 use clap::ArgMatches;
 use datamodel::json::dmmf::Datamodel;
@@ -65,9 +65,13 @@ impl CliCommand {
 
         // temporary code duplication
         let internal_data_model = template.build("".into());
-        let capabilities = SupportedCapabilities::empty();
+        let capabilities = v2components
+            .data_sources
+            .first()
+            .map(|source| exec_loader::capabilities(&**source))
+            .unwrap_or_else(SupportedCapabilities::empty);
 
-        let schema_builder = QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode);
+        let schema_builder = QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode, false);
         let query_schema: QuerySchemaRef = Arc::new(schema_builder.build());
 
         let dmmf = dmmf::render_dmmf(&v2components.datamodel, query_schema);
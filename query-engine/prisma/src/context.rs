@@ -1,10 +1,24 @@
 use crate::{data_model_loader::*, exec_loader, PrismaError, PrismaResult};
+use connector::IsolationLevel;
 use query_core::{
-    schema::{QuerySchemaRef, SupportedCapabilities},
-    BuildMode, QueryExecutor, QuerySchemaBuilder,
+    response_ir::Response, schema::QuerySchemaRef, BuildMode, InteractiveTransaction, QueryDocument, QueryExecutor,
+    QuerySchemaBuilder,
 };
 // use prisma_models::InternalDataModelRef;
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Maximum number of interactive transactions that can be open at the same time.
+const MAX_OPEN_TRANSACTIONS: usize = 100;
+
+/// Timeout of interactive transactions if none is requested.
+const DEFAULT_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Upper bound of requested interactive transaction timeouts.
+const MAX_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -19,6 +33,9 @@ pub struct PrismaContext {
 
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+
+    /// Open interactive transactions by id.
+    transactions: Mutex<HashMap<String, Arc<InteractiveTransaction>>>,
 }
 
 impl PrismaContext {
@@ -27,7 +44,8 @@ impl PrismaContext {
     /// 1. The data model. This has different options on how to initialize. See data_model_loader module. The Prisma configuration (prisma.yml) is used as fallback.
     /// 2. The data model is converted to the internal data model.
     /// 3. The api query schema is constructed from the internal data model.
-    pub fn new(legacy: bool) -> PrismaResult<Self> {
+    ///    The resetData mutation is only part of the schema if explicitly enabled.
+    pub fn new(legacy: bool, enable_reset_data: bool) -> PrismaResult<Self> {
         // Load data model in order of precedence.
         let (v2components, template) = load_data_model_components()?;
 
@@ -48,8 +66,9 @@ impl PrismaContext {
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
-        let capabilities = SupportedCapabilities::empty(); // todo connector capabilities.
-        let schema_builder = QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode);
+        let capabilities = exec_loader::capabilities(&**data_source);
+        let schema_builder =
+            QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode, enable_reset_data);
         let query_schema: QuerySchemaRef = Arc::new(schema_builder.build());
 
         Ok(Self {
@@ -57,6 +76,7 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            transactions: Mutex::new(HashMap::new()),
         })
     }
 
@@ -71,4 +91,83 @@ impl PrismaContext {
     pub fn primary_connector(&self) -> &'static str {
         self.executor.primary_connector()
     }
+
+    /// Starts an interactive transaction, returning its id and the effective timeout.
+    /// Transactions that timed out are cleaned up first, to free their slots.
+    pub async fn start_transaction(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        timeout: Option<Duration>,
+    ) -> PrismaResult<(String, Duration)> {
+        let timeout = timeout
+            .map(|timeout| std::cmp::min(timeout, MAX_TRANSACTION_TIMEOUT))
+            .unwrap_or(DEFAULT_TRANSACTION_TIMEOUT);
+
+        {
+            let mut transactions = self.transactions.lock().unwrap();
+            transactions.retain(|_, tx| tx.is_open());
+
+            if transactions.len() >= MAX_OPEN_TRANSACTIONS {
+                return Err(PrismaError::TransactionError(format!(
+                    "Too many open transactions (maximum is {}).",
+                    MAX_OPEN_TRANSACTIONS
+                )));
+            }
+        }
+
+        let tx = self.executor.start_transaction(isolation_level, timeout).await?;
+        let id = tx.id().to_owned();
+
+        let mut transactions = self.transactions.lock().unwrap();
+
+        // Concurrent starts may have filled the remaining slots in the meantime.
+        if transactions.len() >= MAX_OPEN_TRANSACTIONS {
+            drop(transactions);
+            tx.rollback().await?;
+
+            return Err(PrismaError::TransactionError(format!(
+                "Too many open transactions (maximum is {}).",
+                MAX_OPEN_TRANSACTIONS
+            )));
+        }
+
+        transactions.insert(id.clone(), Arc::new(tx));
+        Ok((id, timeout))
+    }
+
+    /// Executes the query document in the open interactive transaction with the given id.
+    pub async fn execute_in_transaction(&self, id: &str, query_doc: QueryDocument) -> PrismaResult<Vec<Response>> {
+        let tx = self.find_transaction(id)?;
+
+        Ok(tx.execute(query_doc, Arc::clone(&self.query_schema)).await?)
+    }
+
+    pub async fn commit_transaction(&self, id: &str) -> PrismaResult<()> {
+        let tx = self.remove_transaction(id)?;
+
+        Ok(tx.commit().await?)
+    }
+
+    pub async fn rollback_transaction(&self, id: &str) -> PrismaResult<()> {
+        let tx = self.remove_transaction(id)?;
+
+        Ok(tx.rollback().await?)
+    }
+
+    fn find_transaction(&self, id: &str) -> PrismaResult<Arc<InteractiveTransaction>> {
+        self.transactions
+            .lock()
+            .unwrap()
+            .get(id)
+            .cloned()
+            .ok_or_else(|| PrismaError::TransactionError(format!("Transaction '{}' not found.", id)))
+    }
+
+    fn remove_transaction(&self, id: &str) -> PrismaResult<Arc<InteractiveTransaction>> {
+        self.transactions
+            .lock()
+            .unwrap()
+            .remove(id)
+            .ok_or_else(|| PrismaError::TransactionError(format!("Transaction '{}' not found.", id)))
+    }
 }
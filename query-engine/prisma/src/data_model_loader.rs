@@ -92,16 +92,24 @@ pub fn load_data_model_components() -> PrismaResult<(DatamodelV2Components, Inte
 fn load_datamodel_v2() -> PrismaResult<Option<DatamodelV2Components>> {
     debug!("Trying to load v2 data model...");
 
-    load_v2_dml_string().inner_map(|dml_string| match datamodel::parse_datamodel(&dml_string) {
-        Err(errors) => Err(PrismaError::ConversionError(errors, dml_string.clone())),
-        Ok(dm) => load_configuration(&dml_string).map(|configuration| {
+    load_v2_dml_string().inner_map(|dml_string| load_datamodel_v2_components(&dml_string).map(Some))
+}
+
+/// Loads the configuration of a v2 data model string first, so that the data model is validated
+/// with the datasources the engine connects to, and their connector resolves the native types.
+fn load_datamodel_v2_components(dml_string: &str) -> PrismaResult<DatamodelV2Components> {
+    let configuration = load_configuration(dml_string)?;
+
+    match datamodel::parse_datamodel_with_datasources(dml_string, &configuration.datasources) {
+        Err(errors) => Err(PrismaError::ConversionError(errors, dml_string.to_string())),
+        Ok(dm) => {
             debug!("Loaded Prisma v2 data model.");
-            Some(DatamodelV2Components {
+            Ok(DatamodelV2Components {
                 datamodel: dm,
                 data_sources: configuration.datasources,
             })
-        }),
-    })
+        }
+    }
 }
 
 pub fn load_configuration(dml_string: &str) -> PrismaResult<datamodel::Configuration> {
@@ -199,3 +207,6 @@ fn load_from_file(env_var: &str) -> PrismaResult<Option<String>> {
         None => Ok(None),
     }
 }
+
+#[cfg(test)]
+mod tests;
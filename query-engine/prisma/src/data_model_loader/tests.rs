@@ -0,0 +1,43 @@
+use super::*;
+use datamodel::FieldType;
+use prisma_models::TypeIdentifier;
+
+const DATAMODEL: &str = r#"
+    datasource db {
+        provider = "sqlite"
+        url      = "file:dev.db"
+    }
+
+    model Blog {
+        id    Int    @id
+        title String @db.Text
+    }
+"#;
+
+#[test]
+fn native_types_must_be_resolved_with_the_connector_of_the_datasource() {
+    let components = load_datamodel_v2_components(DATAMODEL).unwrap();
+    let field = components
+        .datamodel
+        .find_model("Blog")
+        .and_then(|model| model.find_field("title"))
+        .unwrap();
+
+    match &field.field_type {
+        FieldType::ConnectorSpecific(native_type) => assert_eq!(native_type.datasource_type(), "TEXT"),
+        field_type => panic!("Expected a native type, got {:?}", field_type),
+    }
+
+    assert_eq!(components.data_sources.len(), 1);
+
+    let template = DatamodelConverter::convert(&components.datamodel);
+    let internal_data_model = template.build("db".to_owned());
+    let title = internal_data_model
+        .find_model("Blog")
+        .unwrap()
+        .fields()
+        .find_from_scalar("title")
+        .unwrap();
+
+    assert_eq!(title.type_identifier, TypeIdentifier::String);
+}
@@ -69,6 +69,7 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
@@ -142,6 +143,7 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::ID => "ID",
@@ -1,10 +1,13 @@
 use crate::{PrismaError, PrismaResult};
 use connector::Connector;
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
 use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Source,
 };
+use query_core::{
+    executor::{InterpretingExecutor, QueryExecutor},
+    schema::{ConnectorCapability, IdCapability, ScalarListsCapability, SupportedCapabilities},
+};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
@@ -29,6 +32,16 @@ pub fn load(source: &dyn Source) -> PrismaResult<(String, Box<dyn QueryExecutor
     }
 }
 
+/// Capabilities of the connector for the given source, which determine the features of the query schema.
+pub fn capabilities(source: &dyn Source) -> SupportedCapabilities {
+    match source.connector_type() {
+        #[cfg(feature = "sql")]
+        SQLITE_SOURCE_NAME | MYSQL_SOURCE_NAME | POSTGRES_SOURCE_NAME => sql_capabilities(),
+
+        _ => SupportedCapabilities::empty(),
+    }
+}
+
 #[cfg(feature = "sql")]
 fn sqlite(source: &dyn Source) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQLite connector...");
@@ -77,6 +90,27 @@ fn mysql(source: &dyn Source) -> PrismaResult<(String, Box<dyn QueryExecutor + S
     Ok((db_name, sql_executor("mysql", mysql)))
 }
 
+/// The SQL connectors share their feature set, they only differ in the dialect they speak.
+#[cfg(feature = "sql")]
+fn sql_capabilities() -> SupportedCapabilities {
+    SupportedCapabilities {
+        capabilities: vec![
+            ConnectorCapability::ScalarLists(ScalarListsCapability::NonEmbeddedScalarList),
+            ConnectorCapability::IdCapability(IdCapability::IntId),
+            ConnectorCapability::IdCapability(IdCapability::UuidId),
+            ConnectorCapability::IdCapability(IdCapability::IdSequence),
+            ConnectorCapability::JoinRelationsFilter,
+            ConnectorCapability::TransactionalExecution,
+            ConnectorCapability::SupportsExistingDatabases,
+            ConnectorCapability::Migrations,
+            ConnectorCapability::RawAccess,
+            ConnectorCapability::Introspection,
+            ConnectorCapability::JoinRelationLinks,
+            ConnectorCapability::RelationLinkTable,
+        ],
+    }
+}
+
 #[cfg(feature = "sql")]
 fn sql_executor<T>(primary_connector: &'static str, connector: T) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
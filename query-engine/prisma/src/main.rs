@@ -63,6 +63,13 @@ async fn main() -> Result<(), AnyError> {
                 .takes_value(false)
                 .required(false),
         )
+        .arg(
+            Arg::with_name("enable_reset_data")
+                .long("enable-reset-data")
+                .help("Enables the resetData mutation, which wipes all data. Meant for test setups.")
+                .takes_value(false)
+                .required(false),
+        )
         .arg(
             Arg::with_name("version")
                 .long("version")
@@ -125,8 +132,9 @@ async fn main() -> Result<(), AnyError> {
 
         let address = ([0, 0, 0, 0], port);
         let legacy = matches.is_present("legacy");
+        let enable_reset_data = matches.is_present("enable_reset_data");
 
-        if let Err(err) = HttpServer::run(address, legacy).await {
+        if let Err(err) = HttpServer::run(address, legacy, enable_reset_data).await {
             info!("Encountered error during initialization:");
             err.pretty_print();
             process::exit(1);
@@ -1,21 +1,44 @@
 use super::protocol_adapter::GraphQLProtocolAdapter;
-use crate::{context::PrismaContext, serializers::json, PrismaRequest, PrismaResult, RequestHandler};
-use query_core::{response_ir, CoreError};
+use crate::{context::PrismaContext, serializers::json, PrismaError, PrismaRequest, PrismaResult, RequestHandler};
+use async_trait::async_trait;
 use graphql_parser as gql;
+use query_core::{response_ir, CoreError, QueryDocument};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::Arc};
-use async_trait::async_trait;
+
+/// A GraphQL request body: either a single query, a plain list of queries,
+/// or a list of queries with batch options (`{ "batch": [...], "transaction": true }`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GraphQlBody {
+    Single(SingleQuery),
+    Multi(Vec<SingleQuery>),
+    Batch(BatchQuery),
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GraphQlBody {
+pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    variables: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchQuery {
+    batch: Vec<SingleQuery>,
+
+    /// Executes the whole batch in one transaction if set.
+    #[serde(default)]
+    transaction: bool,
 }
 
 pub struct GraphQlRequestHandler;
 
+/// Requests carrying this header are executed in the open interactive transaction with the given id.
+const TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
 #[allow(unused_variables)]
 #[async_trait]
 impl RequestHandler for GraphQlRequestHandler {
@@ -25,23 +48,29 @@ impl RequestHandler for GraphQlRequestHandler {
     where
         S: Into<PrismaRequest<Self::Body>> + Send + Sync + 'static,
     {
-        let responses = match handle_graphql_query(req.into(), ctx).await {
-            Ok(responses) => responses,
-            Err(err) => vec![err.into()],
-        };
+        let req: PrismaRequest<GraphQlBody> = req.into();
+
+        if let Some(tx_id) = req.headers.get(TRANSACTION_ID_HEADER) {
+            return handle_in_transaction(req.body, tx_id, ctx).await;
+        }
 
-        json::serialize(responses)
+        match req.body {
+            GraphQlBody::Single(query) => {
+                let responses = match handle_graphql_query(query, ctx).await {
+                    Ok(responses) => responses,
+                    Err(err) => vec![err.into()],
+                };
+
+                json::serialize(responses)
+            }
+            GraphQlBody::Multi(queries) => handle_batch(queries, false, ctx).await,
+            GraphQlBody::Batch(batch) => handle_batch(batch.batch, batch.transaction, ctx).await,
+        }
     }
 }
 
-async fn handle_graphql_query(
-    req: PrismaRequest<GraphQlBody>,
-    ctx: &PrismaContext,
-) -> PrismaResult<Vec<response_ir::Response>> {
-    debug!("Incoming GQL query: {:?}", &req.body.query);
-
-    let gql_doc = gql::parse_query(&req.body.query)?;
-    let query_doc = GraphQLProtocolAdapter::convert(gql_doc, req.body.operation_name)?;
+async fn handle_graphql_query(query: SingleQuery, ctx: &PrismaContext) -> PrismaResult<Vec<response_ir::Response>> {
+    let query_doc = convert_query(query, ctx)?;
 
     ctx.executor
         .execute(query_doc, Arc::clone(ctx.query_schema()))
@@ -52,3 +81,112 @@ async fn handle_graphql_query(
             ce.into()
         })
 }
+
+/// Handles a batch of queries, serializing the results into a list with one response per query.
+/// Queries that can't be converted fail individually. A transactional batch is only executed if all of its
+/// queries are valid, and if it fails, every query reports the error, as all of them have been rolled back.
+async fn handle_batch(queries: Vec<SingleQuery>, transactional: bool, ctx: &PrismaContext) -> serde_json::Value {
+    debug!(
+        "Incoming GQL batch of {} queries (transactional: {})",
+        queries.len(),
+        transactional
+    );
+
+    let mut responses: Vec<Option<Vec<response_ir::Response>>> = Vec::with_capacity(queries.len());
+    let mut documents = vec![];
+
+    for query in queries {
+        match convert_query(query, ctx) {
+            Ok(document) => {
+                documents.push(document);
+                responses.push(None);
+            }
+            Err(err) => responses.push(Some(vec![err.into()])),
+        }
+    }
+
+    let count = documents.len();
+    let failed = |message: String| -> Vec<Vec<response_ir::Response>> {
+        (0..count)
+            .map(|_| vec![response_ir::Response::Error(message.clone())])
+            .collect()
+    };
+
+    let results = if transactional && responses.iter().any(Option::is_some) {
+        failed("Batch aborted: the transactional batch contains invalid queries.".into())
+    } else if documents.is_empty() {
+        vec![]
+    } else {
+        match ctx
+            .executor
+            .execute_batch(documents, transactional, Arc::clone(ctx.query_schema()))
+            .await
+        {
+            Ok(results) => results
+                .into_iter()
+                .map(|result| match result {
+                    Ok(responses) => responses,
+                    Err(err) => vec![PrismaError::from(err).into()],
+                })
+                .collect(),
+            Err(err) => {
+                debug!("{}", err);
+                failed(format!("{}", PrismaError::from(err)))
+            }
+        }
+    };
+
+    let mut results = results.into_iter();
+    let serialized = responses
+        .into_iter()
+        .map(|response| json::serialize(response.unwrap_or_else(|| results.next().unwrap())))
+        .collect();
+
+    serde_json::Value::Array(serialized)
+}
+
+/// Executes the queries of the body one after another in an open interactive transaction.
+/// The transaction stays open, regardless of failing queries, until it is committed or rolled back.
+async fn handle_in_transaction(body: GraphQlBody, tx_id: &str, ctx: &PrismaContext) -> serde_json::Value {
+    let (queries, batched) = match body {
+        GraphQlBody::Single(query) => (vec![query], false),
+        GraphQlBody::Multi(queries) => (queries, true),
+        GraphQlBody::Batch(batch) => (batch.batch, true),
+    };
+
+    debug!("Incoming GQL request in transaction '{}'", tx_id);
+
+    let mut serialized = Vec::with_capacity(queries.len());
+
+    for query in queries {
+        let result = match convert_query(query, ctx) {
+            Ok(document) => ctx.execute_in_transaction(tx_id, document).await,
+            Err(err) => Err(err),
+        };
+
+        let responses = match result {
+            Ok(responses) => responses,
+            Err(err) => {
+                debug!("{}", err);
+                vec![err.into()]
+            }
+        };
+
+        serialized.push(json::serialize(responses));
+    }
+
+    if batched {
+        serde_json::Value::Array(serialized)
+    } else {
+        serialized.pop().unwrap()
+    }
+}
+
+fn convert_query(query: SingleQuery, ctx: &PrismaContext) -> PrismaResult<QueryDocument> {
+    debug!("Incoming GQL query: {:?}", &query.query);
+
+    let gql_doc = gql::parse_query(&query.query)?;
+    let variables = query.variables.unwrap_or_default();
+
+    GraphQLProtocolAdapter::convert(gql_doc, query.operation_name, variables, ctx.query_schema())
+}
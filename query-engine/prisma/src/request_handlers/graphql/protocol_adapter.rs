@@ -1,9 +1,13 @@
 use crate::{error::PrismaError, PrismaResult};
-use query_core::query_document::*;
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, FragmentDefinition, OperationDefinition, Selection as GqlSelection, SelectionSet, Type,
+    TypeCondition, Value, VariableDefinition,
 };
-use std::collections::BTreeMap;
+use query_core::{
+    query_document::*,
+    schema::{InputType, IntoArc, ObjectTypeStrongRef, QuerySchemaRef, ScalarType},
+};
+use std::collections::{BTreeMap, HashMap};
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -13,30 +17,93 @@ use std::collections::BTreeMap;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are coerced against their declared type and substituted into the query values.
+///   Declared defaults are used if a variable is not provided. Nullable variables that are neither
+///   provided nor have a default are treated as if the value using them was never given.
+/// - Named fragment spreads and inline fragments are expanded into the selections they appear in.
+///   Fields with the same response key are merged, provided that they select the same field with the same arguments.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
+/// - Directives (ignored).
 /// - Subscription queries.
 /// - Query names are ignored
 pub struct GraphQLProtocolAdapter;
 
+/// Variables of a single operation, after coercion.
+/// Declared variables without a value are tracked separately to tell them apart from undeclared ones.
+struct Variables {
+    values: HashMap<String, QueryValue>,
+    declared: Vec<String>,
+}
+
+impl Variables {
+    fn empty() -> Self {
+        Self {
+            values: HashMap::new(),
+            declared: vec![],
+        }
+    }
+
+    /// Returns `Ok(None)` if the variable is declared, but has no value.
+    fn resolve(&self, name: &str) -> PrismaResult<Option<QueryValue>> {
+        match self.values.get(name) {
+            Some(value) => Ok(Some(value.clone())),
+            None if self.declared.iter().any(|d| d == name) => Ok(None),
+            None => Err(PrismaError::QueryConversionError(format!(
+                "Variable '${}' is used but not declared by the operation.",
+                name
+            ))),
+        }
+    }
+}
+
+/// State required to convert the selection sets of a single operation.
+struct OperationContext<'a> {
+    vars: Variables,
+    fragments: &'a HashMap<String, FragmentDefinition>,
+    query_schema: &'a QuerySchemaRef,
+}
+
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document, operation: Option<String>) -> PrismaResult<QueryDocument> {
+    pub fn convert(
+        gql_doc: Document,
+        operation: Option<String>,
+        variables: HashMap<String, serde_json::Value>,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<QueryDocument> {
+        let (fragment_defs, definitions): (Vec<Definition>, Vec<Definition>) =
+            gql_doc.definitions.into_iter().partition(|def| match def {
+                Definition::Fragment(_) => true,
+                Definition::Operation(_) => false,
+            });
+
+        let mut fragments = HashMap::new();
+
+        for def in fragment_defs {
+            if let Definition::Fragment(f) = def {
+                if fragments.contains_key(&f.name) {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Fragment '{}' is defined more than once.",
+                        f.name
+                    )));
+                }
+
+                fragments.insert(f.name.clone(), f);
+            }
+        }
+
         let operations: Vec<Operation> = match operation {
-            Some(ref op) => gql_doc
-                .definitions
+            Some(ref op) => definitions
                 .into_iter()
                 .find(|def| Self::matches_operation(def, op))
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
                 })
-                .and_then(|def| Self::convert_definition(def)),
+                .and_then(|def| Self::convert_definition(def, &variables, &fragments, query_schema)),
 
-            None => gql_doc
-                .definitions
+            None => definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(|def| Self::convert_definition(def, &variables, &fragments, query_schema))
                 .collect::<PrismaResult<Vec<Vec<Operation>>>>()
                 .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
         }?;
@@ -44,31 +111,50 @@ impl GraphQLProtocolAdapter {
         Ok(QueryDocument { operations })
     }
 
-    fn convert_definition(def: Definition) -> PrismaResult<Vec<Operation>> {
+    fn convert_definition(
+        def: Definition,
+        variables: &HashMap<String, serde_json::Value>,
+        fragments: &HashMap<String, FragmentDefinition>,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<Vec<Operation>> {
+        let context = |vars| OperationContext {
+            vars,
+            fragments,
+            query_schema,
+        };
+
         match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
-            )),
+            // Fragments are only expanded where they are spread.
+            Definition::Fragment(_) => Ok(vec![]),
             Definition::Operation(op) => match op {
                 OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
                     "Subscription query",
                     format!("At position {}.", s.position),
                 )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::SelectionSet(s) => Self::convert_query(s, &context(Variables::empty())),
+                OperationDefinition::Query(q) => {
+                    let vars = Self::coerce_variables(q.variable_definitions, variables, query_schema)?;
+                    Self::convert_query(q.selection_set, &context(vars))
+                }
+                OperationDefinition::Mutation(m) => {
+                    let vars = Self::coerce_variables(m.variable_definitions, variables, query_schema)?;
+                    Self::convert_mutation(m.selection_set, &context(vars))
+                }
             },
         }
     }
 
-    fn convert_query(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set)
+    fn convert_query(selection_set: SelectionSet, ctx: &OperationContext) -> PrismaResult<Vec<Operation>> {
+        let parent = ctx.query_schema.query();
+
+        Self::convert_selection_set(selection_set, Some(parent), ctx, &mut vec![])
             .map(|fields| fields.into_iter().map(|field| Operation::Read(field)).collect())
     }
 
-    fn convert_mutation(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| {
+    fn convert_mutation(selection_set: SelectionSet, ctx: &OperationContext) -> PrismaResult<Vec<Operation>> {
+        let parent = ctx.query_schema.mutation();
+
+        Self::convert_selection_set(selection_set, Some(parent), ctx, &mut vec![]).map(|fields| {
             fields
                 .into_iter()
                 .map(|selection| Operation::Write(selection))
@@ -76,37 +162,144 @@ impl GraphQLProtocolAdapter {
         })
     }
 
-    fn convert_selection_set(selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
-            .into_iter()
-            .map(|item| match item {
+    /// Converts a selection set, expanding fragments in place.
+    /// `parent` is the schema object type the selection set is applied to, if it can be determined.
+    /// Fields that can't be found on the schema are passed through unchanged, the query document parser reports those.
+    /// `spreads` holds the names of the fragments currently being expanded, to detect cycles.
+    fn convert_selection_set(
+        selection_set: SelectionSet,
+        parent: Option<ObjectTypeStrongRef>,
+        ctx: &OperationContext,
+        spreads: &mut Vec<String>,
+    ) -> PrismaResult<Vec<Selection>> {
+        let mut selections: Vec<Selection> = vec![];
+
+        for item in selection_set.items {
+            match item {
                 GqlSelection::Field(f) => {
                     let arguments: Vec<(String, QueryValue)> = f
                         .arguments
                         .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
+                        .filter_map(|(k, v)| match Self::convert_value(v, &ctx.vars) {
+                            Ok(Some(v)) => Some(Ok((k, v))),
+                            Ok(None) => None,
+                            Err(err) => Some(Err(err)),
+                        })
                         .collect::<PrismaResult<Vec<_>>>()?;
 
-                    Ok(Selection {
+                    let nested_parent = parent
+                        .as_ref()
+                        .and_then(|p| p.find_field(&f.name))
+                        .and_then(|field| field.field_type.as_object_type());
+
+                    let selection = Selection {
+                        nested_selections: Self::convert_selection_set(f.selection_set, nested_parent, ctx, spreads)?,
                         name: f.name,
                         alias: f.alias,
                         arguments,
-                        nested_selections: Self::convert_selection_set(f.selection_set)?,
-                    })
+                    };
+
+                    Self::merge_selection(&mut selections, selection)?;
                 }
 
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+                GqlSelection::FragmentSpread(fs) => {
+                    let fragment = ctx.fragments.get(&fs.fragment_name).ok_or_else(|| {
+                        PrismaError::QueryConversionError(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
 
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
-            })
-            .collect()
+                    if spreads.contains(&fs.fragment_name) {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Cannot spread fragment '{}' within itself (via {} -> {}).",
+                            fs.fragment_name,
+                            spreads.join(" -> "),
+                            fs.fragment_name
+                        )));
+                    }
+
+                    Self::validate_type_condition(&fragment.type_condition, parent.as_ref(), ctx)?;
+
+                    spreads.push(fs.fragment_name.clone());
+                    let expanded =
+                        Self::convert_selection_set(fragment.selection_set.clone(), parent.clone(), ctx, spreads)?;
+                    spreads.pop();
+
+                    for selection in expanded {
+                        Self::merge_selection(&mut selections, selection)?;
+                    }
+                }
+
+                GqlSelection::InlineFragment(i) => {
+                    if let Some(ref condition) = i.type_condition {
+                        Self::validate_type_condition(condition, parent.as_ref(), ctx)?;
+                    }
+
+                    let expanded = Self::convert_selection_set(i.selection_set, parent.clone(), ctx, spreads)?;
+
+                    for selection in expanded {
+                        Self::merge_selection(&mut selections, selection)?;
+                    }
+                }
+            }
+        }
+
+        Ok(selections)
+    }
+
+    /// Checks that the type condition names an object type of the query schema,
+    /// and that it matches the type the fragment is spread on.
+    /// The query schema has no interfaces or unions, so the types have to be identical.
+    fn validate_type_condition(
+        condition: &TypeCondition,
+        parent: Option<&ObjectTypeStrongRef>,
+        ctx: &OperationContext,
+    ) -> PrismaResult<()> {
+        let TypeCondition::On(ref type_name) = condition;
+
+        if ctx.query_schema.find_output_object_type(type_name).is_none() {
+            return Err(PrismaError::QueryConversionError(format!(
+                "Unknown type '{}' in fragment type condition.",
+                type_name
+            )));
+        }
+
+        match parent {
+            Some(p) if &p.name != type_name => Err(PrismaError::QueryConversionError(format!(
+                "Fragment on type '{}' cannot be spread on type '{}'.",
+                type_name, p.name
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds a selection to the given list, merging it with an existing selection of the same response key.
+    /// Selections with the same response key have to select the same field with the same arguments.
+    fn merge_selection(selections: &mut Vec<Selection>, selection: Selection) -> PrismaResult<()> {
+        let response_key = |s: &Selection| s.alias.clone().unwrap_or_else(|| s.name.clone());
+        let key = response_key(&selection);
+
+        match selections.iter_mut().find(|existing| response_key(existing) == key) {
+            Some(existing) => {
+                if existing.name != selection.name || existing.arguments != selection.arguments {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Fields '{}' conflict because they select different fields or use different arguments.",
+                        key
+                    )));
+                }
+
+                for nested in selection.nested_selections {
+                    Self::merge_selection(&mut existing.nested_selections, nested)?;
+                }
+
+                Ok(())
+            }
+            None => {
+                selections.push(selection);
+                Ok(())
+            }
+        }
     }
 
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
@@ -123,40 +316,211 @@ impl GraphQLProtocolAdapter {
         }
     }
 
-    fn convert_value(value: Value) -> PrismaResult<QueryValue> {
+    /// Converts a GraphQL value into a query value, substituting variables.
+    /// Returns `None` if the value is a declared variable without a value.
+    fn convert_value(value: Value, vars: &Variables) -> PrismaResult<Option<QueryValue>> {
         match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => vars.resolve(&name),
             Value::Int(i) => match i.as_i64() {
-                Some(i) => Ok(QueryValue::Int(i)),
+                Some(i) => Ok(Some(QueryValue::Int(i))),
                 None => Err(PrismaError::QueryConversionError(format!(
                     "Invalid 64 bit integer: {:?}",
                     i
                 ))),
             },
-            Value::Float(f) => Ok(QueryValue::Float(f)),
-            Value::String(s) => Ok(QueryValue::String(s)),
-            Value::Boolean(b) => Ok(QueryValue::Boolean(b)),
-            Value::Null => Ok(QueryValue::Null),
-            Value::Enum(e) => Ok(QueryValue::Enum(e)),
+            Value::Float(f) => Ok(Some(QueryValue::Float(f))),
+            Value::String(s) => Ok(Some(QueryValue::String(s))),
+            Value::Boolean(b) => Ok(Some(QueryValue::Boolean(b))),
+            Value::Null => Ok(Some(QueryValue::Null)),
+            Value::Enum(e) => Ok(Some(QueryValue::Enum(e))),
             Value::List(values) => {
+                // Missing variables in lists are treated as nulls.
                 let values: Vec<QueryValue> = values
                     .into_iter()
-                    .map(Self::convert_value)
+                    .map(|v| Self::convert_value(v, vars).map(|v| v.unwrap_or(QueryValue::Null)))
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
-                Ok(QueryValue::List(values))
+                Ok(Some(QueryValue::List(values)))
             }
             Value::Object(map) => {
+                // Missing variables in objects cause the field to be omitted.
                 let values = map
                     .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .filter_map(|(k, v)| match Self::convert_value(v, vars) {
+                        Ok(Some(v)) => Some(Ok((k, v))),
+                        Ok(None) => None,
+                        Err(err) => Some(Err(err)),
+                    })
                     .collect::<PrismaResult<BTreeMap<String, QueryValue>>>()?;
 
-                Ok(QueryValue::Object(values))
+                Ok(Some(QueryValue::Object(values)))
+            }
+        }
+    }
+
+    /// Coerces the provided JSON variables against the variable definitions of an operation.
+    /// Provided variables that are not declared by the operation are ignored.
+    fn coerce_variables(
+        definitions: Vec<VariableDefinition>,
+        provided: &HashMap<String, serde_json::Value>,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<Variables> {
+        let mut vars = Variables::empty();
+
+        for def in definitions {
+            let value = match (provided.get(&def.name), def.default_value) {
+                (Some(json), _) => Some(Self::coerce_json(json.clone(), &def.var_type, query_schema)),
+
+                // Defaults are constant values and can't contain variables themselves.
+                (None, Some(default)) => Self::convert_value(default, &Variables::empty()).transpose(),
+                (None, None) => match def.var_type {
+                    Type::NonNullType(_) => Some(Err(PrismaError::QueryConversionError(format!(
+                        "Variable '${}' of required type '{}' was not provided.",
+                        def.name, def.var_type
+                    )))),
+                    _ => None,
+                },
+            };
+
+            let value = value.transpose().map_err(|err| {
+                PrismaError::QueryConversionError(format!("Variable '${}' got an invalid value: {}", def.name, err))
+            })?;
+
+            if let Some(value) = value {
+                vars.values.insert(def.name.clone(), value);
             }
+
+            vars.declared.push(def.name);
+        }
+
+        Ok(vars)
+    }
+
+    /// Coerces a JSON value against a declared GraphQL variable type.
+    fn coerce_json(value: serde_json::Value, typ: &Type, query_schema: &QuerySchemaRef) -> PrismaResult<QueryValue> {
+        match (typ, value) {
+            (Type::NonNullType(_), serde_json::Value::Null) => Err(PrismaError::QueryConversionError(
+                "Expected a non-null value.".to_owned(),
+            )),
+            (Type::NonNullType(inner), value) => Self::coerce_json(value, inner, query_schema),
+            (_, serde_json::Value::Null) => Ok(QueryValue::Null),
+            (Type::ListType(inner), serde_json::Value::Array(values)) => values
+                .into_iter()
+                .map(|v| Self::coerce_json(v, inner, query_schema))
+                .collect::<PrismaResult<Vec<_>>>()
+                .map(QueryValue::List),
+
+            // Single values are coerced into a list of one element, as per GraphQL input coercion rules.
+            (Type::ListType(inner), value) => {
+                Ok(QueryValue::List(vec![Self::coerce_json(value, inner, query_schema)?]))
+            }
+            (Type::NamedType(name), value) => Self::coerce_named(value, name, query_schema),
+        }
+    }
+
+    /// Coerces a JSON value against a named type, which is either a built-in scalar or a type of the query schema.
+    fn coerce_named(value: serde_json::Value, name: &str, query_schema: &QuerySchemaRef) -> PrismaResult<QueryValue> {
+        let scalar = match name {
+            "String" => Some(ScalarType::String),
+            "Int" => Some(ScalarType::Int),
+            "Float" => Some(ScalarType::Float),
+            "Decimal" => Some(ScalarType::Decimal),
+            "Boolean" => Some(ScalarType::Boolean),
+            "DateTime" => Some(ScalarType::DateTime),
+            "Json" => Some(ScalarType::Json),
+            "UUID" => Some(ScalarType::UUID),
+            "ID" => Some(ScalarType::ID),
+            _ => None,
+        };
+
+        if let Some(scalar) = scalar {
+            return Self::coerce_scalar(value, &scalar);
+        }
+
+        if let Some(obj) = query_schema.find_input_object_type(name) {
+            return Self::coerce_input_type(value, &InputType::object(std::sync::Arc::downgrade(&obj)));
+        }
+
+        if let Some(e) = query_schema.find_input_enum_type(name) {
+            return Self::coerce_input_type(value, &InputType::Enum(e));
+        }
+
+        Err(PrismaError::QueryConversionError(format!(
+            "Unknown input type '{}'.",
+            name
+        )))
+    }
+
+    /// Coerces a JSON value against a query schema input type.
+    fn coerce_input_type(value: serde_json::Value, input_type: &InputType) -> PrismaResult<QueryValue> {
+        match (input_type, value) {
+            (_, serde_json::Value::Null) => Ok(QueryValue::Null),
+            (InputType::Opt(inner), value) => Self::coerce_input_type(value, inner),
+            (InputType::Scalar(scalar), value) => Self::coerce_scalar(value, scalar),
+            (InputType::Enum(_), serde_json::Value::String(s)) => Ok(QueryValue::Enum(s)),
+            (InputType::List(inner), serde_json::Value::Array(values)) => values
+                .into_iter()
+                .map(|v| Self::coerce_input_type(v, inner))
+                .collect::<PrismaResult<Vec<_>>>()
+                .map(QueryValue::List),
+
+            (InputType::List(inner), value) => Ok(QueryValue::List(vec![Self::coerce_input_type(value, inner)?])),
+            (InputType::Object(obj), serde_json::Value::Object(map)) => {
+                let obj = obj.into_arc();
+
+                map.into_iter()
+                    .map(|(k, v)| match obj.find_field(k.as_str()) {
+                        Some(field) => Self::coerce_input_type(v, &field.field_type).map(|v| (k, v)),
+                        None => Err(PrismaError::QueryConversionError(format!(
+                            "Field '{}' is not defined on input type '{}'.",
+                            k, obj.name
+                        ))),
+                    })
+                    .collect::<PrismaResult<BTreeMap<_, _>>>()
+                    .map(QueryValue::Object)
+            }
+
+            (input_type, value) => Err(PrismaError::QueryConversionError(format!(
+                "Value {} does not match expected input type {:?}.",
+                value, input_type
+            ))),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn coerce_scalar(value: serde_json::Value, scalar_type: &ScalarType) -> PrismaResult<QueryValue> {
+        use serde_json::Value as Json;
+
+        match (scalar_type, value) {
+            (_, Json::Null)                                      => Ok(QueryValue::Null),
+            (ScalarType::String, Json::String(s))                => Ok(QueryValue::String(s)),
+            (ScalarType::DateTime, Json::String(s))              => Ok(QueryValue::String(s)),
+            (ScalarType::UUID, Json::String(s))                  => Ok(QueryValue::String(s)),
+            (ScalarType::ID, Json::String(s))                    => Ok(QueryValue::String(s)),
+            (ScalarType::ID, Json::Number(ref n)) if n.is_i64()  => Ok(QueryValue::Int(n.as_i64().unwrap())),
+            (ScalarType::Int, Json::Number(ref n)) if n.is_i64() => Ok(QueryValue::Int(n.as_i64().unwrap())),
+            (ScalarType::Float, Json::Number(n))                 => Ok(QueryValue::Float(n.as_f64().unwrap())),
+            (ScalarType::Decimal, Json::String(s))               => Ok(QueryValue::String(s)),
+            (ScalarType::Boolean, Json::Bool(b))                 => Ok(QueryValue::Boolean(b)),
+            (ScalarType::Enum(_), Json::String(s))               => Ok(QueryValue::Enum(s)),
+
+            // Json values are passed through the query document as strings.
+            (ScalarType::Json, Json::String(s))                  => Ok(QueryValue::String(s)),
+            (ScalarType::Json, value)                            => Ok(QueryValue::String(value.to_string())),
+
+            // JSON numbers are parsed as floats, which can't hold every decimal.
+            (ScalarType::Decimal, Json::Number(n)) => Err(PrismaError::QueryConversionError(format!(
+                "Decimal value {} has to be given as a string to keep its precision.",
+                n
+            ))),
+
+            (scalar_type, value) => Err(PrismaError::QueryConversionError(format!(
+                "Value {} is not a valid {:?}.",
+                value, scalar_type
+            ))),
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,275 @@
+use super::*;
+use prisma_models::DatamodelConverter;
+use query_core::{schema::SupportedCapabilities, BuildMode, QuerySchemaBuilder};
+use serde_json::json;
+use std::sync::Arc;
+
+const DATAMODEL: &str = r#"
+    model User {
+        id      String   @id @default(cuid())
+        name    String?
+        balance Decimal?
+        posts   Post[]
+    }
+
+    model Post {
+        id     String @id @default(cuid())
+        title  String
+        author User
+    }
+"#;
+
+#[test]
+fn variables_must_be_substituted_into_arguments() {
+    let operation = convert_single(
+        "query($id: String!) { findOneUser(where: { id: $id }) { id } }",
+        json!({ "id": "a" }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        operation.arguments,
+        vec![(
+            "where".to_owned(),
+            object(vec![("id", QueryValue::String("a".to_owned()))])
+        )]
+    );
+}
+
+#[test]
+fn variables_must_be_coerced_against_input_object_types() {
+    let operation = convert_single(
+        "query($where: UserWhereUniqueInput!) { findOneUser(where: $where) { id } }",
+        json!({ "where": { "id": "a" } }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        operation.arguments,
+        vec![(
+            "where".to_owned(),
+            object(vec![("id", QueryValue::String("a".to_owned()))])
+        )]
+    );
+
+    let error = convert_single(
+        "query($where: UserWhereUniqueInput!) { findOneUser(where: $where) { id } }",
+        json!({ "where": { "unknown": "a" } }),
+    )
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Field 'unknown' is not defined on input type"));
+}
+
+#[test]
+fn variables_with_invalid_values_must_be_rejected() {
+    let error = convert_single(
+        "query($skip: Int) { findManyUser(skip: $skip) { id } }",
+        json!({ "skip": "ten" }),
+    )
+    .unwrap_err();
+
+    assert!(error.to_string().contains("Variable '$skip' got an invalid value"));
+}
+
+#[test]
+fn decimal_variables_must_keep_their_precision() {
+    let operation = convert_single(
+        "mutation($balance: Decimal) { createOneUser(data: { balance: $balance }) { id } }",
+        json!({ "balance": "12345678901234567890.123456789" }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        operation.arguments,
+        vec![(
+            "data".to_owned(),
+            object(vec![(
+                "balance",
+                QueryValue::String("12345678901234567890.123456789".to_owned())
+            )])
+        )]
+    );
+}
+
+#[test]
+fn decimal_variables_given_as_numbers_must_be_rejected() {
+    let error = convert_single(
+        "mutation($balance: Decimal) { createOneUser(data: { balance: $balance }) { id } }",
+        json!({ "balance": 0.1 }),
+    )
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Decimal value 0.1 has to be given as a string to keep its precision."));
+}
+
+#[test]
+fn declared_defaults_must_be_used_for_missing_variables() {
+    let operation = convert_single("query($skip: Int = 10) { findManyUser(skip: $skip) { id } }", json!({})).unwrap();
+
+    assert_eq!(operation.arguments, vec![("skip".to_owned(), QueryValue::Int(10))]);
+}
+
+#[test]
+fn missing_required_variables_must_be_an_error() {
+    let error = convert_single(
+        "query($id: String!) { findOneUser(where: { id: $id }) { id } }",
+        json!({}),
+    )
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Variable '$id' of required type 'String!' was not provided."));
+}
+
+#[test]
+fn missing_nullable_variables_must_omit_the_values_using_them() {
+    let operation = convert_single(
+        "query($name: String, $skip: Int) { findManyUser(skip: $skip, where: { name: $name }) { id } }",
+        json!({}),
+    )
+    .unwrap();
+
+    assert_eq!(operation.arguments, vec![("where".to_owned(), object(vec![]))]);
+}
+
+#[test]
+fn undeclared_variables_must_be_an_error() {
+    let error = convert_single("query { findOneUser(where: { id: $id }) { id } }", json!({ "id": "a" })).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Variable '$id' is used but not declared by the operation."));
+}
+
+#[test]
+fn named_and_inline_fragments_must_be_expanded_and_merged() {
+    let operation = convert_single(
+        r#"
+            query {
+                findManyUser {
+                    id
+                    ...UserFields
+                    ... on User { posts { title } }
+                }
+            }
+
+            fragment UserFields on User {
+                name
+                posts { id }
+            }
+        "#,
+        json!({}),
+    )
+    .unwrap();
+
+    let names = |selections: &[Selection]| selections.iter().map(|s| s.name.clone()).collect::<Vec<_>>();
+
+    assert_eq!(names(&operation.nested_selections), vec!["id", "name", "posts"]);
+    assert_eq!(
+        names(&operation.nested_selections[2].nested_selections),
+        vec!["id", "title"]
+    );
+}
+
+#[test]
+fn fragment_cycles_must_be_an_error() {
+    let error = convert(
+        r#"
+            query { findManyUser { ...A } }
+            fragment A on User { id ...B }
+            fragment B on User { name ...A }
+        "#,
+        json!({}),
+    )
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Cannot spread fragment 'A' within itself (via A -> B -> A)."));
+}
+
+#[test]
+fn fragments_spreading_themselves_must_be_an_error() {
+    let error = convert(
+        r#"
+            query { findManyUser { ...A } }
+            fragment A on User { id posts { author { ...A } } }
+        "#,
+        json!({}),
+    )
+    .unwrap_err();
+
+    assert!(error.to_string().contains("Cannot spread fragment 'A' within itself"));
+}
+
+#[test]
+fn unknown_fragments_must_be_an_error() {
+    let error = convert("query { findManyUser { ...Unknown } }", json!({})).unwrap_err();
+
+    assert!(error.to_string().contains("Unknown fragment 'Unknown'"));
+}
+
+#[test]
+fn fragments_on_other_types_must_be_an_error() {
+    let error = convert(
+        r#"
+            query { findManyUser { ...PostFields } }
+            fragment PostFields on Post { title }
+        "#,
+        json!({}),
+    )
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Fragment on type 'Post' cannot be spread on type 'User'."));
+}
+
+#[test]
+fn conflicting_fields_in_fragments_must_be_an_error() {
+    let error = convert(
+        r#"
+            query { findManyUser { value: id ...UserFields } }
+            fragment UserFields on User { value: name }
+        "#,
+        json!({}),
+    )
+    .unwrap_err();
+
+    assert!(error.to_string().contains("Fields 'value' conflict"));
+}
+
+fn query_schema() -> QuerySchemaRef {
+    let datamodel = datamodel::parse_datamodel(DATAMODEL).unwrap();
+    let internal_data_model = DatamodelConverter::convert(&datamodel).build("db".to_owned());
+    let capabilities = SupportedCapabilities::empty();
+
+    Arc::new(QuerySchemaBuilder::new(&internal_data_model, &capabilities, BuildMode::Modern, false).build())
+}
+
+fn convert(query: &str, variables: serde_json::Value) -> PrismaResult<QueryDocument> {
+    let gql_doc = graphql_parser::parse_query(query).unwrap();
+    let variables: HashMap<String, serde_json::Value> = serde_json::from_value(variables).unwrap();
+
+    GraphQLProtocolAdapter::convert(gql_doc, None, variables, &query_schema())
+}
+
+/// Converts a query with a single operation and returns its selection.
+fn convert_single(query: &str, variables: serde_json::Value) -> PrismaResult<Selection> {
+    let mut document = convert(query, variables)?;
+    assert_eq!(document.operations.len(), 1);
+
+    match document.operations.pop().unwrap() {
+        Operation::Read(selection) | Operation::Write(selection) => Ok(selection),
+    }
+}
+
+fn object(fields: Vec<(&str, QueryValue)>) -> QueryValue {
+    QueryValue::Object(fields.into_iter().map(|(k, v)| (k.to_owned(), v)).collect())
+}
@@ -71,6 +71,8 @@ fn serialize_prisma_value(value: PrismaValue) -> PrismaResult<Value> {
             Some(num) => num,
             None => return Err(PrismaError::SerializationError("`f64` number was invalid".into())),
         }),
+        // Decimals are serialized as strings, JSON numbers would lose their precision on the client.
+        PrismaValue::Decimal(x) => Value::String(x.to_string()),
         PrismaValue::Boolean(x) => Value::Bool(x),
         PrismaValue::DateTime(date) => Value::String(format!("{}", date.format("%Y-%m-%dT%H:%M:%S%.3fZ"))),
         PrismaValue::Enum(x) => Value::String(x.as_string()),
@@ -5,20 +5,33 @@ use crate::{
         graphql::{GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
         PrismaRequest, RequestHandler,
     },
-    PrismaResult,
+    PrismaError, PrismaResult,
 };
-use query_core::schema::QuerySchemaRenderer;
+use connector::IsolationLevel;
 use futures::stream::TryStreamExt;
 use hyper::header;
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{Body, Error, Method, Request, Response, Server, StatusCode};
+use query_core::{schema::QuerySchemaRenderer, CoreError};
+use serde::Deserialize;
 use serde_json::json;
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[derive(RustEmbed)]
 #[folder = "query-engine/prisma/static_files"]
 struct StaticFiles;
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StartTransactionBody {
+    /// Timeout in milliseconds after which the transaction is rolled back.
+    timeout: Option<u64>,
+    isolation_level: Option<String>,
+}
+
 pub(crate) struct RequestContext {
     context: PrismaContext,
     graphql_request_handler: GraphQlRequestHandler,
@@ -27,11 +40,11 @@ pub(crate) struct RequestContext {
 pub struct HttpServer;
 
 impl HttpServer {
-    pub async fn run(address: ([u8; 4], u16), legacy_mode: bool) -> PrismaResult<()> {
+    pub async fn run(address: ([u8; 4], u16), legacy_mode: bool, enable_reset_data: bool) -> PrismaResult<()> {
         let now = Instant::now();
 
         let ctx = Arc::new(RequestContext {
-            context: PrismaContext::new(legacy_mode)?,
+            context: PrismaContext::new(legacy_mode, enable_reset_data)?,
             graphql_request_handler: GraphQlRequestHandler,
         });
 
@@ -87,6 +100,37 @@ impl HttpServer {
             (&Method::GET, "/dmmf") => Self::dmmf_handler(ctx),
             (&Method::GET, "/server_info") => Self::server_info_handler(ctx),
 
+            (&Method::POST, "/transaction/start") => {
+                let body_bytes = req.into_body().try_concat().await?;
+
+                if body_bytes.is_empty() {
+                    Self::start_transaction_handler(StartTransactionBody::default(), ctx).await
+                } else {
+                    match serde_json::from_slice(body_bytes.as_ref()) {
+                        Ok(body) => Self::start_transaction_handler(body, ctx).await,
+                        Err(_) => {
+                            let mut bad_request = Response::default();
+                            *bad_request.status_mut() = StatusCode::BAD_REQUEST;
+                            bad_request
+                        }
+                    }
+                }
+            }
+
+            (&Method::POST, path) if path.starts_with("/transaction/") => {
+                let segments: Vec<&str> = path.trim_start_matches("/transaction/").split('/').collect();
+
+                match segments.as_slice() {
+                    [id, "commit"] => Self::end_transaction_handler(id, true, ctx).await,
+                    [id, "rollback"] => Self::end_transaction_handler(id, false, ctx).await,
+                    _ => {
+                        let mut not_found = Response::default();
+                        *not_found.status_mut() = StatusCode::NOT_FOUND;
+                        not_found
+                    }
+                }
+            }
+
             _ => {
                 let mut not_found = Response::default();
                 *not_found.status_mut() = StatusCode::NOT_FOUND;
@@ -108,6 +152,60 @@ impl HttpServer {
             .unwrap()
     }
 
+    /// Opens an interactive transaction. Queries are run in it by passing the returned id
+    /// in the `X-Transaction-Id` header, until it is committed, rolled back or times out.
+    async fn start_transaction_handler(body: StartTransactionBody, cx: Arc<RequestContext>) -> Response<Body> {
+        let isolation_level = match body
+            .isolation_level
+            .map(|level| level.parse::<IsolationLevel>())
+            .transpose()
+        {
+            Ok(level) => level,
+            Err(err) => return Self::transaction_error(PrismaError::from(CoreError::from(err))),
+        };
+
+        let timeout = body.timeout.map(Duration::from_millis);
+
+        match cx.context.start_transaction(isolation_level, timeout).await {
+            Ok((id, timeout)) => Self::json_response(json!({ "id": id, "timeout": timeout.as_millis() as u64 })),
+            Err(err) => Self::transaction_error(err),
+        }
+    }
+
+    async fn end_transaction_handler(id: &str, commit: bool, cx: Arc<RequestContext>) -> Response<Body> {
+        let result = if commit {
+            cx.context.commit_transaction(id).await
+        } else {
+            cx.context.rollback_transaction(id).await
+        };
+
+        match result {
+            Ok(()) => Self::json_response(json!({})),
+            Err(err) => Self::transaction_error(err),
+        }
+    }
+
+    fn transaction_error(err: PrismaError) -> Response<Body> {
+        debug!("{}", err);
+        let bytes = serde_json::to_vec(&json!({ "error": format!("{}", err) })).unwrap();
+
+        Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap()
+    }
+
+    fn json_response(body: serde_json::Value) -> Response<Body> {
+        let bytes = serde_json::to_vec(&body).unwrap();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(bytes))
+            .unwrap()
+    }
+
     fn status_handler() -> Response<Body> {
         let body_data = json!({"status": "ok"});
         let bytes = serde_json::to_vec(&body_data).unwrap();